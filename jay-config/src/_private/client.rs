@@ -11,12 +11,14 @@ use {
             },
             logging,
         },
-        Axis, Direction, ModifiedKeySym, PciId, Workspace,
-        client::{Client, ClientCriterion, ClientMatcher, MatchedClient},
+        Axis, DecorationMode, Direction, FocusStealingPolicy, ModifiedKeySym, PciId, Workspace,
+        WorkspaceLayout,
+        client::{Client, ClientCapabilities, ClientCriterion, ClientMatcher, MatchedClient},
         exec::Command,
         input::{
-            FocusFollowsMouseMode, InputDevice, LayerDirection, Seat, SwitchEvent, Timeline,
-            acceleration::AccelProfile, capability::Capability, clickmethod::ClickMethod,
+            FocusFollowsMouseMode, InputDevice, LayerDirection, Seat, SwipeEvent, SwitchEvent,
+            Timeline, acceleration::AccelProfile, capability::Capability, clickmethod::ClickMethod,
+            scrollmethod::ScrollMethod, sendeventsmode::SendEventsMode,
         },
         keyboard::{
             Keymap,
@@ -107,7 +109,9 @@ pub(crate) struct ConfigClient {
     on_new_drm_device: RefCell<Option<Callback<DrmDevice>>>,
     on_del_drm_device: RefCell<Option<Callback<DrmDevice>>>,
     on_idle: RefCell<Option<Callback>>,
+    on_lock_requested: RefCell<Option<Callback>>,
     on_switch_event: RefCell<HashMap<InputDevice, Callback<SwitchEvent>>>,
+    on_swipe_event: RefCell<HashMap<Seat, Callback<SwipeEvent>>>,
     on_unload: Cell<Option<OnDrop<Box<dyn FnOnce()>>>>,
     bufs: RefCell<Vec<Vec<u8>>>,
     reload: Cell<bool>,
@@ -252,7 +256,9 @@ pub unsafe extern "C" fn init(
         on_new_drm_device: Default::default(),
         on_del_drm_device: Default::default(),
         on_idle: Default::default(),
+        on_lock_requested: Default::default(),
         on_switch_event: Default::default(),
+        on_swipe_event: Default::default(),
         on_unload: Default::default(),
         bufs: Default::default(),
         reload: Cell::new(false),
@@ -574,6 +580,57 @@ impl ConfigClient {
         self.send(&ClientMessage::SetWorkspaceCapture { workspace, capture });
     }
 
+    pub fn set_default_workspace_layout(&self, layout: WorkspaceLayout) {
+        self.send(&ClientMessage::SetDefaultWorkspaceLayout { layout });
+    }
+
+    pub fn set_workspace_layout(&self, workspace: Workspace, layout: WorkspaceLayout) {
+        self.send(&ClientMessage::SetWorkspaceLayout { workspace, layout });
+    }
+
+    pub fn set_workspace_inner_gap(&self, workspace: Workspace, gap: Option<i32>) {
+        self.send(&ClientMessage::SetWorkspaceInnerGap { workspace, gap });
+    }
+
+    pub fn set_workspace_outer_gap(&self, workspace: Workspace, gap: Option<i32>) {
+        self.send(&ClientMessage::SetWorkspaceOuterGap { workspace, gap });
+    }
+
+    pub fn seat_move_window_to_output(&self, seat: Seat, connector: Connector) {
+        self.send(&ClientMessage::SeatMoveWindowToOutput { seat, connector });
+    }
+
+    pub fn seat_move_window_to_adjacent_output(&self, seat: Seat, direction: Direction) {
+        self.send(&ClientMessage::SeatMoveWindowToAdjacentOutput { seat, direction });
+    }
+
+    pub fn seat_move_to_scratchpad(&self, seat: Seat, name: &str) {
+        self.send(&ClientMessage::SeatMoveToScratchpad { seat, name });
+    }
+
+    pub fn seat_toggle_scratchpad(&self, seat: Seat, name: &str) {
+        self.send(&ClientMessage::SeatToggleScratchpad { seat, name });
+    }
+
+    pub fn set_swallow_app_ids(&self, app_ids: &[&str]) {
+        let app_ids = app_ids.iter().map(|s| s.to_string()).collect();
+        self.send(&ClientMessage::SetSwallowAppIds { app_ids });
+    }
+
+    pub fn set_clipboard_persistence_enabled(&self, enabled: bool) {
+        self.send(&ClientMessage::SetClipboardPersistenceEnabled { enabled });
+    }
+
+    pub fn set_clipboard_persistence_max_size(&self, max_size: u64) {
+        self.send(&ClientMessage::SetClipboardPersistenceMaxSize { max_size });
+    }
+
+    pub fn get_workspace_gaps(&self, workspace: Workspace) -> (i32, i32) {
+        let res = self.send_with_response(&ClientMessage::GetWorkspaceGaps { workspace });
+        get_response!(res, (0, 0), GetWorkspaceGaps { inner, outer });
+        (inner, outer)
+    }
+
     pub fn get_default_workspace_capture(&self) -> bool {
         let res = self.send_with_response(&ClientMessage::GetDefaultWorkspaceCapture);
         get_response!(res, true, GetDefaultWorkspaceCapture { capture });
@@ -679,6 +736,100 @@ impl ConfigClient {
         self.send(&ClientMessage::SetWindowFloating { window, floating });
     }
 
+    pub fn get_window_opacity(&self, window: Window) -> f32 {
+        let res = self.send_with_response(&ClientMessage::GetWindowOpacity { window });
+        get_response!(res, 1.0, GetWindowOpacity { opacity });
+        opacity
+    }
+
+    pub fn set_window_opacity(&self, window: Window, opacity: f32) {
+        self.send(&ClientMessage::SetWindowOpacity { window, opacity });
+    }
+
+    pub fn set_inactive_window_opacity(&self, opacity: f32) {
+        self.send(&ClientMessage::SetInactiveWindowOpacity { opacity });
+    }
+
+    pub fn set_workspace_animation_duration(&self, duration: Duration) {
+        self.send(&ClientMessage::SetWorkspaceAnimationDuration { duration });
+    }
+
+    pub fn set_window_animation_duration(&self, duration: Duration) {
+        self.send(&ClientMessage::SetWindowAnimationDuration { duration });
+    }
+
+    pub fn set_focus_stealing_policy(&self, policy: FocusStealingPolicy) {
+        self.send(&ClientMessage::SetFocusStealingPolicy { policy });
+    }
+
+    pub fn set_decoration_mode(&self, mode: DecorationMode) {
+        self.send(&ClientMessage::SetDecorationMode { mode });
+    }
+
+    pub fn set_client_object_limit(&self, limit: Option<u64>) {
+        self.send(&ClientMessage::SetClientObjectLimit { limit });
+    }
+
+    pub fn set_client_shm_limit(&self, limit: Option<u64>) {
+        self.send(&ClientMessage::SetClientShmLimit { limit });
+    }
+
+    pub fn set_sandbox_capabilities(&self, grants: &[(&str, ClientCapabilities)]) {
+        let grants = grants
+            .iter()
+            .map(|(app_id, caps)| (app_id.to_string(), *caps))
+            .collect();
+        self.send(&ClientMessage::SetSandboxCapabilities { grants });
+    }
+
+    pub fn connector_set_max_fps(&self, connector: Connector, max_fps: Option<f64>) {
+        self.send(&ClientMessage::ConnectorSetMaxFps { connector, max_fps });
+    }
+
+    pub fn set_low_power_mode(&self, enabled: bool) {
+        self.send(&ClientMessage::SetLowPowerMode { enabled });
+    }
+
+    pub fn set_low_power_max_fps(&self, max_fps: Option<f64>) {
+        self.send(&ClientMessage::SetLowPowerMaxFps { max_fps });
+    }
+
+    pub fn set_idle_dim(&self, dim: f32) {
+        self.send(&ClientMessage::SetIdleDim { dim });
+    }
+
+    pub fn seat_warp_pointer(&self, seat: Seat, x: f64, y: f64) {
+        self.send(&ClientMessage::SeatWarpPointer { seat, x, y });
+    }
+
+    pub fn seat_warp_pointer_to_connector(&self, seat: Seat, connector: Connector) {
+        self.send(&ClientMessage::SeatWarpPointerToConnector { seat, connector });
+    }
+
+    pub fn seat_warp_pointer_to_window(&self, seat: Seat, window: Window) {
+        self.send(&ClientMessage::SeatWarpPointerToWindow { seat, window });
+    }
+
+    pub fn set_cursor_follows_focus(&self, seat: Seat, enabled: bool) {
+        self.send(&ClientMessage::SetCursorFollowsFocus { seat, enabled });
+    }
+
+    pub fn set_hide_cursor_while_typing(&self, seat: Seat, enabled: bool) {
+        self.send(&ClientMessage::SetHideCursorWhileTyping { seat, enabled });
+    }
+
+    pub fn connector_set_zoom(&self, connector: Connector, zoom: f64) {
+        self.send(&ClientMessage::ConnectorSetZoom { connector, zoom });
+    }
+
+    pub fn seat_adjust_zoom(&self, seat: Seat, factor: f64) {
+        self.send(&ClientMessage::SeatAdjustZoom { seat, factor });
+    }
+
+    pub fn seat_reset_zoom(&self, seat: Seat) {
+        self.send(&ClientMessage::SeatResetZoom { seat });
+    }
+
     pub fn reset_colors(&self) {
         self.send(&ClientMessage::ResetColors);
     }
@@ -707,6 +858,10 @@ impl ConfigClient {
         self.send(&ClientMessage::SetCursorSize { seat, size })
     }
 
+    pub fn set_cursor_theme(&self, seat: Seat, theme: Option<&str>) {
+        self.send(&ClientMessage::SetCursorTheme { seat, theme })
+    }
+
     pub fn set_use_hardware_cursor(&self, seat: Seat, use_hardware_cursor: bool) {
         self.send(&ClientMessage::SetUseHardwareCursor {
             seat,
@@ -826,6 +981,10 @@ impl ConfigClient {
             .insert(input_device, cb(f));
     }
 
+    pub fn on_swipe_event<F: FnMut(SwipeEvent) + 'static>(&self, seat: Seat, f: F) {
+        self.on_swipe_event.borrow_mut().insert(seat, cb(f));
+    }
+
     pub fn set_double_click_interval(&self, usec: u64) {
         self.send(&ClientMessage::SetDoubleClickIntervalUsec { usec });
     }
@@ -987,6 +1146,10 @@ impl ConfigClient {
         self.send(&ClientMessage::SetMiddleClickPasteEnabled { enabled });
     }
 
+    pub fn set_freeze_cursor_animation_when_idle(&self, freeze: bool) {
+        self.send(&ClientMessage::SetFreezeCursorAnimationWhenIdle { freeze });
+    }
+
     pub fn seat_create_mark(&self, seat: Seat, kc: Option<u32>) {
         self.send(&ClientMessage::SeatCreateMark { seat, kc });
     }
@@ -1003,6 +1166,10 @@ impl ConfigClient {
         self.send(&ClientMessage::SetShowFloatPinIcon { show });
     }
 
+    pub fn set_show_float_close_icon(&self, show: bool) {
+        self.send(&ClientMessage::SetShowFloatCloseIcon { show });
+    }
+
     pub fn get_pinned(&self, seat: Seat) -> bool {
         let res = self.send_with_response(&ClientMessage::GetSeatFloatPinned { seat });
         get_response!(res, false, GetFloatPinned { pinned });
@@ -1057,6 +1224,10 @@ impl ConfigClient {
         });
     }
 
+    pub fn connector_set_cursor_size(&self, connector: Connector, size: Option<i32>) {
+        self.send(&ClientMessage::ConnectorSetCursorSize { connector, size });
+    }
+
     pub fn connector_get_scale(&self, connector: Connector) -> f64 {
         let res = self.send_with_response(&ClientMessage::ConnectorGetScale { connector });
         get_response!(res, 1.0, ConnectorGetScale { scale });
@@ -1141,6 +1312,10 @@ impl ConfigClient {
         *self.on_idle.borrow_mut() = Some(cb(move |_| f()));
     }
 
+    pub fn on_lock_requested<F: FnMut() + 'static>(&self, mut f: F) {
+        *self.on_lock_requested.borrow_mut() = Some(cb(move |_| f()));
+    }
+
     pub fn on_connector_connected<F: FnMut(Connector) + 'static>(&self, f: F) {
         *self.on_connector_connected.borrow_mut() = Some(cb(f));
     }
@@ -1240,6 +1415,18 @@ impl ConfigClient {
         self.send(&ClientMessage::SetMiddleButtonEmulationEnabled { device, enabled })
     }
 
+    pub fn set_input_scroll_method(&self, device: InputDevice, method: ScrollMethod) {
+        self.send(&ClientMessage::SetScrollMethod { device, method })
+    }
+
+    pub fn set_input_disable_while_typing_enabled(&self, device: InputDevice, enabled: bool) {
+        self.send(&ClientMessage::SetDisableWhileTypingEnabled { device, enabled })
+    }
+
+    pub fn set_input_send_events_mode(&self, device: InputDevice, mode: SendEventsMode) {
+        self.send(&ClientMessage::SetSendEventsMode { device, mode })
+    }
+
     pub fn device_name(&self, device: InputDevice) -> String {
         let res = self.send_with_response(&ClientMessage::GetDeviceName { device });
         get_response!(res, String::new(), GetDeviceName { name });
@@ -1258,6 +1445,12 @@ impl ConfigClient {
         devnode
     }
 
+    pub fn input_device_ids(&self, device: InputDevice) -> (u32, u32) {
+        let res = self.send_with_response(&ClientMessage::GetInputDeviceIds { device });
+        get_response!(res, (0, 0), GetInputDeviceIds { vendor, product });
+        (vendor, product)
+    }
+
     pub fn has_capability(&self, device: InputDevice, cap: Capability) -> bool {
         let res = self.send_with_response(&ClientMessage::HasCapability { device, cap });
         get_response!(res, false, HasCapability { has });
@@ -1290,6 +1483,26 @@ impl ConfigClient {
         self.send(&ClientMessage::SetFocusFollowsMouseMode { seat, mode })
     }
 
+    pub fn set_focus_follows_mouse_delay(&self, seat: Seat, delay: Duration) {
+        self.send(&ClientMessage::SetFocusFollowsMouseDelay { seat, delay })
+    }
+
+    pub fn set_per_window_keyboard_layout(&self, seat: Seat, enabled: bool) {
+        self.send(&ClientMessage::SetPerWindowKeyboardLayout { seat, enabled })
+    }
+
+    pub fn seat_cycle_keyboard_layout(&self, seat: Seat, forward: bool) {
+        self.send(&ClientMessage::SeatCycleKeyboardLayout { seat, forward })
+    }
+
+    pub fn seat_show_workspace_rel(&self, seat: Seat, forward: bool) {
+        self.send(&ClientMessage::SeatShowWorkspaceRel { seat, forward })
+    }
+
+    pub fn seat_repeat_shortcut(&self, seat: Seat) {
+        self.send(&ClientMessage::SeatRepeatShortcut { seat })
+    }
+
     pub fn set_window_management_enabled(&self, seat: Seat, enabled: bool) {
         self.send(&ClientMessage::SetWindowManagementEnabled { seat, enabled })
     }
@@ -1322,6 +1535,19 @@ impl ConfigClient {
         workspace
     }
 
+    pub fn get_workspace_connector(&self, workspace: Workspace) -> Connector {
+        let res = self.send_with_response(&ClientMessage::GetWorkspaceConnector { workspace });
+        get_response!(res, Connector(0), GetWorkspaceConnector { connector });
+        connector
+    }
+
+    pub fn bind_workspace_to_connector(&self, workspace: Workspace, connector: Connector) {
+        self.send(&ClientMessage::BindWorkspaceToConnector {
+            workspace,
+            connector,
+        })
+    }
+
     pub fn get_connector_workspaces(&self, connector: Connector) -> Vec<Workspace> {
         let res = self.send_with_response(&ClientMessage::GetConnectorWorkspaces { connector });
         get_response!(res, vec![], GetConnectorWorkspaces { workspaces });
@@ -1775,6 +2001,17 @@ impl ConfigClient {
         });
     }
 
+    pub fn set_window_matcher_may_inhibit_shortcuts(
+        &self,
+        matcher: WindowMatcher,
+        may_inhibit: bool,
+    ) {
+        self.send(&ClientMessage::SetWindowMatcherMayInhibitShortcuts {
+            matcher,
+            may_inhibit,
+        });
+    }
+
     pub fn set_window_matcher_initial_tile_state(
         &self,
         matcher: WindowMatcher,
@@ -1786,6 +2023,19 @@ impl ConfigClient {
         });
     }
 
+    pub fn set_window_matcher_initial_float_size(
+        &self,
+        matcher: WindowMatcher,
+        width: i32,
+        height: i32,
+    ) {
+        self.send(&ClientMessage::SetWindowMatcherInitialFloatSize {
+            matcher,
+            width,
+            height,
+        });
+    }
+
     pub fn set_window_matcher_latch_handler(
         &self,
         matcher: WindowMatcher,
@@ -2117,6 +2367,18 @@ impl ConfigClient {
                 };
                 cb();
             }
+            ServerMessage::SwipeEvent { seat, event } => {
+                let cb = self.on_swipe_event.borrow().get(&seat).cloned();
+                if let Some(cb) = cb {
+                    run_cb("swipe event", &cb, event);
+                }
+            }
+            ServerMessage::LockRequested => {
+                let handler = self.on_lock_requested.borrow_mut().clone();
+                if let Some(handler) = handler {
+                    run_cb("lock requested", &handler, ());
+                }
+            }
         }
     }
 
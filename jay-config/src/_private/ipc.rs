@@ -1,11 +1,12 @@
 use {
     crate::{
         _private::{ClientCriterionIpc, PollableId, WindowCriterionIpc, WireMode},
-        Axis, Direction, PciId, Workspace,
-        client::{Client, ClientMatcher},
+        Axis, DecorationMode, Direction, FocusStealingPolicy, PciId, Workspace, WorkspaceLayout,
+        client::{Client, ClientCapabilities, ClientMatcher},
         input::{
-            FocusFollowsMouseMode, InputDevice, LayerDirection, Seat, SwitchEvent, Timeline,
-            acceleration::AccelProfile, capability::Capability, clickmethod::ClickMethod,
+            FocusFollowsMouseMode, InputDevice, LayerDirection, Seat, SwipeEvent, SwitchEvent,
+            Timeline, acceleration::AccelProfile, capability::Capability, clickmethod::ClickMethod,
+            scrollmethod::ScrollMethod, sendeventsmode::SendEventsMode,
         },
         keyboard::{Keymap, mods::Modifiers, syms::KeySym},
         logging::LogLevel,
@@ -110,6 +111,11 @@ pub enum ServerMessage {
         matcher: WindowMatcher,
         window: Window,
     },
+    SwipeEvent {
+        seat: Seat,
+        event: SwipeEvent,
+    },
+    LockRequested,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -760,6 +766,189 @@ pub enum ClientMessage<'a> {
         src: u32,
         dst: u32,
     },
+    ConnectorSetCursorSize {
+        connector: Connector,
+        size: Option<i32>,
+    },
+    SetFreezeCursorAnimationWhenIdle {
+        freeze: bool,
+    },
+    SetCursorTheme {
+        seat: Seat,
+        theme: Option<&'a str>,
+    },
+    GetWorkspaceConnector {
+        workspace: Workspace,
+    },
+    BindWorkspaceToConnector {
+        workspace: Workspace,
+        connector: Connector,
+    },
+    SetWindowMatcherMayInhibitShortcuts {
+        matcher: WindowMatcher,
+        may_inhibit: bool,
+    },
+    GetWindowOpacity {
+        window: Window,
+    },
+    SetWindowOpacity {
+        window: Window,
+        opacity: f32,
+    },
+    SetInactiveWindowOpacity {
+        opacity: f32,
+    },
+    SetWorkspaceAnimationDuration {
+        duration: Duration,
+    },
+    SetWindowAnimationDuration {
+        duration: Duration,
+    },
+    SetWindowMatcherInitialFloatSize {
+        matcher: WindowMatcher,
+        width: i32,
+        height: i32,
+    },
+    SetFocusFollowsMouseDelay {
+        seat: Seat,
+        delay: Duration,
+    },
+    SetFocusStealingPolicy {
+        policy: FocusStealingPolicy,
+    },
+    SetPerWindowKeyboardLayout {
+        seat: Seat,
+        enabled: bool,
+    },
+    SeatCycleKeyboardLayout {
+        seat: Seat,
+        forward: bool,
+    },
+    SeatRepeatShortcut {
+        seat: Seat,
+    },
+    GetInputDeviceIds {
+        device: InputDevice,
+    },
+    SetScrollMethod {
+        device: InputDevice,
+        method: ScrollMethod,
+    },
+    SetDisableWhileTypingEnabled {
+        device: InputDevice,
+        enabled: bool,
+    },
+    SetSendEventsMode {
+        device: InputDevice,
+        mode: SendEventsMode,
+    },
+    SeatShowWorkspaceRel {
+        seat: Seat,
+        forward: bool,
+    },
+    SetDefaultWorkspaceLayout {
+        layout: WorkspaceLayout,
+    },
+    SetWorkspaceLayout {
+        workspace: Workspace,
+        layout: WorkspaceLayout,
+    },
+    SetWorkspaceInnerGap {
+        workspace: Workspace,
+        gap: Option<i32>,
+    },
+    SetWorkspaceOuterGap {
+        workspace: Workspace,
+        gap: Option<i32>,
+    },
+    GetWorkspaceGaps {
+        workspace: Workspace,
+    },
+    SeatMoveWindowToOutput {
+        seat: Seat,
+        connector: Connector,
+    },
+    SeatMoveWindowToAdjacentOutput {
+        seat: Seat,
+        direction: Direction,
+    },
+    SeatMoveToScratchpad {
+        seat: Seat,
+        name: &'a str,
+    },
+    SeatToggleScratchpad {
+        seat: Seat,
+        name: &'a str,
+    },
+    SetSwallowAppIds {
+        app_ids: Vec<String>,
+    },
+    SetClipboardPersistenceEnabled {
+        enabled: bool,
+    },
+    SetClipboardPersistenceMaxSize {
+        max_size: u64,
+    },
+    SetShowFloatCloseIcon {
+        show: bool,
+    },
+    SetDecorationMode {
+        mode: DecorationMode,
+    },
+    SetClientObjectLimit {
+        limit: Option<u64>,
+    },
+    SetClientShmLimit {
+        limit: Option<u64>,
+    },
+    SetSandboxCapabilities {
+        grants: Vec<(String, ClientCapabilities)>,
+    },
+    ConnectorSetMaxFps {
+        connector: Connector,
+        max_fps: Option<f64>,
+    },
+    SetLowPowerMode {
+        enabled: bool,
+    },
+    SetLowPowerMaxFps {
+        max_fps: Option<f64>,
+    },
+    SetIdleDim {
+        dim: f32,
+    },
+    SeatWarpPointer {
+        seat: Seat,
+        x: f64,
+        y: f64,
+    },
+    SeatWarpPointerToConnector {
+        seat: Seat,
+        connector: Connector,
+    },
+    SeatWarpPointerToWindow {
+        seat: Seat,
+        window: Window,
+    },
+    SetCursorFollowsFocus {
+        seat: Seat,
+        enabled: bool,
+    },
+    SetHideCursorWhileTyping {
+        seat: Seat,
+        enabled: bool,
+    },
+    ConnectorSetZoom {
+        connector: Connector,
+        zoom: f64,
+    },
+    SeatAdjustZoom {
+        seat: Seat,
+        factor: f64,
+    },
+    SeatResetZoom {
+        seat: Seat,
+    },
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -992,6 +1181,20 @@ pub enum Response {
     GetShowBar {
         show: bool,
     },
+    GetWorkspaceConnector {
+        connector: Connector,
+    },
+    GetWindowOpacity {
+        opacity: f32,
+    },
+    GetInputDeviceIds {
+        vendor: u32,
+        product: u32,
+    },
+    GetWorkspaceGaps {
+        inner: i32,
+        outer: i32,
+    },
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -38,6 +38,36 @@ pub fn clients() -> Vec<Client> {
     get!().clients()
 }
 
+bitflags! {
+    /// Capabilities that can be granted to sandboxed clients.
+    #[derive(Serialize, Deserialize, Copy, Clone, Hash, Eq, PartialEq, Default)]
+    pub struct ClientCapabilities(pub u64) {
+        /// Allows the client to capture the contents of outputs and windows.
+        pub const CAP_SCREENCOPY = 1 << 0,
+        /// Allows the client to emulate keyboard input.
+        pub const CAP_VIRTUAL_KEYBOARD = 1 << 1,
+        /// Allows the client to act as an input method.
+        pub const CAP_INPUT_METHOD = 1 << 2,
+        /// Allows the client to access the clipboard without focus.
+        pub const CAP_DATA_CONTROL = 1 << 3,
+    }
+}
+
+/// Grants capabilities to sandboxed clients based on their app id.
+///
+/// Sandboxed clients are clients that connect through a socket created with the
+/// security-context protocol. By default, they do not have access to privileged
+/// protocols. This function grants additional capabilities to sandboxed clients whose
+/// app id, as reported by the sandbox engine, matches one of the entries.
+///
+/// The granted capabilities are still limited by the capabilities of the client that
+/// created the sandbox. Changes only apply to sockets created afterwards.
+///
+/// Replaces all previously granted capabilities.
+pub fn set_sandbox_capabilities(grants: &[(&str, ClientCapabilities)]) {
+    get!().set_sandbox_capabilities(grants);
+}
+
 /// A client matcher.
 #[derive(Serialize, Deserialize, Copy, Clone, Debug, Hash, Eq, PartialEq)]
 pub struct ClientMatcher(pub u64);
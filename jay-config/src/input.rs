@@ -3,12 +3,17 @@
 pub mod acceleration;
 pub mod capability;
 pub mod clickmethod;
+pub mod scrollmethod;
+pub mod sendeventsmode;
 
 use {
     crate::{
         _private::{DEFAULT_SEAT_NAME, ipc::WorkspaceSource},
         Axis, Direction, ModifiedKeySym, Workspace,
-        input::{acceleration::AccelProfile, capability::Capability, clickmethod::ClickMethod},
+        input::{
+            acceleration::AccelProfile, capability::Capability, clickmethod::ClickMethod,
+            scrollmethod::ScrollMethod, sendeventsmode::SendEventsMode,
+        },
         keyboard::{Keymap, mods::Modifiers, syms::KeySym},
         video::Connector,
         window::Window,
@@ -148,6 +153,29 @@ impl InputDevice {
         get!().set_input_middle_button_emulation_enabled(self, enabled);
     }
 
+    /// Sets the scroll method of the device.
+    ///
+    /// See <https://wayland.freedesktop.org/libinput/doc/latest/scrolling.html>
+    pub fn set_scroll_method(self, method: ScrollMethod) {
+        get!().set_input_scroll_method(self, method);
+    }
+
+    /// Sets whether the device is disabled while typing.
+    ///
+    /// See <https://wayland.freedesktop.org/libinput/doc/latest/palm-detection.html#disable-while-typing>
+    pub fn set_disable_while_typing_enabled(self, enabled: bool) {
+        get!().set_input_disable_while_typing_enabled(self, enabled);
+    }
+
+    /// Sets when the device sends events.
+    ///
+    /// This can be used to disable a touchpad while an external mouse is connected.
+    ///
+    /// See <https://wayland.freedesktop.org/libinput/doc/latest/configuration.html#send-events-mode>
+    pub fn set_send_events_mode(self, mode: SendEventsMode) {
+        get!().set_input_send_events_mode(self, mode);
+    }
+
     /// Returns the syspath of this device.
     ///
     /// E.g. `/sys/devices/pci0000:00/0000:00:08.1/0000:14:00.4/usb5/5-1/5-1.1/5-1.1.3/5-1.1.3:1.0`.
@@ -162,6 +190,20 @@ impl InputDevice {
         get!(String::new()).input_device_devnode(self)
     }
 
+    /// Returns the USB vendor ID of this device.
+    ///
+    /// Returns `0` if the ID is not known.
+    pub fn vendor_id(self) -> u32 {
+        get!(0).input_device_ids(self).0
+    }
+
+    /// Returns the USB product ID of this device.
+    ///
+    /// Returns `0` if the ID is not known.
+    pub fn product_id(self) -> u32 {
+        get!(0).input_device_ids(self).1
+    }
+
     /// Sets a callback that will be run if this device triggers a switch event.
     pub fn on_switch_event<F: FnMut(SwitchEvent) + 'static>(self, f: F) {
         get!().on_switch_event(self, f)
@@ -235,6 +277,16 @@ impl Seat {
         get!().set_cursor_size(self, size)
     }
 
+    /// Sets the cursor theme of this seat.
+    ///
+    /// This allows different seats to use different cursor themes. If `theme` is `None`,
+    /// the theme from the `XCURSOR_THEME` environment variable is used.
+    ///
+    /// Default: `None`.
+    pub fn set_cursor_theme(self, theme: Option<&str>) {
+        get!().set_cursor_theme(self, theme)
+    }
+
     /// Creates a compositor-wide hotkey.
     ///
     /// The closure is invoked when the user presses the last key of the modified keysym.
@@ -282,6 +334,17 @@ impl Seat {
         get!().latch(self, f)
     }
 
+    /// Makes the currently invoked shortcut repeat while its key is held down.
+    ///
+    /// This should only be called in callbacks for key-press binds.
+    ///
+    /// The shortcut is invoked again according to the repeat rate of the seat, see
+    /// [`Seat::set_repeat_rate`]. Repetition stops when the key is released or another
+    /// key is pressed.
+    pub fn repeat_shortcut(self) {
+        get!().seat_repeat_shortcut(self)
+    }
+
     /// Unbinds a hotkey.
     pub fn unbind<T: Into<ModifiedKeySym>>(self, mod_sym: T) {
         get!().unbind(self, mod_sym.into())
@@ -439,11 +502,44 @@ impl Seat {
         get!().show_workspace(self, workspace)
     }
 
+    /// Shows the workspace after the active workspace on the output that contains the seat's
+    /// cursor.
+    ///
+    /// If the active workspace is the last workspace on the output, this has no effect.
+    pub fn show_next_workspace(self) {
+        get!().seat_show_workspace_rel(self, true)
+    }
+
+    /// Shows the workspace before the active workspace on the output that contains the seat's
+    /// cursor.
+    ///
+    /// If the active workspace is the first workspace on the output, this has no effect.
+    pub fn show_prev_workspace(self) {
+        get!().seat_show_workspace_rel(self, false)
+    }
+
     /// Moves the currently focused window to the workspace.
     pub fn set_workspace(self, workspace: Workspace) {
         get!().set_seat_workspace(self, workspace)
     }
 
+    /// Moves the currently focused window to the scratchpad with the given name.
+    ///
+    /// The window is hidden until the scratchpad is toggled with `toggle_scratchpad`. If
+    /// the scratchpad already contains a hidden window, that window is shown instead.
+    pub fn move_to_scratchpad(self, name: &str) {
+        get!().seat_move_to_scratchpad(self, name)
+    }
+
+    /// Toggles the visibility of the window in the scratchpad with the given name.
+    ///
+    /// If the window is hidden, it is shown as a floating window on the output that
+    /// contains the seat's cursor, at the position it had when it was hidden. Otherwise
+    /// it is moved back to the scratchpad.
+    pub fn toggle_scratchpad(self, name: &str) {
+        get!().seat_toggle_scratchpad(self, name)
+    }
+
     /// Toggles whether the currently focused window is fullscreen.
     pub fn toggle_fullscreen(self) {
         let c = get!();
@@ -464,11 +560,47 @@ impl Seat {
         get!().disable_pointer_constraint(self)
     }
 
+    /// Moves the pointer of this seat to a position in the global compositor space.
+    ///
+    /// The pointer focus is updated as if the pointer had been moved by the user. This
+    /// has no effect while the pointer is locked by an application.
+    pub fn warp_pointer(self, x: f64, y: f64) {
+        get!().seat_warp_pointer(self, x, y)
+    }
+
+    /// Moves the pointer of this seat to the center of the output.
+    ///
+    /// This has no effect while the pointer is locked by an application.
+    pub fn warp_pointer_to_connector(self, connector: Connector) {
+        get!().seat_warp_pointer_to_connector(self, connector)
+    }
+
+    /// Moves the pointer of this seat to the center of the window.
+    ///
+    /// This has no effect if the window is not visible or while the pointer is locked by
+    /// an application.
+    pub fn warp_pointer_to_window(self, window: Window) {
+        get!().seat_warp_pointer_to_window(self, window)
+    }
+
     /// Moves the currently focused workspace to another output.
     pub fn move_to_output(self, connector: Connector) {
         get!().move_to_output(WorkspaceSource::Seat(self), connector);
     }
 
+    /// Moves the currently focused window to the active workspace of another output.
+    pub fn move_window_to_output(self, connector: Connector) {
+        get!().seat_move_window_to_output(self, connector);
+    }
+
+    /// Moves the currently focused window to the active workspace of the output next to
+    /// the output of the window.
+    ///
+    /// If there is no output in that direction, this has no effect.
+    pub fn move_window_to_adjacent_output(self, direction: Direction) {
+        get!().seat_move_window_to_adjacent_output(self, direction);
+    }
+
     /// Set whether the current key event is forwarded to the focused client.
     ///
     /// This only has an effect if called from a keyboard shortcut.
@@ -494,6 +626,77 @@ impl Seat {
         get!().set_focus_follows_mouse_mode(self, mode);
     }
 
+    /// Sets how long the mouse has to stay over a window before it gets the keyboard
+    /// focus in focus-follows-mouse mode.
+    ///
+    /// This avoids accidental focus changes while moving the mouse across windows. The
+    /// default is `0`, which moves the focus immediately.
+    pub fn set_focus_follows_mouse_delay(self, delay: Duration) {
+        get!().set_focus_follows_mouse_delay(self, delay);
+    }
+
+    /// Sets whether each window remembers its own keyboard layout.
+    ///
+    /// If this is enabled, the active layout is saved when a window loses the keyboard
+    /// focus and restored when it gets the focus again. Windows that have never been
+    /// focused use the current layout.
+    ///
+    /// The default is `false`.
+    pub fn set_per_window_keyboard_layout(self, enabled: bool) {
+        get!().set_per_window_keyboard_layout(self, enabled);
+    }
+
+    /// Sets whether the pointer moves to the center of a window when the window gets
+    /// the keyboard focus.
+    ///
+    /// Focus changes caused by the pointer, for example by clicking on a window or via
+    /// focus-follows-mouse, do not move the pointer. The pointer is also not moved if
+    /// it is already inside the window.
+    ///
+    /// The default is `false`.
+    pub fn set_cursor_follows_focus(self, enabled: bool) {
+        get!().set_cursor_follows_focus(self, enabled);
+    }
+
+    /// Sets whether the cursor is hidden while typing.
+    ///
+    /// If this is enabled, the cursor is hidden when a key other than a modifier key is
+    /// pressed without any modifiers other than shift. It is shown again when the
+    /// pointer is moved by a few pixels or when a button is pressed.
+    ///
+    /// The default is `false`.
+    pub fn set_hide_cursor_while_typing(self, enabled: bool) {
+        get!().set_hide_cursor_while_typing(self, enabled);
+    }
+
+    /// Multiplies the magnification of the output that contains the cursor by `factor`.
+    ///
+    /// See [`Connector::set_zoom`].
+    pub fn adjust_zoom(self, factor: f64) {
+        get!().seat_adjust_zoom(self, factor);
+    }
+
+    /// Disables the magnification of the output that contains the cursor.
+    pub fn reset_zoom(self) {
+        get!().seat_reset_zoom(self);
+    }
+
+    /// Switches to the next layout of the keymap.
+    ///
+    /// Keymaps with multiple layouts can be created by using multiple groups in the
+    /// `xkb_symbols` section, for example `pc+us+de:2+inet(evdev)`. After the last
+    /// layout, this wraps around to the first layout.
+    pub fn next_keyboard_layout(self) {
+        get!().seat_cycle_keyboard_layout(self, true);
+    }
+
+    /// Switches to the previous layout of the keymap.
+    ///
+    /// Before the first layout, this wraps around to the last layout.
+    pub fn prev_keyboard_layout(self) {
+        get!().seat_cycle_keyboard_layout(self, false);
+    }
+
     /// Enables or disable window management mode.
     ///
     /// In window management mode, floating windows can be moved by pressing the left
@@ -594,6 +797,17 @@ impl Seat {
     pub fn copy_mark(self, src: u32, dst: u32) {
         get!().seat_copy_mark(self, src, dst);
     }
+
+    /// Sets a callback that will be run for swipe gestures that are not consumed by a
+    /// client.
+    ///
+    /// A gesture is consumed by a client if the pointer is over one of its surfaces when
+    /// the gesture begins and the client has subscribed to swipe gestures. All other
+    /// swipe gestures are forwarded to this callback. This can be used, for example, to
+    /// switch workspaces with a three-finger swipe.
+    pub fn on_swipe_event<F: FnMut(SwipeEvent) + 'static>(self, f: F) {
+        get!().on_swipe_event(self, f)
+    }
 }
 
 /// A focus-follows-mouse mode.
@@ -707,6 +921,28 @@ pub enum SwitchEvent {
     ConvertedToTablet,
 }
 
+/// An event generated by a touchpad swipe gesture.
+///
+/// See [`Seat::on_swipe_event`].
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq)]
+pub enum SwipeEvent {
+    /// A swipe gesture has begun.
+    Begin {
+        /// The number of fingers on the touchpad.
+        finger_count: u32,
+    },
+    /// The fingers have moved.
+    ///
+    /// The deltas are relative to the previous event and use the same units as pointer
+    /// motion.
+    Update { dx: f64, dy: f64 },
+    /// The swipe gesture has ended.
+    End {
+        /// Whether the gesture was cancelled, e.g. because a finger was lifted early.
+        cancelled: bool,
+    },
+}
+
 /// Enables or disables the unauthenticated libei socket.
 ///
 /// Even if the socket is disabled, application can still request access via the portal.
@@ -0,0 +1,21 @@
+//! Constants determining the scroll method of a device.
+//!
+//! See the libinput documentation for details.
+
+use serde::{Deserialize, Serialize};
+
+/// The scroll method of a device.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct ScrollMethod(pub u32);
+
+/// Never send scroll events
+pub const SCROLL_METHOD_NO_SCROLL: ScrollMethod = ScrollMethod(0);
+
+/// Two-finger scrolling
+pub const SCROLL_METHOD_TWO_FINGER: ScrollMethod = ScrollMethod(1 << 0);
+
+/// Edge scrolling
+pub const SCROLL_METHOD_EDGE: ScrollMethod = ScrollMethod(1 << 1);
+
+/// Scrolling while a button is held down
+pub const SCROLL_METHOD_ON_BUTTON_DOWN: ScrollMethod = ScrollMethod(1 << 2);
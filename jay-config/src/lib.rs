@@ -143,10 +143,32 @@ pub fn toggle_default_workspace_capture() {
     get.set_default_workspace_capture(!get.get_default_workspace_capture());
 }
 
+/// Sets the layout of newly created workspaces.
+///
+/// The default is [`WorkspaceLayout::MANUAL`].
+pub fn set_default_workspace_layout(layout: WorkspaceLayout) {
+    get!().set_default_workspace_layout(layout)
+}
+
 /// A workspace.
 #[derive(Serialize, Deserialize, Copy, Clone, Debug, Hash, Eq, PartialEq)]
 pub struct Workspace(pub u64);
 
+/// Determines where new tiled windows are placed on a workspace.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct WorkspaceLayout(pub u32);
+
+impl WorkspaceLayout {
+    /// New windows are placed next to the last active window in its container.
+    pub const MANUAL: Self = Self(0);
+    /// New windows split the last active window in two along its longer side.
+    pub const BSP: Self = Self(1);
+    /// New windows are added as tabs to the container of the last active window.
+    ///
+    /// The container is switched to mono mode if it is not already in mono mode.
+    pub const TABBED: Self = Self(2);
+}
+
 impl Workspace {
     /// Returns whether this workspace existed at the time `Seat::get_workspace` was called.
     pub fn exists(self) -> bool {
@@ -171,6 +193,38 @@ impl Workspace {
         get.set_workspace_capture(self, !get.get_workspace_capture(self));
     }
 
+    /// Sets the layout that determines where new tiled windows are placed on this
+    /// workspace.
+    ///
+    /// The default is determined by `set_default_workspace_layout`.
+    pub fn set_layout(self, layout: WorkspaceLayout) {
+        get!().set_workspace_layout(self, layout)
+    }
+
+    /// Sets the gap between tiled windows on this workspace.
+    ///
+    /// If `None` is passed, the workspace uses the global `INNER_GAP` size.
+    pub fn set_inner_gap(self, gap: Option<i32>) {
+        get!().set_workspace_inner_gap(self, gap)
+    }
+
+    /// Sets the gap between tiled windows and the edges of this workspace.
+    ///
+    /// If `None` is passed, the workspace uses the global `OUTER_GAP` size.
+    pub fn set_outer_gap(self, gap: Option<i32>) {
+        get!().set_workspace_outer_gap(self, gap)
+    }
+
+    /// Returns the gap between tiled windows on this workspace.
+    pub fn inner_gap(self) -> i32 {
+        get!(0).get_workspace_gaps(self).0
+    }
+
+    /// Returns the gap between tiled windows and the edges of this workspace.
+    pub fn outer_gap(self) -> i32 {
+        get!(0).get_workspace_gaps(self).1
+    }
+
     /// Moves this workspace to another output.
     ///
     /// This has no effect if the workspace is not currently being shown.
@@ -178,6 +232,24 @@ impl Workspace {
         get!().move_to_output(WorkspaceSource::Explicit(self), output);
     }
 
+    /// Returns the connector that this workspace is currently shown on.
+    ///
+    /// If the workspace does not exist or is not on any connector, returns a connector
+    /// whose `exists()` returns false.
+    pub fn connector(self) -> Connector {
+        get!(Connector(0)).get_workspace_connector(self)
+    }
+
+    /// Binds this workspace to an output.
+    ///
+    /// The workspace is moved to the output immediately and every time the output is
+    /// connected again. If the workspace does not exist yet, it will be created on the
+    /// output. The output is identified by its connector and monitor, so the binding
+    /// survives the output being disconnected.
+    pub fn bind_to_output(self, output: Connector) {
+        get!().bind_workspace_to_connector(self, output);
+    }
+
     /// Returns the root container of this workspace.
     ///
     /// If no such container exists, [`Window::exists`] returns false.
@@ -216,6 +288,15 @@ pub fn on_idle<F: FnMut() + 'static>(f: F) {
     get!().on_idle(f)
 }
 
+/// Sets the callback to be called when the session manager requests that the session be
+/// locked.
+///
+/// This happens, for example, when `loginctl lock-session` is run. The callback should
+/// usually start a screen locker.
+pub fn on_lock_requested<F: FnMut() + 'static>(f: F) {
+    get!().on_lock_requested(f)
+}
+
 /// Sets the callback to be called when all devices have been enumerated.
 ///
 /// This callback is only invoked once during the lifetime of the compositor. This is a
@@ -243,6 +324,22 @@ pub fn set_idle(timeout: Option<Duration>) {
     get!().set_idle(timeout.unwrap_or_default())
 }
 
+/// Configures whether animated cursors stop animating while the compositor or the pointer
+/// is idle.
+///
+/// The compositor is considered idle during the idle grace period and after the idle
+/// timeout has expired. The pointer is considered idle after it has not moved for 10
+/// seconds. Freezing the animation avoids waking up the compositor for every frame of the
+/// animation while nobody is looking at the cursor. The animation resumes on the next
+/// pointer motion.
+///
+/// Hidden cursors are never animated, regardless of this setting.
+///
+/// The default is `false`.
+pub fn set_freeze_cursor_animation_when_idle(freeze: bool) {
+    get!().set_freeze_cursor_animation_when_idle(freeze)
+}
+
 /// Configures the idle grace period.
 ///
 /// The grace period starts after the idle timeout expires. During the grace period, the
@@ -255,6 +352,17 @@ pub fn set_idle_grace_period(timeout: Duration) {
     get!().set_idle_grace_period(timeout)
 }
 
+/// Configures how much the outputs are dimmed during the idle grace period.
+///
+/// The value is the opacity of a black layer drawn over all outputs and should be in
+/// the range 0 to 1. With `1`, the screen goes black. Smaller values dim the screen so
+/// that its contents remain visible until the outputs are disabled.
+///
+/// The default is `1`.
+pub fn set_idle_dim(dim: f32) {
+    get!().set_idle_dim(dim)
+}
+
 /// Enables or disables explicit sync.
 ///
 /// Calling this after the compositor has started has no effect.
@@ -306,6 +414,168 @@ pub fn toggle_float_above_fullscreen() {
     set_float_above_fullscreen(!get_float_above_fullscreen())
 }
 
+/// Sets the opacity of windows that do not have the keyboard focus.
+///
+/// This is multiplied with the opacity of the window. Fullscreen windows are not
+/// affected.
+///
+/// The value is clamped to `[0.0, 1.0]`. The default is `1.0`.
+pub fn set_inactive_window_opacity(opacity: f32) {
+    get!().set_inactive_window_opacity(opacity);
+}
+
+/// Sets the app-ids of terminals that swallow the windows they spawn.
+///
+/// When a window is mapped as a tiled window and one of its ancestor processes owns a
+/// window with one of these app-ids, the terminal window is hidden and replaced by the
+/// new window. The terminal is restored when the new window is closed.
+///
+/// Only Wayland clients can be swallowed since the process of X windows is unknown.
+///
+/// The default is an empty list, which disables swallowing.
+pub fn set_swallow_app_ids(app_ids: &[&str]) {
+    get!().set_swallow_app_ids(app_ids);
+}
+
+/// Enables or disables clipboard persistence.
+///
+/// If this is enabled, the compositor keeps a copy of the contents of the clipboard
+/// and continues to offer them after the client that owns the clipboard has exited.
+///
+/// The default is `false`.
+pub fn set_clipboard_persistence_enabled(enabled: bool) {
+    get!().set_clipboard_persistence_enabled(enabled);
+}
+
+/// Sets the maximum number of bytes that are copied for clipboard persistence.
+///
+/// The limit applies to the sum of all MIME types offered by the clipboard. Clipboard
+/// contents that exceed this limit are not persisted.
+///
+/// The default is 8 MiB.
+pub fn set_clipboard_persistence_max_size(bytes: u64) {
+    get!().set_clipboard_persistence_max_size(bytes);
+}
+
+/// Sets the maximum number of objects a single client can have.
+///
+/// Clients that exceed this limit are disconnected. Xwayland is exempt from this limit.
+///
+/// The default is `None` (no limit).
+pub fn set_client_object_limit(limit: Option<u64>) {
+    get!().set_client_object_limit(limit);
+}
+
+/// Sets the maximum number of bytes of shared memory a single client can have mapped.
+///
+/// Memory of a destroyed pool is counted until all buffers created from it have been
+/// destroyed.
+///
+/// Clients that exceed this limit are disconnected. Xwayland is exempt from this limit.
+///
+/// The default is `None` (no limit).
+pub fn set_client_shm_limit(bytes: Option<u64>) {
+    get!().set_client_shm_limit(bytes);
+}
+
+/// Sets the duration of the animation played when switching workspaces.
+///
+/// The new workspace slides in from the side of the output while the old workspace
+/// slides out. Switches involving fullscreen workspaces are not animated.
+///
+/// The default is `0`, which disables the animation.
+pub fn set_workspace_animation_duration(duration: Duration) {
+    get!().set_workspace_animation_duration(duration);
+}
+
+/// Sets the duration of the animations played when a window is opened or closed.
+///
+/// Opened windows fade in and grow to their regular size. Closed windows fade out and
+/// shrink. Fullscreen windows are not animated.
+///
+/// The default is `0`, which disables the animation.
+pub fn set_window_animation_duration(duration: Duration) {
+    get!().set_window_animation_duration(duration);
+}
+
+/// Enables or disables the low-power mode.
+///
+/// While the low-power mode is enabled, the compositor does not play animations, does
+/// not draw shadows, and limits the frame rate of all outputs to the value set with
+/// [`set_low_power_max_fps`]. This reduces the GPU usage, for example while running on
+/// battery.
+///
+/// The mode can also be toggled at runtime with `jay low-power`.
+///
+/// The default is `false`.
+pub fn set_low_power_mode(enabled: bool) {
+    get!().set_low_power_mode(enabled);
+}
+
+/// Sets the frame rate cap that applies to all outputs in low-power mode.
+///
+/// If an output has a lower cap configured, that cap is used instead. When `max_fps`
+/// is `None`, zero, or infinite, the low-power mode does not cap the frame rate.
+///
+/// The default is `30`.
+pub fn set_low_power_max_fps(max_fps: Option<f64>) {
+    get!().set_low_power_max_fps(max_fps);
+}
+
+/// A policy for focus changes requested by applications via xdg-activation.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct FocusStealingPolicy(pub u32);
+
+impl FocusStealingPolicy {
+    /// Activation requests never move the focus. The window is marked as urgent instead.
+    pub const NEVER: Self = Self(0);
+    /// Activation requests with a valid token always move the focus.
+    pub const ALWAYS: Self = Self(1);
+    /// Activation requests move the focus if the token was created by the application
+    /// that owns the window.
+    pub const SAME_APP: Self = Self(2);
+    /// Activation requests move the focus if the application that created the token
+    /// received user input shortly before the request.
+    pub const SMART: Self = Self(3);
+}
+
+/// Sets the policy that decides whether activation requests move the keyboard focus.
+///
+/// Requests that do not move the focus mark the window as urgent.
+///
+/// The default is [`FocusStealingPolicy::NEVER`].
+pub fn set_focus_stealing_policy(policy: FocusStealingPolicy) {
+    get!().set_focus_stealing_policy(policy);
+}
+
+/// A policy for choosing between client-side and server-side decorations.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct DecorationMode(pub u32);
+
+impl DecorationMode {
+    /// Windows use the mode requested by the application. Applications that don't
+    /// request a mode use server-side decorations.
+    pub const CLIENT_PREFERENCE: Self = Self(0);
+    /// All windows use server-side decorations.
+    pub const SERVER_SIDE: Self = Self(1);
+    /// All windows use client-side decorations.
+    pub const CLIENT_SIDE: Self = Self(2);
+}
+
+/// Sets the policy that decides whether windows draw their own decorations.
+///
+/// This only affects applications that use the xdg-decoration protocol. The compositor
+/// always draws its own title bars and borders around windows, so windows that use
+/// client-side decorations show both.
+///
+/// The new policy applies to windows that negotiate their decoration mode after this
+/// call.
+///
+/// The default is [`DecorationMode::SERVER_SIDE`].
+pub fn set_decoration_mode(mode: DecorationMode) {
+    get!().set_decoration_mode(mode);
+}
+
 /// Sets whether floating windows always show a pin icon.
 ///
 /// Clicking on the pin icon toggles the pin mode. See [`Seat::toggle_float_pinned`].
@@ -316,6 +586,16 @@ pub fn set_show_float_pin_icon(show: bool) {
     get!().set_show_float_pin_icon(show);
 }
 
+/// Sets whether floating windows show a close icon.
+///
+/// The icon is shown at the right end of the title bar. Clicking on it asks the window
+/// to close.
+///
+/// The default is `false`.
+pub fn set_show_float_close_icon(show: bool) {
+    get!().set_show_float_close_icon(show);
+}
+
 /// Sets whether the built-in bar is shown.
 ///
 /// The default is `true`.
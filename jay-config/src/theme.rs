@@ -264,6 +264,10 @@ pub mod colors {
         ///
         /// Default: `#9d28c67f`.
         const 15 => HIGHLIGHT_COLOR,
+        /// The color of the shadow drawn behind floating windows.
+        ///
+        /// Default: `#00000080`.
+        const 16 => SHADOW_COLOR,
     }
 
     /// Sets the color of GUI element.
@@ -315,5 +319,31 @@ pub mod sized {
         ///
         /// Default: 4
         const 02 => BORDER_WIDTH,
+        /// The size of the shadow drawn behind floating windows.
+        ///
+        /// The shadow fades out over this many pixels. A value of 0 disables the shadow.
+        ///
+        /// Default: 0
+        const 03 => SHADOW_SIZE,
+        /// The horizontal offset of the shadow drawn behind floating windows.
+        ///
+        /// Default: 0
+        const 04 => SHADOW_OFFSET_X,
+        /// The vertical offset of the shadow drawn behind floating windows.
+        ///
+        /// Default: 0
+        const 05 => SHADOW_OFFSET_Y,
+        /// The gap between tiled windows.
+        ///
+        /// This can be overridden per workspace with `Workspace::set_inner_gap`.
+        ///
+        /// Default: 0
+        const 06 => INNER_GAP,
+        /// The gap between tiled windows and the edges of the workspace.
+        ///
+        /// This can be overridden per workspace with `Workspace::set_outer_gap`.
+        ///
+        /// Default: 0
+        const 07 => OUTER_GAP,
     }
 }
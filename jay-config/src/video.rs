@@ -302,6 +302,42 @@ impl Connector {
         get!().connector_set_brightness(self, brightness);
     }
 
+    /// Sets the cursor size to use while the cursor is on this output.
+    ///
+    /// The size is in logical pixels and is scaled by the output scale. When `size` is
+    /// `None`, the cursor size of the seat is used. See [`Seat::set_cursor_size`].
+    ///
+    /// This is useful on setups with mixed DPIs where a single cursor size looks too
+    /// small or too large on some outputs.
+    ///
+    /// [`Seat::set_cursor_size`]: crate::input::Seat::set_cursor_size
+    pub fn set_cursor_size(self, size: Option<i32>) {
+        get!().connector_set_cursor_size(self, size);
+    }
+
+    /// Limits the number of frames presented per second on this output.
+    ///
+    /// Page flips are scheduled no more often than this, skipping refresh cycles of
+    /// the display if necessary. Frame callbacks are sent at the capped rate. When
+    /// `max_fps` is `None`, zero, or infinite, the frame rate is not capped.
+    ///
+    /// The cap has no effect while VRR is active.
+    pub fn set_max_fps(self, max_fps: Option<f64>) {
+        get!().connector_set_max_fps(self, max_fps);
+    }
+
+    /// Sets the magnification of this output.
+    ///
+    /// The magnified view is centered on the cursor and follows it as it moves. While
+    /// the output is magnified, the cursor is always rendered as part of the output so
+    /// that it is magnified as well.
+    ///
+    /// The zoom is clamped to the range `[1, 32]`. A zoom of 1 disables the
+    /// magnification.
+    pub fn set_zoom(self, zoom: f64) {
+        get!().connector_set_zoom(self, zoom);
+    }
+
     /// Get the currently visible/active workspace.
     ///
     /// If this connector is not connected, or is there no active workspace, returns a
@@ -566,6 +602,11 @@ impl DrmDevice {
 pub enum GfxApi {
     OpenGl,
     Vulkan,
+    /// A CPU renderer.
+    ///
+    /// This API is slow and does not support color management. It is used as a last
+    /// resort if neither OpenGL nor Vulkan can be initialized.
+    Software,
 }
 
 /// Sets the default graphics API.
@@ -193,6 +193,21 @@ impl Window {
         self.set_floating(!self.floating());
     }
 
+    /// Returns the opacity of the window.
+    pub fn opacity(self) -> f32 {
+        get!(1.0).get_window_opacity(self)
+    }
+
+    /// Sets the opacity of the window.
+    ///
+    /// The value is clamped to `[0.0, 1.0]`. Fullscreen windows are always drawn fully
+    /// opaque.
+    ///
+    /// The default is `1.0`.
+    pub fn set_opacity(self, opacity: f32) {
+        get!().set_window_opacity(self, opacity);
+    }
+
     /// Returns the workspace that this window belongs to.
     ///
     /// If no such workspace exists, `exists` returns `false` for the returned workspace.
@@ -349,6 +364,25 @@ impl WindowCriterion<'_> {
     pub fn set_initial_tile_state(self, tile_state: TileState) {
         self.to_matcher().set_initial_tile_state(tile_state);
     }
+
+    /// Sets the size of windows that match this criterion when they first become
+    /// floating.
+    ///
+    /// See [`WindowMatcher::set_initial_float_size`].
+    ///
+    /// This leaks the matcher.
+    pub fn set_initial_float_size(self, width: i32, height: i32) {
+        self.to_matcher().set_initial_float_size(width, height);
+    }
+
+    /// Sets whether windows that match this criterion may inhibit compositor shortcuts.
+    ///
+    /// See [`WindowMatcher::set_may_inhibit_shortcuts`].
+    ///
+    /// This leaks the matcher.
+    pub fn set_may_inhibit_shortcuts(self, may_inhibit: bool) {
+        self.to_matcher().set_may_inhibit_shortcuts(may_inhibit);
+    }
 }
 
 impl WindowMatcher {
@@ -382,6 +416,29 @@ impl WindowMatcher {
     pub fn set_initial_tile_state(self, tile_state: TileState) {
         get!().set_window_matcher_initial_tile_state(self, tile_state);
     }
+
+    /// Sets the size of windows that match this matcher when they first become floating.
+    ///
+    /// The size does not include the window decorations. It replaces the default of half
+    /// the size of the output and is still subject to the size limits of the window. Once
+    /// a window has been floating, it remembers its last floating size instead.
+    ///
+    /// If multiple such window matchers match a window, the used size is unspecified.
+    pub fn set_initial_float_size(self, width: i32, height: i32) {
+        get!().set_window_matcher_initial_float_size(self, width, height);
+    }
+
+    /// Sets whether windows that match this matcher may inhibit compositor shortcuts.
+    ///
+    /// Clients such as virtual machines and remote desktop clients can use the
+    /// keyboard-shortcuts-inhibit protocol to receive all key presses, including those
+    /// that would otherwise trigger shortcuts. This is only allowed for windows that
+    /// match at least one matcher for which this is true.
+    ///
+    /// The default is `false`.
+    pub fn set_may_inhibit_shortcuts(self, may_inhibit: bool) {
+        get!().set_window_matcher_may_inhibit_shortcuts(self, may_inhibit);
+    }
 }
 
 impl MatchedWindow {
@@ -51,7 +51,6 @@ pub trait BufferObject {
 
 pub trait MappedBuffer {
     unsafe fn data(&self) -> &[u8];
-    #[cfg_attr(not(feature = "it"), expect(dead_code))]
     fn data_ptr(&self) -> *mut u8;
     fn stride(&self) -> i32;
 }
@@ -9,7 +9,7 @@ use {
         drm_feedback::DrmFeedback,
         fixed::Fixed,
         format::Format,
-        gfx_api::{GfxFramebuffer, SyncFile},
+        gfx_api::{GfxFormat, GfxFramebuffer, SyncFile},
         ifs::{
             wl_output::OutputId,
             wl_seat::{
@@ -28,6 +28,7 @@ use {
             HDMI_EOTF_TRADITIONAL_GAMMA_SDR,
         },
     },
+    ahash::AHashMap,
     jay_config::{input::SwitchEvent, video::GfxApi},
     linearize::Linearize,
     std::{
@@ -110,6 +111,7 @@ pub struct MonitorInfo {
     pub color_spaces: Vec<BackendColorSpace>,
     pub primaries: Primaries,
     pub luminance: Option<BackendLuminance>,
+    pub vrr_range: Option<BackendVrrRange>,
     pub state: BackendConnectorState,
 }
 
@@ -152,6 +154,12 @@ pub trait Connector: Any {
     fn before_non_desktop_override_update(&self, overrd: Option<bool>) {
         let _ = overrd;
     }
+    fn gamma_lut_size(&self) -> Option<u32> {
+        None
+    }
+    fn set_gamma_lut(&self, lut: Option<Rc<BackendGammaLut>>) {
+        let _ = lut;
+    }
     fn transaction_type(&self) -> Box<dyn BackendConnectorTransactionTypeDyn> {
         #[derive(Hash, Eq, PartialEq)]
         struct UnimplementedConnectorTransactionType;
@@ -229,6 +237,12 @@ pub trait InputDevice {
     fn dev_t(&self) -> Option<c::dev_t> {
         None
     }
+    fn vendor_id(&self) -> Option<u32> {
+        None
+    }
+    fn product_id(&self) -> Option<u32> {
+        None
+    }
     fn tap_enabled(&self) -> Option<bool> {
         None
     }
@@ -253,6 +267,24 @@ pub trait InputDevice {
         None
     }
     fn set_middle_button_emulation_enabled(&self, enabled: bool);
+    fn scroll_method(&self) -> Option<InputDeviceScrollMethod> {
+        None
+    }
+    fn set_scroll_method(&self, method: InputDeviceScrollMethod) {
+        let _ = method;
+    }
+    fn disable_while_typing_enabled(&self) -> Option<bool> {
+        None
+    }
+    fn set_disable_while_typing_enabled(&self, enabled: bool) {
+        let _ = enabled;
+    }
+    fn send_events_mode(&self) -> Option<InputDeviceSendEventsMode> {
+        None
+    }
+    fn set_send_events_mode(&self, mode: InputDeviceSendEventsMode) {
+        let _ = mode;
+    }
     fn tablet_info(&self) -> Option<Box<TabletInit>> {
         None
     }
@@ -300,6 +332,21 @@ pub enum InputDeviceClickMethod {
     Clickfinger,
 }
 
+#[derive(Debug, Copy, Clone)]
+pub enum InputDeviceScrollMethod {
+    NoScroll,
+    TwoFinger,
+    Edge,
+    OnButtonDown,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum InputDeviceSendEventsMode {
+    Enabled,
+    Disabled,
+    DisabledOnExternalMouse,
+}
+
 pub enum BackendEvent {
     NewDrmDevice(Rc<dyn BackendDrmDevice>),
     NewConnector(Rc<dyn Connector>),
@@ -510,6 +557,7 @@ pub trait BackendDrmDevice {
     fn make_render_device(&self);
     fn set_gfx_api(&self, api: GfxApi);
     fn gtx_api(&self) -> GfxApi;
+    fn gfx_formats(&self) -> Rc<AHashMap<u32, GfxFormat>>;
     fn version(&self) -> Result<DrmVersion, DrmError>;
     fn set_direct_scanout_enabled(&self, enabled: bool);
     fn is_render_device(&self) -> bool;
@@ -548,6 +596,12 @@ pub enum BackendColorSpace {
     Bt2020,
 }
 
+pub struct BackendGammaLut {
+    pub red: Vec<u16>,
+    pub green: Vec<u16>,
+    pub blue: Vec<u16>,
+}
+
 #[derive(Copy, Clone, Debug)]
 pub struct BackendLuminance {
     pub min: f64,
@@ -555,6 +609,12 @@ pub struct BackendLuminance {
     pub max_fall: f64,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BackendVrrRange {
+    pub min_hz: u32,
+    pub max_hz: u32,
+}
+
 impl BackendTransferFunction {
     pub fn to_drm(self) -> u8 {
         match self {
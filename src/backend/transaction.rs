@@ -118,6 +118,8 @@ pub enum BackendConnectorTransactionError {
     CreateHdrMetadataBlob(#[source] DrmError),
     #[error("Could not create a mode blob")]
     CreateModeBlob(#[source] DrmError),
+    #[error("Could not create a gamma LUT blob")]
+    CreateGammaLutBlob(#[source] DrmError),
     #[error("Could not allocate buffers for connector {}", .0)]
     AllocateScanoutBuffers(ConnectorKernelId, #[source] Box<MetalError>),
     #[error("Test commit failed")]
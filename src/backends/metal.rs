@@ -9,8 +9,9 @@ use {
         async_engine::SpawnedFuture,
         backend::{
             Backend, InputDevice, InputDeviceAccelProfile, InputDeviceCapability,
-            InputDeviceClickMethod, InputDeviceGroupId, InputDeviceId, InputEvent, KeyState,
-            TransformMatrix, transaction::BackendConnectorTransactionError,
+            InputDeviceClickMethod, InputDeviceGroupId, InputDeviceId, InputDeviceScrollMethod,
+            InputDeviceSendEventsMode, InputEvent, KeyState, TransformMatrix,
+            transaction::BackendConnectorTransactionError,
         },
         backends::metal::video::{
             MetalDrmDeviceData, MetalLeaseData, MetalRenderContext, PendingDrmDevice,
@@ -28,10 +29,16 @@ use {
         libinput::{
             LibInput, LibInputAdapter, LibInputError,
             consts::{
-                AccelProfile, ConfigClickMethod, LIBINPUT_CONFIG_ACCEL_PROFILE_ADAPTIVE,
-                LIBINPUT_CONFIG_ACCEL_PROFILE_FLAT, LIBINPUT_CONFIG_CLICK_METHOD_BUTTON_AREAS,
+                AccelProfile, ConfigClickMethod, ConfigScrollMethod, ConfigSendEventsMode,
+                LIBINPUT_CONFIG_ACCEL_PROFILE_ADAPTIVE, LIBINPUT_CONFIG_ACCEL_PROFILE_FLAT,
+                LIBINPUT_CONFIG_CLICK_METHOD_BUTTON_AREAS,
                 LIBINPUT_CONFIG_CLICK_METHOD_CLICKFINGER, LIBINPUT_CONFIG_CLICK_METHOD_NONE,
-                LIBINPUT_DEVICE_CAP_TABLET_PAD, LIBINPUT_DEVICE_CAP_TABLET_TOOL,
+                LIBINPUT_CONFIG_SCROLL_2FG, LIBINPUT_CONFIG_SCROLL_EDGE,
+                LIBINPUT_CONFIG_SCROLL_NO_SCROLL, LIBINPUT_CONFIG_SCROLL_ON_BUTTON_DOWN,
+                LIBINPUT_CONFIG_SEND_EVENTS_DISABLED,
+                LIBINPUT_CONFIG_SEND_EVENTS_DISABLED_ON_EXTERNAL_MOUSE,
+                LIBINPUT_CONFIG_SEND_EVENTS_ENABLED, LIBINPUT_DEVICE_CAP_TABLET_PAD,
+                LIBINPUT_DEVICE_CAP_TABLET_TOOL,
             },
             device::{LibInputDevice, RegisteredDevice},
         },
@@ -148,6 +155,7 @@ pub struct MetalBackend {
     pub session: Session,
     pause_handler: Cell<Option<SignalHandler>>,
     resume_handler: Cell<Option<SignalHandler>>,
+    lock_handler: Cell<Option<SignalHandler>>,
     ctx: CloneCell<Option<Rc<MetalRenderContext>>>,
     signaled_sync_file: CloneCell<Option<SyncFile>>,
     default_feedback: CloneCell<Option<Rc<DrmFeedback>>>,
@@ -189,6 +197,7 @@ impl Backend for MetalBackend {
     fn clear(&self) {
         self.pause_handler.take();
         self.resume_handler.take();
+        self.lock_handler.take();
         self.ctx.take();
         self.device_holder.devices.clear();
         for dev in self.device_holder.input_devices.take() {
@@ -307,6 +316,7 @@ pub async fn create(state: &Rc<State>) -> Result<Rc<MetalBackend>, MetalError> {
         session,
         pause_handler: Default::default(),
         resume_handler: Default::default(),
+        lock_handler: Default::default(),
         ctx: Default::default(),
         signaled_sync_file: Default::default(),
         default_feedback: Default::default(),
@@ -330,6 +340,13 @@ pub async fn create(state: &Rc<State>) -> Result<Rc<MetalBackend>, MetalError> {
             Err(e) => return Err(MetalError::DeviceResumeSignalHandler(e)),
         }
     }));
+    {
+        let state = state.clone();
+        match metal.session.on_lock(move |_| state.handle_logind_lock()) {
+            Ok(sh) => metal.lock_handler.set(Some(sh)),
+            Err(e) => log::warn!("Could not create lock signal handler: {}", ErrorFmt(e)),
+        }
+    }
     Ok(metal)
 }
 
@@ -373,6 +390,9 @@ struct InputDeviceProperties {
     calibration_matrix: Cell<Option<[[f32; 3]; 2]>>,
     click_method: Cell<Option<ConfigClickMethod>>,
     middle_button_emulation_enabled: Cell<Option<bool>>,
+    scroll_method: Cell<Option<ConfigScrollMethod>>,
+    disable_while_typing_enabled: Cell<Option<bool>>,
+    send_events_mode: Cell<Option<ConfigSendEventsMode>>,
 }
 
 #[derive(Clone)]
@@ -442,6 +462,15 @@ impl MetalInputDevice {
         if let Some(enabled) = self.desired.middle_button_emulation_enabled.get() {
             self.set_middle_button_emulation_enabled(enabled);
         }
+        if let Some(method) = self.desired.scroll_method.get() {
+            self.set_scroll_method_(method);
+        }
+        if let Some(enabled) = self.desired.disable_while_typing_enabled.get() {
+            self.set_disable_while_typing_enabled(enabled);
+        }
+        if let Some(mode) = self.desired.send_events_mode.get() {
+            self.set_send_events_mode_(mode);
+        }
         self.fetch_effective();
     }
 
@@ -484,6 +513,21 @@ impl MetalInputDevice {
                 .middle_button_emulation_enabled
                 .set(Some(device.middle_button_emulation_enabled()));
         }
+        if device.has_scroll_methods() {
+            self.effective
+                .scroll_method
+                .set(Some(device.scroll_method()));
+        }
+        if device.disable_while_typing_available() {
+            self.effective
+                .disable_while_typing_enabled
+                .set(Some(device.disable_while_typing_enabled()));
+        }
+        if device.has_send_events_modes() {
+            self.effective
+                .send_events_mode
+                .set(Some(device.send_events_mode()));
+        }
     }
 
     fn pre_pause(&self) {
@@ -527,6 +571,30 @@ impl MetalInputDevice {
                 .set(Some(dev.device().click_method()));
         }
     }
+
+    fn set_scroll_method_(&self, method: ConfigScrollMethod) {
+        self.desired.scroll_method.set(Some(method));
+        if let Some(dev) = self.inputdev.get()
+            && dev.device().has_scroll_methods()
+        {
+            dev.device().set_scroll_method(method);
+            self.effective
+                .scroll_method
+                .set(Some(dev.device().scroll_method()));
+        }
+    }
+
+    fn set_send_events_mode_(&self, mode: ConfigSendEventsMode) {
+        self.desired.send_events_mode.set(Some(mode));
+        if let Some(dev) = self.inputdev.get()
+            && dev.device().has_send_events_modes()
+        {
+            dev.device().set_send_events_mode(mode);
+            self.effective
+                .send_events_mode
+                .set(Some(dev.device().send_events_mode()));
+        }
+    }
 }
 
 impl InputDevice for MetalInputDevice {
@@ -640,6 +708,14 @@ impl InputDevice for MetalInputDevice {
         Some(self.devnum)
     }
 
+    fn vendor_id(&self) -> Option<u32> {
+        Some(self.inputdev.get()?.device().vendor())
+    }
+
+    fn product_id(&self) -> Option<u32> {
+        Some(self.inputdev.get()?.device().product())
+    }
+
     fn tap_enabled(&self) -> Option<bool> {
         self.effective.tap_enabled.get()
     }
@@ -742,6 +818,68 @@ impl InputDevice for MetalInputDevice {
         }
     }
 
+    fn scroll_method(&self) -> Option<InputDeviceScrollMethod> {
+        let p = self.effective.scroll_method.get()?;
+        let p = match p {
+            LIBINPUT_CONFIG_SCROLL_NO_SCROLL => InputDeviceScrollMethod::NoScroll,
+            LIBINPUT_CONFIG_SCROLL_2FG => InputDeviceScrollMethod::TwoFinger,
+            LIBINPUT_CONFIG_SCROLL_EDGE => InputDeviceScrollMethod::Edge,
+            LIBINPUT_CONFIG_SCROLL_ON_BUTTON_DOWN => InputDeviceScrollMethod::OnButtonDown,
+            _ => return None,
+        };
+        Some(p)
+    }
+
+    fn set_scroll_method(&self, method: InputDeviceScrollMethod) {
+        let method = match method {
+            InputDeviceScrollMethod::NoScroll => LIBINPUT_CONFIG_SCROLL_NO_SCROLL,
+            InputDeviceScrollMethod::TwoFinger => LIBINPUT_CONFIG_SCROLL_2FG,
+            InputDeviceScrollMethod::Edge => LIBINPUT_CONFIG_SCROLL_EDGE,
+            InputDeviceScrollMethod::OnButtonDown => LIBINPUT_CONFIG_SCROLL_ON_BUTTON_DOWN,
+        };
+        self.set_scroll_method_(method);
+    }
+
+    fn disable_while_typing_enabled(&self) -> Option<bool> {
+        self.effective.disable_while_typing_enabled.get()
+    }
+
+    fn set_disable_while_typing_enabled(&self, enabled: bool) {
+        self.desired.disable_while_typing_enabled.set(Some(enabled));
+        if let Some(dev) = self.inputdev.get()
+            && dev.device().disable_while_typing_available()
+        {
+            dev.device().set_disable_while_typing_enabled(enabled);
+            self.effective
+                .disable_while_typing_enabled
+                .set(Some(dev.device().disable_while_typing_enabled()));
+        }
+    }
+
+    fn send_events_mode(&self) -> Option<InputDeviceSendEventsMode> {
+        let p = self.effective.send_events_mode.get()?;
+        let p = match p {
+            LIBINPUT_CONFIG_SEND_EVENTS_ENABLED => InputDeviceSendEventsMode::Enabled,
+            LIBINPUT_CONFIG_SEND_EVENTS_DISABLED => InputDeviceSendEventsMode::Disabled,
+            LIBINPUT_CONFIG_SEND_EVENTS_DISABLED_ON_EXTERNAL_MOUSE => {
+                InputDeviceSendEventsMode::DisabledOnExternalMouse
+            }
+            _ => return None,
+        };
+        Some(p)
+    }
+
+    fn set_send_events_mode(&self, mode: InputDeviceSendEventsMode) {
+        let mode = match mode {
+            InputDeviceSendEventsMode::Enabled => LIBINPUT_CONFIG_SEND_EVENTS_ENABLED,
+            InputDeviceSendEventsMode::Disabled => LIBINPUT_CONFIG_SEND_EVENTS_DISABLED,
+            InputDeviceSendEventsMode::DisabledOnExternalMouse => {
+                LIBINPUT_CONFIG_SEND_EVENTS_DISABLED_ON_EXTERNAL_MOUSE
+            }
+        };
+        self.set_send_events_mode_(mode);
+    }
+
     fn tablet_info(&self) -> Option<Box<TabletInit>> {
         let dev = self.inputdev.get()?;
         let dev = dev.device();
@@ -112,6 +112,7 @@ impl MetalConnector {
         let frame_name = FrameName::get(&self.kernel_id().to_string());
         let mut cur_sec = 0;
         let mut max = 0;
+        let mut last_vblank = 0;
         loop {
             self.present_trigger.triggered().await;
             if !self.buffers_idle.get() || !self.crtc_idle.get() {
@@ -127,10 +128,19 @@ impl MetalConnector {
             let mut expected_sequence = crtc.sequence.get() + 1;
             let mut start = Time::now_unchecked();
             let use_frame_scheduling = !self.try_async_flip();
+            let mut next_vblank = self.next_vblank_nsec.get();
             if use_frame_scheduling {
-                let next_present = self
-                    .next_vblank_nsec
-                    .get()
+                if let Some(interval) = node.min_present_interval_nsec() {
+                    let refresh = self.display.borrow().refresh as u64;
+                    if refresh > 0 {
+                        let earliest = (last_vblank + interval).saturating_sub(refresh / 2);
+                        while next_vblank < earliest {
+                            next_vblank += refresh;
+                            expected_sequence += 1;
+                        }
+                    }
+                }
+                let next_present = next_vblank
                     .saturating_sub(self.pre_commit_margin.get())
                     .saturating_sub(self.post_commit_margin.get());
                 if start.nsec() < next_present {
@@ -145,7 +155,7 @@ impl MetalConnector {
                 let now = start.nsec();
                 let flip = match self.try_async_flip() {
                     true => now,
-                    false => self.next_vblank_nsec.get(),
+                    false => next_vblank,
                 };
                 node.before_latch(flip).await;
             }
@@ -159,6 +169,7 @@ impl MetalConnector {
             }
             if use_frame_scheduling {
                 self.expected_sequence.set(Some(expected_sequence));
+                last_vblank = next_vblank;
             }
             self.state.set_backend_idle(false);
             let duration = start.elapsed();
@@ -218,7 +229,15 @@ impl MetalConnector {
         let mut present_fb = None;
         let mut direct_scanout_id = None;
         if let Some(latched) = &latched {
-            let fb = self.prepare_present_fb(&cd, &linear_cd, buffer, &plane, latched, true)?;
+            let try_direct_scanout = !node.global.zoomed();
+            let fb = self.prepare_present_fb(
+                &cd,
+                &linear_cd,
+                buffer,
+                &plane,
+                latched,
+                try_direct_scanout,
+            )?;
             direct_scanout_id = fb.direct_scanout_data.as_ref().map(|d| d.dma_buf_id);
             present_fb = Some(fb);
         }
@@ -601,6 +620,7 @@ impl MetalConnector {
             node.has_fullscreen(),
             true,
             node.global.persistent.transform.get(),
+            node.zoom(),
             Some(&self.state.damage_visualizer),
         );
         Some(Latched {
@@ -2,8 +2,8 @@ use {
     crate::{
         allocator::BufferObject,
         backend::{
-            BackendColorSpace, BackendConnectorState, BackendTransferFunction, Connector,
-            ConnectorEvent,
+            BackendColorSpace, BackendConnectorState, BackendGammaLut, BackendTransferFunction,
+            Connector, ConnectorEvent,
             transaction::{
                 BackendAppliedConnectorTransaction, BackendConnectorTransaction,
                 BackendConnectorTransactionError, BackendPreparedConnectorTransaction,
@@ -20,7 +20,7 @@ use {
         },
         video::drm::{
             Change, ConnectorStatus, DRM_MODE_ATOMIC_ALLOW_MODESET, DrmBlob, DrmConnector, DrmCrtc,
-            DrmFb, DrmModeInfo, DrmObject, DrmPlane, PropBlob, hdr_output_metadata,
+            DrmFb, DrmModeInfo, DrmObject, DrmPlane, PropBlob, drm_color_lut, hdr_output_metadata,
         },
     },
     arrayvec::ArrayVec,
@@ -55,6 +55,9 @@ pub struct DrmCrtcState {
     pub mode_blob_id: DrmBlob,
     pub mode_blob: Option<Rc<PropBlob>>,
     pub vrr_enabled: bool,
+    pub gamma_lut: Option<Rc<BackendGammaLut>>,
+    pub gamma_lut_blob_id: DrmBlob,
+    pub gamma_lut_blob: Option<Rc<PropBlob>>,
     pub assigned_connector: DrmConnector,
 }
 
@@ -654,6 +657,45 @@ impl MetalDeviceTransaction {
                 ));
             }
             crtc.new.vrr_enabled = state.vrr;
+            if crtc.obj.gamma_lut.is_some() {
+                let size = crtc.obj.gamma_lut_size as usize;
+                let lut = connector
+                    .obj
+                    .gamma_lut
+                    .get()
+                    .filter(|l| [&l.red, &l.green, &l.blue].iter().all(|c| c.len() == size));
+                let unchanged = match (&crtc.new.gamma_lut, &lut) {
+                    (Some(old), Some(new)) => rc_eq(old, new),
+                    // Also clears LUTs left behind by the previous DRM master.
+                    (None, None) => crtc.new.gamma_lut_blob_id == DrmBlob::NONE,
+                    _ => false,
+                };
+                if !unchanged {
+                    match &lut {
+                        Some(lut) => {
+                            let entries: Vec<_> = (0..size)
+                                .map(|i| drm_color_lut {
+                                    red: lut.red[i],
+                                    green: lut.green[i],
+                                    blue: lut.blue[i],
+                                    reserved: 0,
+                                })
+                                .collect();
+                            let blob =
+                                slf.dev.dev.master.create_blob(&entries[..]).map_err(
+                                    BackendConnectorTransactionError::CreateGammaLutBlob,
+                                )?;
+                            crtc.new.gamma_lut_blob_id = blob.id();
+                            crtc.new.gamma_lut_blob = Some(Rc::new(blob));
+                        }
+                        None => {
+                            crtc.new.gamma_lut_blob_id = DrmBlob::NONE;
+                            crtc.new.gamma_lut_blob = None;
+                        }
+                    }
+                    crtc.new.gamma_lut = lut;
+                }
+            }
             if state.tearing && !slf.dev.dev.supports_async_commit {
                 return Err(BackendConnectorTransactionError::TearingNotSupported(
                     connector.obj.kernel_id(),
@@ -833,6 +875,12 @@ impl MetalDeviceTransactionWithDrmState {
                     log_change!(o, n, mode_blob_id);
                     c.change(crtc.obj.mode_id, n.mode_blob_id);
                 }
+                if let Some(prop) = crtc.obj.gamma_lut
+                    && n.gamma_lut_blob_id != o.gamma_lut_blob_id
+                {
+                    log_change!(o, n, gamma_lut_blob_id);
+                    c.change(prop, n.gamma_lut_blob_id);
+                }
                 reset_default_properties!(
                     c,
                     &*crtc.obj.untyped_properties.borrow(),
@@ -4,10 +4,11 @@ use {
         async_engine::{Phase, SpawnedFuture},
         backend::{
             BackendColorSpace, BackendConnectorState, BackendDrmDevice, BackendDrmLease,
-            BackendDrmLessee, BackendEvent, BackendLuminance, BackendTransferFunction,
-            CONCAP_CONNECTOR, CONCAP_MODE_SETTING, CONCAP_PHYSICAL_DISPLAY, Connector,
-            ConnectorCaps, ConnectorEvent, ConnectorId, ConnectorKernelId, DrmDeviceId,
-            HardwareCursor, HardwareCursorUpdate, Mode, MonitorInfo,
+            BackendDrmLessee, BackendEvent, BackendGammaLut, BackendLuminance,
+            BackendTransferFunction, BackendVrrRange, CONCAP_CONNECTOR, CONCAP_MODE_SETTING,
+            CONCAP_PHYSICAL_DISPLAY, Connector, ConnectorCaps, ConnectorEvent, ConnectorId,
+            ConnectorKernelId, DrmDeviceId, HardwareCursor, HardwareCursorUpdate, Mode,
+            MonitorInfo,
             transaction::{
                 BackendConnectorTransaction, BackendConnectorTransactionError,
                 BackendConnectorTransactionType, BackendConnectorTransactionTypeDyn,
@@ -26,8 +27,8 @@ use {
         edid::{CtaDataBlock, Descriptor, EdidExtension},
         format::{Format, XRGB8888},
         gfx_api::{
-            AcquireSync, GfxBlendBuffer, GfxContext, GfxFramebuffer, GfxTexture, ReleaseSync,
-            SyncFile, needs_render_usage,
+            AcquireSync, GfxBlendBuffer, GfxContext, GfxFormat, GfxFramebuffer, GfxTexture,
+            ReleaseSync, SyncFile, needs_render_usage,
         },
         ifs::{
             wl_output::OutputId,
@@ -164,6 +165,10 @@ impl BackendDrmDevice for MetalDrmDevice {
         self.ctx.get().gfx.gfx_api()
     }
 
+    fn gfx_formats(&self) -> Rc<AHashMap<u32, GfxFormat>> {
+        self.ctx.get().gfx.formats()
+    }
+
     fn version(&self) -> Result<DrmVersion, DrmError> {
         self.gbm.drm.version()
     }
@@ -362,6 +367,7 @@ pub struct ConnectorDisplayData {
     pub supports_pq: bool,
     pub primaries: Primaries,
     pub luminance: Option<BackendLuminance>,
+    pub vrr_range: Option<BackendVrrRange>,
 
     pub colorspace: Option<DrmProperty>,
     pub hdr_metadata: Option<DrmProperty>,
@@ -514,6 +520,7 @@ pub struct MetalConnector {
     pub cursor_plane: CloneCell<Option<Rc<MetalPlane>>>,
 
     pub crtc: CloneCell<Option<Rc<MetalCrtc>>>,
+    pub gamma_lut: CloneCell<Option<Rc<BackendGammaLut>>>,
 
     pub on_change: OnChange<ConnectorEvent>,
 
@@ -677,6 +684,11 @@ impl MetalConnector {
     pub fn update_drm_feedback(&self) {
         let fb = self.compute_drm_feedback();
         self.drm_feedback.set(fb);
+        if let Some(node) = self.state.root.outputs.get(&self.connector_id) {
+            for ws in node.workspaces.iter() {
+                ws.send_scanout_feedback();
+            }
+        }
     }
 
     fn compute_drm_feedback(&self) -> Option<Rc<DrmFeedback>> {
@@ -887,6 +899,15 @@ impl Connector for MetalConnector {
         }
     }
 
+    fn gamma_lut_size(&self) -> Option<u32> {
+        let crtc = self.crtc.get()?;
+        crtc.gamma_lut.is_some().then_some(crtc.gamma_lut_size)
+    }
+
+    fn set_gamma_lut(&self, lut: Option<Rc<BackendGammaLut>>) {
+        self.gamma_lut.set(lut);
+    }
+
     fn transaction_type(&self) -> Box<dyn BackendConnectorTransactionTypeDyn> {
         #[derive(Eq, PartialEq, Hash)]
         struct TT(dev_t);
@@ -919,6 +940,8 @@ pub struct MetalCrtc {
     pub mode_id: DrmProperty,
     pub vrr_enabled: DrmProperty,
     pub out_fence_ptr: DrmProperty,
+    pub gamma_lut: Option<DrmProperty>,
+    pub gamma_lut_size: u32,
     pub drm_state: RefCell<DrmCrtcState>,
 
     pub sequence: Cell<u64>,
@@ -1100,6 +1123,7 @@ fn create_connector(
         primary_plane: Default::default(),
         cursor_plane: Default::default(),
         crtc: Default::default(),
+        gamma_lut: Default::default(),
         on_change: Default::default(),
         present_trigger: Default::default(),
         cursor_x: Cell::new(0),
@@ -1165,6 +1189,7 @@ fn create_connector_display_data(
     let mut supports_bt2020 = false;
     let mut supports_pq = false;
     let mut luminance = None;
+    let mut vrr_range = None;
     let mut primaries = Primaries::SRGB;
     'fetch_edid: {
         if connection != ConnectorStatus::Connected {
@@ -1227,12 +1252,15 @@ fn create_connector_display_data(
             );
             serial_number = edid.base_block.id_serial_number.to_string();
         }
-        let min_vrr_hz = 'fetch_min_hz: {
+        let (min_vrr_hz, max_vrr_hz) = 'fetch_vrr_range: {
             for ext in &edid.extension_blocks {
                 if let EdidExtension::CtaV3(cta) = ext {
                     for data_block in &cta.data_blocks {
                         if let CtaDataBlock::VendorAmd(amd) = data_block {
-                            break 'fetch_min_hz amd.minimum_refresh_hz as u64;
+                            break 'fetch_vrr_range (
+                                amd.minimum_refresh_hz as u64,
+                                amd.maximum_refresh_hz as u64,
+                            );
                         }
                     }
                 }
@@ -1241,13 +1269,22 @@ fn create_connector_display_data(
                 if let Some(desc) = desc
                     && let Descriptor::DisplayRangeLimitsAndAdditionalTiming(timings) = desc
                 {
-                    break 'fetch_min_hz timings.vertical_field_rate_min as u64;
+                    break 'fetch_vrr_range (
+                        timings.vertical_field_rate_min as u64,
+                        timings.vertical_field_rate_max as u64,
+                    );
                 }
             }
-            0
+            (0, 0)
         };
         if min_vrr_hz > 0 {
             vrr_refresh_max_nsec = 1_000_000_000 / min_vrr_hz;
+            if max_vrr_hz >= min_vrr_hz {
+                vrr_range = Some(BackendVrrRange {
+                    min_hz: min_vrr_hz as u32,
+                    max_hz: max_vrr_hz as u32,
+                });
+            }
         }
         let cc = &edid.base_block.chromaticity_coordinates;
         let map = |c: u16| F64(c as f64 / 1024.0);
@@ -1438,6 +1475,7 @@ fn create_connector_display_data(
         supports_pq,
         primaries,
         luminance,
+        vrr_range,
         connector_id,
         output_id,
         colorspace: colorspace_prop.map(|p| p.id),
@@ -1484,7 +1522,6 @@ fn create_crtc(
             ("AMD_CRTC_REGAMMA_TF", DefaultValue::Enum("Default")),
             ("CTM", DefaultValue::Fixed(0)),
             ("DEGAMMA_LUT", DefaultValue::Fixed(0)),
-            ("GAMMA_LUT", DefaultValue::Fixed(0)),
             ("OUT_FENCE_PTR", DefaultValue::Fixed(0)),
         ],
     );
@@ -1492,6 +1529,11 @@ fn create_crtc(
     let mode_id = props.get("MODE_ID")?.map(|v| DrmBlob(v as u32));
     let vrr_enabled = props.get("VRR_ENABLED")?.map(|v| v == 1);
     let out_fence_ptr = props.get("OUT_FENCE_PTR")?;
+    let gamma_lut = props.get("GAMMA_LUT").ok();
+    let gamma_lut_size = match props.get("GAMMA_LUT_SIZE") {
+        Ok(p) => p.value as u32,
+        Err(_) => 0,
+    };
     let mut mode = None;
     if mode_id.value.is_some() {
         match master.getblob::<drm_mode_modeinfo>(mode_id.value) {
@@ -1507,6 +1549,11 @@ fn create_crtc(
         mode_blob_id: mode_id.value,
         mode_blob: None,
         vrr_enabled: vrr_enabled.value,
+        gamma_lut: None,
+        gamma_lut_blob_id: gamma_lut
+            .map(|p| DrmBlob(p.value as _))
+            .unwrap_or(DrmBlob::NONE),
+        gamma_lut_blob: None,
         assigned_connector: DrmConnector::NONE,
     };
     Ok(MetalCrtc {
@@ -1524,6 +1571,8 @@ fn create_crtc(
         mode_id: mode_id.id,
         vrr_enabled: vrr_enabled.id,
         out_fence_ptr: out_fence_ptr.id,
+        gamma_lut: gamma_lut.map(|p| p.id),
+        gamma_lut_size,
         sequence: Cell::new(0),
         have_queued_sequence: Cell::new(false),
         needs_vblank_emulation: Cell::new(false),
@@ -1922,6 +1971,7 @@ impl MetalBackend {
             color_spaces,
             primaries: dd.primaries,
             luminance: dd.luminance,
+            vrr_range: dd.vrr_range,
             state,
         }));
         connector.send_hardware_cursor();
@@ -2141,6 +2191,14 @@ impl MetalCrtc {
         let state = &mut *self.drm_state.borrow_mut();
         state.active = get(&props, self.active)? != 0;
         state.vrr_enabled = get(&props, self.vrr_enabled)? != 0;
+        if let Some(prop) = self.gamma_lut {
+            let id = DrmBlob(get(&props, prop)? as _);
+            if state.gamma_lut_blob_id != id {
+                state.gamma_lut_blob_id = id;
+                state.gamma_lut = None;
+                state.gamma_lut_blob = None;
+            }
+        }
         let id = DrmBlob(get(props, self.mode_id)? as _);
         let old = state.mode_blob_id;
         state.mode_blob_id = id;
@@ -16,7 +16,9 @@ use {
         cmm::cmm_primaries::Primaries,
         fixed::Fixed,
         format::{Format, XRGB8888},
-        gfx_api::{AcquireSync, GfxContext, GfxError, GfxFramebuffer, GfxTexture, ReleaseSync},
+        gfx_api::{
+            AcquireSync, GfxContext, GfxError, GfxFormat, GfxFramebuffer, GfxTexture, ReleaseSync,
+        },
         ifs::wl_output::OutputId,
         state::State,
         time::Time,
@@ -603,6 +605,7 @@ impl XBackend {
             color_spaces: vec![],
             primaries: Primaries::SRGB,
             luminance: None,
+            vrr_range: None,
             state: output.state.get(),
         }));
         output.changed();
@@ -1031,6 +1034,10 @@ impl BackendDrmDevice for XDrmDevice {
         self.backend.ctx.gfx_api()
     }
 
+    fn gfx_formats(&self) -> Rc<AHashMap<u32, GfxFormat>> {
+        self.backend.ctx.formats()
+    }
+
     fn version(&self) -> Result<DrmVersion, DrmError> {
         self.backend.gbm.drm.version()
     }
@@ -7,6 +7,9 @@ mod generate;
 mod idle;
 mod input;
 mod log;
+mod low_power;
+mod on_output;
+mod protocol_trace;
 mod quit;
 mod randr;
 mod reexec;
@@ -22,9 +25,18 @@ mod xwayland;
 use {
     crate::{
         cli::{
-            clients::ClientsArgs, color_management::ColorManagementArgs,
-            damage_tracking::DamageTrackingArgs, idle::IdleCmd, input::InputArgs, randr::RandrArgs,
-            reexec::ReexecArgs, tree::TreeArgs, xwayland::XwaylandArgs,
+            clients::ClientsArgs,
+            color_management::ColorManagementArgs,
+            damage_tracking::DamageTrackingArgs,
+            idle::IdleCmd,
+            input::InputArgs,
+            low_power::LowPowerArgs,
+            on_output::{OnOutputConnectArgs, OnOutputDisconnectArgs},
+            protocol_trace::ProtocolTraceArgs,
+            randr::RandrArgs,
+            reexec::ReexecArgs,
+            tree::TreeArgs,
+            xwayland::XwaylandArgs,
         },
         compositor::start_compositor,
         format::{Format, ref_formats},
@@ -94,6 +106,14 @@ pub enum Cmd {
     Clients(ClientsArgs),
     /// Inspect the surface tree.
     Tree(TreeArgs),
+    /// Run a program whenever an output is connected.
+    OnOutputConnect(OnOutputConnectArgs),
+    /// Run a program whenever an output is disconnected.
+    OnOutputDisconnect(OnOutputDisconnectArgs),
+    /// Record wayland messages for debugging.
+    ProtocolTrace(ProtocolTraceArgs),
+    /// Inspect/modify the low-power mode.
+    LowPower(LowPowerArgs),
     /// Prints the Jay version and exits.
     Version,
     #[cfg(feature = "it")]
@@ -259,6 +279,10 @@ pub fn main() {
         Cmd::ColorManagement(a) => color_management::main(cli.global, a),
         Cmd::Clients(a) => clients::main(cli.global, a),
         Cmd::Tree(a) => tree::main(cli.global, a),
+        Cmd::OnOutputConnect(a) => on_output::connect(cli.global, a),
+        Cmd::OnOutputDisconnect(a) => on_output::disconnect(cli.global, a),
+        Cmd::ProtocolTrace(a) => protocol_trace::main(cli.global, a),
+        Cmd::LowPower(a) => low_power::main(cli.global, a),
         Cmd::Version => version::main(cli.global),
         #[cfg(feature = "it")]
         Cmd::RunTests => crate::it::run_tests(),
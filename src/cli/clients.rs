@@ -157,6 +157,7 @@ pub struct Client {
     pub is_xwayland: bool,
     pub comm: Option<String>,
     pub exe: Option<String>,
+    pub num_objects: Option<u64>,
 }
 
 pub async fn handle_client_query(
@@ -201,6 +202,9 @@ pub async fn handle_client_query(
     Exe::handle(tl, id, c.clone(), |c, event| {
         last!(c).exe = Some(event.exe.to_string());
     });
+    NumObjects::handle(tl, id, c.clone(), |c, event| {
+        last!(c).num_objects = Some(event.num_objects);
+    });
     tl.round_trip().await;
     mem::take(&mut *c.borrow_mut())
         .into_iter()
@@ -239,5 +243,6 @@ impl ClientPrinter<'_> {
         bol!(is_xwayland, "xwayland");
         opt!(comm, "comm");
         opt!(exe, "exe");
+        opt!(num_objects, "objects");
     }
 }
@@ -1,12 +1,21 @@
 use {
     crate::{
-        backend::{InputDeviceAccelProfile, InputDeviceCapability, InputDeviceClickMethod},
+        backend::{
+            InputDeviceAccelProfile, InputDeviceCapability, InputDeviceClickMethod,
+            InputDeviceScrollMethod, InputDeviceSendEventsMode,
+        },
         cli::GlobalArgs,
         clientmem::ClientMem,
+        fixed::Fixed,
         libinput::consts::{
-            ConfigClickMethod, LIBINPUT_CONFIG_ACCEL_PROFILE_ADAPTIVE,
-            LIBINPUT_CONFIG_ACCEL_PROFILE_FLAT, LIBINPUT_CONFIG_CLICK_METHOD_BUTTON_AREAS,
-            LIBINPUT_CONFIG_CLICK_METHOD_CLICKFINGER, LIBINPUT_CONFIG_CLICK_METHOD_NONE,
+            ConfigClickMethod, ConfigScrollMethod, ConfigSendEventsMode,
+            LIBINPUT_CONFIG_ACCEL_PROFILE_ADAPTIVE, LIBINPUT_CONFIG_ACCEL_PROFILE_FLAT,
+            LIBINPUT_CONFIG_CLICK_METHOD_BUTTON_AREAS, LIBINPUT_CONFIG_CLICK_METHOD_CLICKFINGER,
+            LIBINPUT_CONFIG_CLICK_METHOD_NONE, LIBINPUT_CONFIG_SCROLL_2FG,
+            LIBINPUT_CONFIG_SCROLL_EDGE, LIBINPUT_CONFIG_SCROLL_NO_SCROLL,
+            LIBINPUT_CONFIG_SCROLL_ON_BUTTON_DOWN, LIBINPUT_CONFIG_SEND_EVENTS_DISABLED,
+            LIBINPUT_CONFIG_SEND_EVENTS_DISABLED_ON_EXTERNAL_MOUSE,
+            LIBINPUT_CONFIG_SEND_EVENTS_ENABLED,
         },
         tools::tool_client::{Handle, ToolClient, with_tool_client},
         utils::{errorfmt::ErrorFmt, string_ext::StringExt},
@@ -83,6 +92,12 @@ pub enum SeatCommand {
     UseHardwareCursor(UseHardwareCursorArgs),
     /// Set the size of the cursor.
     SetCursorSize(SetCursorSizeArgs),
+    /// Print the name of the active keyboard layout.
+    KeyboardLayout,
+    /// Switch the active keyboard layout.
+    SetKeyboardLayout(SetKeyboardLayoutArgs),
+    /// Move the pointer to a position in the global compositor space.
+    WarpPointer(WarpPointerArgs),
 }
 
 impl Default for SeatCommand {
@@ -139,6 +154,12 @@ pub enum DeviceCommand {
     SetClickMethod(SetClickMethodArgs),
     /// Set whether the device uses middle button emulation.
     SetMiddleButtonEmulation(SetMiddleButtonEmulationArgs),
+    /// Set the scroll method.
+    SetScrollMethod(SetScrollMethodArgs),
+    /// Set whether the device is disabled while typing.
+    SetDisableWhileTyping(SetDisableWhileTypingArgs),
+    /// Set when the device sends events.
+    SetSendEventsMode(SetSendEventsModeArgs),
 }
 
 #[derive(ValueEnum, Debug, Clone)]
@@ -238,6 +259,40 @@ pub struct SetMiddleButtonEmulationArgs {
     pub middle_button_emulation: bool,
 }
 
+#[derive(ValueEnum, Debug, Clone)]
+pub enum ScrollMethod {
+    NoScroll,
+    TwoFinger,
+    Edge,
+    OnButtonDown,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct SetScrollMethodArgs {
+    /// The method.
+    pub method: ScrollMethod,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct SetDisableWhileTypingArgs {
+    /// Whether the device is disabled while typing.
+    #[arg(action = clap::ArgAction::Set)]
+    pub disable_while_typing: bool,
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+pub enum SendEventsMode {
+    Enabled,
+    Disabled,
+    DisabledOnExternalMouse,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct SetSendEventsModeArgs {
+    /// The mode.
+    pub mode: SendEventsMode,
+}
+
 #[derive(Args, Debug, Clone)]
 pub struct MapToOutputArgs {
     /// The output to map to.
@@ -271,6 +326,20 @@ pub struct SetKeymapArgs {
     pub file: Option<String>,
 }
 
+#[derive(Args, Debug, Clone)]
+pub struct SetKeyboardLayoutArgs {
+    /// The layout to switch to: `next`, `prev`, the index of the layout, or its name.
+    pub layout: String,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct WarpPointerArgs {
+    /// The x coordinate.
+    pub x: f64,
+    /// The y coordinate.
+    pub y: f64,
+}
+
 #[derive(Args, Debug, Clone)]
 pub struct UseHardwareCursorArgs {
     /// Whether the seat uses the hardware cursor.
@@ -291,6 +360,21 @@ struct Seat {
     pub repeat_rate: i32,
     pub repeat_delay: i32,
     pub hardware_cursor: bool,
+    pub cursor_animation: Option<CursorAnimation>,
+    pub keyboard_layouts: Vec<KeyboardLayout>,
+}
+
+#[derive(Clone, Debug)]
+struct KeyboardLayout {
+    pub index: u32,
+    pub name: String,
+    pub active: bool,
+}
+
+#[derive(Clone, Debug)]
+struct CursorAnimation {
+    pub current_frame: u32,
+    pub frame_delays_ns: Vec<u64>,
 }
 
 #[derive(Clone, Debug)]
@@ -314,6 +398,9 @@ struct InputDevice {
     pub calibration_matrix: Option<[[f32; 3]; 2]>,
     pub click_method: Option<InputDeviceClickMethod>,
     pub middle_button_emulation_enabled: Option<bool>,
+    pub scroll_method: Option<InputDeviceScrollMethod>,
+    pub disable_while_typing_enabled: Option<bool>,
+    pub send_events_mode: Option<InputDeviceSendEventsMode>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -460,6 +547,57 @@ impl Input {
                     size: a.size,
                 });
             }
+            SeatCommand::KeyboardLayout => {
+                let layouts = self.keyboard_layouts(input, &args.seat).await;
+                if let Some(layout) = layouts.iter().find(|l| l.active) {
+                    println!("{}", layout.name);
+                }
+            }
+            SeatCommand::SetKeyboardLayout(a) => {
+                let layouts = self.keyboard_layouts(input, &args.seat).await;
+                if layouts.is_empty() {
+                    return;
+                }
+                let num = layouts.len() as u32;
+                let active = layouts.iter().position(|l| l.active).unwrap_or(0) as u32;
+                let layout = match a.layout.as_str() {
+                    "next" => (active + 1) % num,
+                    "prev" => (active + num - 1) % num,
+                    s => match s.parse::<u32>() {
+                        Ok(idx) => idx,
+                        Err(_) => match layouts.iter().find(|l| l.name == s) {
+                            Some(l) => l.index,
+                            None => {
+                                eprintln!("Seat {} has no keyboard layout named {s}", args.seat);
+                                eprintln!("Available layouts:");
+                                for l in &layouts {
+                                    eprintln!("  {}: {}", l.index, l.name);
+                                }
+                                std::process::exit(1);
+                            }
+                        },
+                    },
+                };
+                self.handle_error(input, |e| {
+                    eprintln!("Could not set the keyboard layout: {}", e);
+                });
+                tc.send(jay_input::SetKeyboardLayout {
+                    self_id: input,
+                    seat: &args.seat,
+                    layout,
+                });
+            }
+            SeatCommand::WarpPointer(a) => {
+                self.handle_error(input, |e| {
+                    eprintln!("Could not warp the pointer: {}", e);
+                });
+                tc.send(jay_input::WarpPointer {
+                    self_id: input,
+                    seat: &args.seat,
+                    x: Fixed::from_f64(a.x),
+                    y: Fixed::from_f64(a.y),
+                });
+            }
         }
         tc.round_trip().await;
     }
@@ -682,6 +820,49 @@ impl Input {
                     enabled: a.middle_button_emulation as _,
                 });
             }
+            DeviceCommand::SetScrollMethod(a) => {
+                let method = match a.method {
+                    ScrollMethod::NoScroll => LIBINPUT_CONFIG_SCROLL_NO_SCROLL.0,
+                    ScrollMethod::TwoFinger => LIBINPUT_CONFIG_SCROLL_2FG.0,
+                    ScrollMethod::Edge => LIBINPUT_CONFIG_SCROLL_EDGE.0,
+                    ScrollMethod::OnButtonDown => LIBINPUT_CONFIG_SCROLL_ON_BUTTON_DOWN.0,
+                };
+                self.handle_error(input, |e| {
+                    eprintln!("Could not set the scroll method: {}", e);
+                });
+                tc.send(jay_input::SetScrollMethod {
+                    self_id: input,
+                    id: args.device,
+                    method,
+                });
+            }
+            DeviceCommand::SetDisableWhileTyping(a) => {
+                self.handle_error(input, |e| {
+                    eprintln!("Could not modify the disable-while-typing setting: {}", e);
+                });
+                tc.send(jay_input::SetDisableWhileTyping {
+                    self_id: input,
+                    id: args.device,
+                    enabled: a.disable_while_typing as _,
+                });
+            }
+            DeviceCommand::SetSendEventsMode(a) => {
+                let mode = match a.mode {
+                    SendEventsMode::Enabled => LIBINPUT_CONFIG_SEND_EVENTS_ENABLED.0,
+                    SendEventsMode::Disabled => LIBINPUT_CONFIG_SEND_EVENTS_DISABLED.0,
+                    SendEventsMode::DisabledOnExternalMouse => {
+                        LIBINPUT_CONFIG_SEND_EVENTS_DISABLED_ON_EXTERNAL_MOUSE.0
+                    }
+                };
+                self.handle_error(input, |e| {
+                    eprintln!("Could not set the send-events mode: {}", e);
+                });
+                tc.send(jay_input::SetSendEventsMode {
+                    self_id: input,
+                    id: args.device,
+                    mode,
+                });
+            }
         }
         tc.round_trip().await;
     }
@@ -736,6 +917,26 @@ impl Input {
         }
     }
 
+    async fn keyboard_layouts(
+        self: &Rc<Self>,
+        input: JayInputId,
+        seat: &str,
+    ) -> Vec<KeyboardLayout> {
+        self.handle_error(input, |e| {
+            eprintln!("Could not retrieve seat data: {}", e);
+        });
+        self.tc.send(jay_input::GetSeat {
+            self_id: input,
+            name: seat,
+        });
+        let data = self.get(input).await;
+        data.seats
+            .into_iter()
+            .next()
+            .map(|s| s.keyboard_layouts)
+            .unwrap_or_default()
+    }
+
     fn print_seat(&self, seat: &Seat) {
         println!("Seat {}:", seat.name);
         println!("  repeat rate: {}", seat.repeat_rate);
@@ -743,6 +944,23 @@ impl Input {
         if !seat.hardware_cursor {
             println!("  hardware cursor disabled");
         }
+        if seat.keyboard_layouts.len() > 1 {
+            println!("  keyboard layouts:");
+            for layout in &seat.keyboard_layouts {
+                let active = if layout.active { " (active)" } else { "" };
+                println!("    {}: {}{active}", layout.index, layout.name);
+            }
+        }
+        if let Some(a) = &seat.cursor_animation {
+            println!("  cursor animation:");
+            println!("    frames: {}", a.frame_delays_ns.len());
+            println!("    current frame: {}", a.current_frame);
+            print!("    frame delays (ms):");
+            for delay in &a.frame_delays_ns {
+                print!(" {}", *delay as f64 / 1_000_000.0);
+            }
+            println!();
+        }
     }
 
     fn print_device(&self, prefix: &str, print_seat: bool, device: &InputDevice) {
@@ -826,6 +1044,26 @@ impl Input {
         if let Some(v) = &device.middle_button_emulation_enabled {
             println!("{prefix}  middle button emulation: {}", v);
         }
+        if let Some(v) = &device.scroll_method {
+            let name = match v {
+                InputDeviceScrollMethod::NoScroll => "no-scroll",
+                InputDeviceScrollMethod::TwoFinger => "two-finger",
+                InputDeviceScrollMethod::Edge => "edge",
+                InputDeviceScrollMethod::OnButtonDown => "on-button-down",
+            };
+            println!("{prefix}  scroll method: {}", name);
+        }
+        if let Some(v) = &device.disable_while_typing_enabled {
+            println!("{prefix}  disable while typing: {}", v);
+        }
+        if let Some(v) = &device.send_events_mode {
+            let name = match v {
+                InputDeviceSendEventsMode::Enabled => "enabled",
+                InputDeviceSendEventsMode::Disabled => "disabled",
+                InputDeviceSendEventsMode::DisabledOnExternalMouse => "disabled-on-external-mouse",
+            };
+            println!("{prefix}  send events mode: {}", name);
+        }
     }
 
     async fn get(self: &Rc<Self>, input: JayInputId) -> Data {
@@ -837,8 +1075,29 @@ impl Input {
                 repeat_rate: msg.repeat_rate,
                 repeat_delay: msg.repeat_delay,
                 hardware_cursor: msg.hardware_cursor != 0,
+                cursor_animation: None,
+                keyboard_layouts: vec![],
             });
         });
+        jay_input::KeyboardLayout::handle(tc, input, data.clone(), |data, msg| {
+            let mut data = data.borrow_mut();
+            if let Some(last) = data.seats.last_mut() {
+                last.keyboard_layouts.push(KeyboardLayout {
+                    index: msg.index,
+                    name: msg.name.to_string(),
+                    active: msg.active != 0,
+                });
+            }
+        });
+        jay_input::CursorAnimation::handle(tc, input, data.clone(), |data, msg| {
+            let mut data = data.borrow_mut();
+            if let Some(last) = data.seats.last_mut() {
+                last.cursor_animation = Some(CursorAnimation {
+                    current_frame: msg.current_frame,
+                    frame_delays_ns: msg.frame_delays_ns.to_vec(),
+                });
+            }
+        });
         jay_input::InputDevice::handle(tc, input, data.clone(), |data, msg| {
             use crate::{backend::InputDeviceCapability::*, libinput::consts::*};
             let mut capabilities = vec![];
@@ -893,6 +1152,9 @@ impl Input {
                 calibration_matrix: None,
                 click_method: None,
                 middle_button_emulation_enabled: None,
+                scroll_method: None,
+                disable_while_typing_enabled: None,
+                send_events_mode: None,
             });
         });
         jay_input::InputDeviceOutput::handle(tc, input, data.clone(), |data, msg| {
@@ -931,6 +1193,41 @@ impl Input {
                     Some(msg.middle_button_emulation_enabled != 0);
             }
         });
+        jay_input::ScrollMethod::handle(tc, input, data.clone(), |data, msg| {
+            let scroll_method = match ConfigScrollMethod(msg.scroll_method) {
+                LIBINPUT_CONFIG_SCROLL_NO_SCROLL => Some(InputDeviceScrollMethod::NoScroll),
+                LIBINPUT_CONFIG_SCROLL_2FG => Some(InputDeviceScrollMethod::TwoFinger),
+                LIBINPUT_CONFIG_SCROLL_EDGE => Some(InputDeviceScrollMethod::Edge),
+                LIBINPUT_CONFIG_SCROLL_ON_BUTTON_DOWN => {
+                    Some(InputDeviceScrollMethod::OnButtonDown)
+                }
+                _ => None,
+            };
+            let mut data = data.borrow_mut();
+            if let Some(last) = data.input_device.last_mut() {
+                last.scroll_method = scroll_method;
+            }
+        });
+        jay_input::DisableWhileTyping::handle(tc, input, data.clone(), |data, msg| {
+            let mut data = data.borrow_mut();
+            if let Some(last) = data.input_device.last_mut() {
+                last.disable_while_typing_enabled = Some(msg.disable_while_typing_enabled != 0);
+            }
+        });
+        jay_input::SendEventsMode::handle(tc, input, data.clone(), |data, msg| {
+            let send_events_mode = match ConfigSendEventsMode(msg.send_events_mode) {
+                LIBINPUT_CONFIG_SEND_EVENTS_ENABLED => Some(InputDeviceSendEventsMode::Enabled),
+                LIBINPUT_CONFIG_SEND_EVENTS_DISABLED => Some(InputDeviceSendEventsMode::Disabled),
+                LIBINPUT_CONFIG_SEND_EVENTS_DISABLED_ON_EXTERNAL_MOUSE => {
+                    Some(InputDeviceSendEventsMode::DisabledOnExternalMouse)
+                }
+                _ => None,
+            };
+            let mut data = data.borrow_mut();
+            if let Some(last) = data.input_device.last_mut() {
+                last.send_events_mode = send_events_mode;
+            }
+        });
         tc.round_trip().await;
         data.borrow_mut().clone()
     }
@@ -0,0 +1,73 @@
+use {
+    crate::{
+        cli::GlobalArgs,
+        tools::tool_client::{Handle, ToolClient, with_tool_client},
+        wire::{JayCompositorId, jay_compositor},
+    },
+    clap::{Args, Subcommand},
+    std::{cell::Cell, rc::Rc},
+};
+
+#[derive(Args, Debug)]
+pub struct LowPowerArgs {
+    #[clap(subcommand)]
+    pub command: Option<LowPowerCmd>,
+}
+
+#[derive(Subcommand, Debug, Default)]
+pub enum LowPowerCmd {
+    /// Print whether the low-power mode is enabled.
+    #[default]
+    Status,
+    /// Enable the low-power mode.
+    Enable,
+    /// Disable the low-power mode.
+    Disable,
+}
+
+pub fn main(global: GlobalArgs, args: LowPowerArgs) {
+    with_tool_client(global.log_level.into(), |tc| async move {
+        let lp = LowPower { tc: tc.clone() };
+        lp.run(args).await;
+    });
+}
+
+struct LowPower {
+    tc: Rc<ToolClient>,
+}
+
+impl LowPower {
+    async fn run(self, args: LowPowerArgs) {
+        let tc = &self.tc;
+        let comp = tc.jay_compositor().await;
+        match args.command.unwrap_or_default() {
+            LowPowerCmd::Status => self.status(comp).await,
+            LowPowerCmd::Enable => self.set_enabled(comp, true).await,
+            LowPowerCmd::Disable => self.set_enabled(comp, false).await,
+        }
+    }
+
+    async fn status(self, comp: JayCompositorId) {
+        let tc = &self.tc;
+        tc.send(jay_compositor::GetLowPowerMode { self_id: comp });
+        let enabled = Rc::new(Cell::new(false));
+        jay_compositor::LowPowerMode::handle(tc, comp, enabled.clone(), |iv, msg| {
+            iv.set(msg.enabled != 0);
+        });
+        tc.round_trip().await;
+        if enabled.get() {
+            println!("Enabled");
+        } else {
+            println!("Disabled");
+        }
+    }
+
+    async fn set_enabled(self, comp: JayCompositorId, enabled: bool) {
+        let tc = &self.tc;
+        tc.send(jay_compositor::SetLowPowerMode {
+            self_id: comp,
+            enabled: enabled as _,
+        });
+        tc.round_trip().await;
+    }
+}
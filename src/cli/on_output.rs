@@ -0,0 +1,97 @@
+use {
+    crate::{
+        cli::GlobalArgs,
+        tools::tool_client::{Handle, with_tool_client},
+        utils::errorfmt::ErrorFmt,
+        wire::{
+            jay_compositor::WatchOutputs,
+            jay_output_watcher::{Connected, Disconnected},
+        },
+    },
+    clap::{Args, ValueHint},
+    std::{cell::Cell, future::pending, process::Command, rc::Rc},
+};
+
+#[derive(Args, Debug)]
+pub struct OnOutputConnectArgs {
+    /// Do not run the program for outputs that are already connected.
+    #[arg(long)]
+    skip_existing: bool,
+    /// The program to run whenever an output is connected.
+    ///
+    /// The program is run with the environment variables `JAY_OUTPUT_CONNECTOR`,
+    /// `JAY_OUTPUT_MANUFACTURER`, `JAY_OUTPUT_MODEL`, and `JAY_OUTPUT_SERIAL_NUMBER` set.
+    #[clap(required = true, trailing_var_arg = true, value_hint = ValueHint::CommandWithArguments)]
+    program: Vec<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct OnOutputDisconnectArgs {
+    /// The program to run whenever an output is disconnected.
+    ///
+    /// The program is run with the environment variables `JAY_OUTPUT_CONNECTOR`,
+    /// `JAY_OUTPUT_MANUFACTURER`, `JAY_OUTPUT_MODEL`, and `JAY_OUTPUT_SERIAL_NUMBER` set.
+    #[clap(required = true, trailing_var_arg = true, value_hint = ValueHint::CommandWithArguments)]
+    program: Vec<String>,
+}
+
+pub fn connect(global: GlobalArgs, args: OnOutputConnectArgs) {
+    with_tool_client(global.log_level.into(), |tc| async move {
+        let comp = tc.jay_compositor().await;
+        let watcher = tc.id();
+        tc.send(WatchOutputs {
+            self_id: comp,
+            id: watcher,
+        });
+        let ready = Rc::new(Cell::new(!args.skip_existing));
+        let program = Rc::new(args.program);
+        Connected::handle(&tc, watcher, ready.clone(), move |ready, ev| {
+            if ready.get() {
+                run(
+                    &program,
+                    ev.connector,
+                    ev.manufacturer,
+                    ev.model,
+                    ev.serial_number,
+                );
+            }
+        });
+        tc.round_trip().await;
+        ready.set(true);
+        pending::<()>().await;
+    });
+}
+
+pub fn disconnect(global: GlobalArgs, args: OnOutputDisconnectArgs) {
+    with_tool_client(global.log_level.into(), |tc| async move {
+        let comp = tc.jay_compositor().await;
+        let watcher = tc.id();
+        tc.send(WatchOutputs {
+            self_id: comp,
+            id: watcher,
+        });
+        Disconnected::handle(&tc, watcher, args.program, |program, ev| {
+            run(
+                program,
+                ev.connector,
+                ev.manufacturer,
+                ev.model,
+                ev.serial_number,
+            );
+        });
+        pending::<()>().await;
+    });
+}
+
+fn run(program: &[String], connector: &str, manufacturer: &str, model: &str, serial_number: &str) {
+    let res = Command::new(&program[0])
+        .args(&program[1..])
+        .env("JAY_OUTPUT_CONNECTOR", connector)
+        .env("JAY_OUTPUT_MANUFACTURER", manufacturer)
+        .env("JAY_OUTPUT_MODEL", model)
+        .env("JAY_OUTPUT_SERIAL_NUMBER", serial_number)
+        .status();
+    if let Err(e) = res {
+        eprintln!("Could not run `{}`: {}", program[0], ErrorFmt(e));
+    }
+}
@@ -0,0 +1,121 @@
+use {
+    crate::{
+        cli::GlobalArgs,
+        tools::tool_client::{Handle, ToolClient, with_tool_client},
+        utils::errorfmt::ErrorFmt,
+        wire::{JayProtocolTraceId, jay_compositor, jay_protocol_trace},
+    },
+    clap::{Args, Subcommand},
+    std::{cell::RefCell, rc::Rc},
+};
+
+#[derive(Args, Debug)]
+pub struct ProtocolTraceArgs {
+    #[clap(subcommand)]
+    pub command: ProtocolTraceCmd,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ProtocolTraceCmd {
+    /// Start recording wayland messages.
+    Start(StartArgs),
+    /// Stop recording wayland messages.
+    Stop,
+    /// Discard all recorded messages.
+    Clear,
+    /// Write the recorded messages to a file.
+    Dump(DumpArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct StartArgs {
+    /// Only record messages of this interface. Can be specified multiple times.
+    #[clap(long)]
+    pub interface: Vec<String>,
+    /// Only record messages of this client. Can be specified multiple times.
+    #[clap(long)]
+    pub client: Vec<u64>,
+    /// The maximum number of messages to keep. Older messages are discarded.
+    #[clap(long, default_value_t = 10_000)]
+    pub capacity: u32,
+}
+
+#[derive(Args, Debug)]
+pub struct DumpArgs {
+    /// The file to write the messages to. If no file is given, the messages are
+    /// printed to stdout.
+    pub file: Option<String>,
+}
+
+pub fn main(global: GlobalArgs, args: ProtocolTraceArgs) {
+    with_tool_client(global.log_level.into(), |tc| async move {
+        let trace = ProtocolTrace { tc: tc.clone() };
+        trace.run(args).await;
+    });
+}
+
+struct ProtocolTrace {
+    tc: Rc<ToolClient>,
+}
+
+impl ProtocolTrace {
+    async fn run(self, args: ProtocolTraceArgs) {
+        let tc = &self.tc;
+        let comp = tc.jay_compositor().await;
+        let trace = tc.id();
+        tc.send(jay_compositor::CreateProtocolTrace {
+            self_id: comp,
+            id: trace,
+        });
+        match args.command {
+            ProtocolTraceCmd::Start(args) => self.start(trace, args),
+            ProtocolTraceCmd::Stop => {
+                tc.send(jay_protocol_trace::Stop { self_id: trace });
+            }
+            ProtocolTraceCmd::Clear => {
+                tc.send(jay_protocol_trace::Clear { self_id: trace });
+            }
+            ProtocolTraceCmd::Dump(args) => self.dump(trace, args).await,
+        }
+        tc.send(jay_protocol_trace::Destroy { self_id: trace });
+        tc.round_trip().await;
+    }
+
+    fn start(&self, trace: JayProtocolTraceId, args: StartArgs) {
+        let tc = &self.tc;
+        for name in &args.interface {
+            tc.send(jay_protocol_trace::AddInterface {
+                self_id: trace,
+                name,
+            });
+        }
+        for &id in &args.client {
+            tc.send(jay_protocol_trace::AddClient { self_id: trace, id });
+        }
+        tc.send(jay_protocol_trace::Start {
+            self_id: trace,
+            capacity: args.capacity,
+        });
+    }
+
+    async fn dump(&self, trace: JayProtocolTraceId, args: DumpArgs) {
+        let tc = &self.tc;
+        let messages = Rc::new(RefCell::new(String::new()));
+        jay_protocol_trace::Message::handle(tc, trace, messages.clone(), |m, msg| {
+            let m = &mut *m.borrow_mut();
+            m.push_str(msg.message);
+            m.push('\n');
+        });
+        tc.send(jay_protocol_trace::Dump { self_id: trace });
+        tc.round_trip().await;
+        let messages = messages.take();
+        match &args.file {
+            Some(file) => {
+                if let Err(e) = std::fs::write(file, messages) {
+                    fatal!("Could not write `{}`: {}", file, ErrorFmt(e));
+                }
+            }
+            None => print!("{}", messages),
+        }
+    }
+}
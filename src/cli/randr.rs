@@ -1,3 +1,6 @@
+#[cfg(test)]
+mod tests;
+
 use {
     crate::{
         backend::{BackendColorSpace, BackendTransferFunction},
@@ -114,6 +117,9 @@ pub enum ApiCmd {
     /// Use Vulkan for rendering in this card.
     #[clap(name = "vulkan")]
     Vulkan,
+    /// Use the CPU for rendering in this card.
+    #[clap(name = "software")]
+    Software,
 }
 
 #[derive(Args, Debug, Clone)]
@@ -279,12 +285,39 @@ pub struct PositionArgs {
 
 #[derive(Args, Debug, Clone)]
 pub struct ModeArgs {
-    /// The width.
-    pub width: i32,
-    /// The height.
-    pub height: i32,
-    /// The refresh rate.
-    pub refresh_rate: f64,
+    /// The mode in the form `<width>x<height>[@<refresh rate>]` or the width.
+    ///
+    /// If the refresh rate is omitted, the highest refresh rate available for this size
+    /// is used.
+    pub mode: String,
+    /// The height if the first argument is the width.
+    pub height: Option<i32>,
+    /// The refresh rate if the first argument is the width.
+    pub refresh_rate: Option<f64>,
+}
+
+#[derive(Debug, Error)]
+#[error("Mode must have the form `<width>x<height>[@<refresh rate>]`")]
+struct ParseModeError;
+
+fn parse_mode(args: &ModeArgs) -> Result<(i32, i32, Option<f64>), ParseModeError> {
+    if let Some(height) = args.height {
+        let width = i32::from_str(&args.mode).map_err(|_| ParseModeError)?;
+        return Ok((width, height, args.refresh_rate));
+    }
+    let (size, refresh_rate) = match args.mode.split_once('@') {
+        Some((size, rate)) => {
+            let rate = f64::from_str(rate.trim()).map_err(|_| ParseModeError)?;
+            (size, Some(rate))
+        }
+        _ => (args.mode.as_str(), None),
+    };
+    let Some((width, height)) = size.split_once('x') else {
+        return Err(ParseModeError);
+    };
+    let width = i32::from_str(width.trim()).map_err(|_| ParseModeError)?;
+    let height = i32::from_str(height.trim()).map_err(|_| ParseModeError)?;
+    Ok((width, height, refresh_rate))
 }
 
 #[derive(Args, Debug, Clone)]
@@ -425,6 +458,7 @@ struct Device {
     pub model_name: String,
     pub gfx_api: String,
     pub render_device: bool,
+    pub formats: Vec<(String, u64)>,
 }
 
 #[derive(Clone, Debug)]
@@ -456,6 +490,7 @@ struct Output {
     pub vrr_enabled: bool,
     pub vrr_mode: VrrMode,
     pub vrr_cursor_hz: Option<f64>,
+    pub vrr_range: Option<(u32, u32)>,
     pub tearing_mode: TearingMode,
     pub formats: Vec<String>,
     pub format: Option<String>,
@@ -466,6 +501,7 @@ struct Output {
     pub current_transfer_function: Option<String>,
     pub brightness_range: Option<(f64, f64)>,
     pub brightness: Option<f64>,
+    pub hardware_cursor: Option<bool>,
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -577,13 +613,42 @@ impl Randr {
                     log::error!("Connector {} is not connected", connector.name);
                     return;
                 };
-                let Some(mode) = output.modes.iter().find(|m| {
-                    m.width == t.width && m.height == t.height && m.refresh_rate() == t.refresh_rate
-                }) else {
+                let (width, height, refresh_rate) = match parse_mode(&t) {
+                    Ok(m) => m,
+                    Err(e) => {
+                        log::error!("{}", e);
+                        return;
+                    }
+                };
+                let candidates = output
+                    .modes
+                    .iter()
+                    .filter(|m| m.width == width && m.height == height);
+                let mode = match refresh_rate {
+                    Some(r) => candidates
+                        .filter(|m| (m.refresh_rate() - r).abs() < 0.5)
+                        .min_by(|a, b| {
+                            let a = (a.refresh_rate() - r).abs();
+                            let b = (b.refresh_rate() - r).abs();
+                            a.total_cmp(&b)
+                        }),
+                    None => candidates.max_by_key(|m| m.refresh_rate_millihz),
+                };
+                let Some(mode) = mode else {
+                    let rate = refresh_rate.map(|r| format!("@{r}")).unwrap_or_default();
                     log::error!(
-                        "Output {} does not support this refresh rate",
-                        connector.name
+                        "Output {} does not support the mode {}x{}{}",
+                        connector.name,
+                        width,
+                        height,
+                        rate,
                     );
+                    if output.modes.is_not_empty() {
+                        eprintln!("Available modes:");
+                        for mode in &output.modes {
+                            eprintln!("  - {}", mode);
+                        }
+                    }
                     return;
                 };
                 self.handle_error(randr, |msg| {
@@ -769,6 +834,7 @@ impl Randr {
                 let api = match &api.cmd {
                     ApiCmd::OpenGl => "opengl",
                     ApiCmd::Vulkan => "vulkan",
+                    ApiCmd::Software => "software",
                 };
                 tc.send(jay_randr::SetApi {
                     self_id: randr,
@@ -812,7 +878,7 @@ impl Randr {
             println!("drm devices:");
         }
         for dev in &data.drm_devices {
-            self.print_drm_device(dev);
+            self.print_drm_device(dev, args.formats);
             println!("    connectors:");
             let mut connectors: Vec<_> = data
                 .connectors
@@ -840,7 +906,7 @@ impl Randr {
         }
     }
 
-    fn print_drm_device(&self, dev: &Device) {
+    fn print_drm_device(&self, dev: &Device, formats: bool) {
         println!("  {}:", dev.devnode);
         println!("    model: {} {}", dev.vendor_name, dev.model_name);
         println!("    pci-id: {:x}:{:x}", dev.vendor, dev.model);
@@ -849,6 +915,17 @@ impl Randr {
         if dev.render_device {
             println!("    primary device");
         }
+        if dev.formats.is_not_empty() && formats {
+            println!("    dmabuf formats:");
+            let mut prev = None;
+            for (format, modifier) in &dev.formats {
+                if prev != Some(format) {
+                    println!("      {format}:");
+                    prev = Some(format);
+                }
+                println!("        0x{modifier:016x}");
+            }
+        }
     }
 
     fn print_connector(&self, connector: &Connector, modes: bool, formats: bool) {
@@ -875,6 +952,9 @@ impl Randr {
             }
             return;
         }
+        if let Some(hc) = o.hardware_cursor {
+            println!("        hardware cursor: {}", hc);
+        }
         println!("        VRR capable: {}", o.vrr_capable);
         if o.vrr_capable {
             println!("        VRR enabled: {}", o.vrr_enabled);
@@ -891,6 +971,9 @@ impl Randr {
                 }
             };
             println!("        VRR mode: {}", mode);
+            if let Some((min, max)) = o.vrr_range {
+                println!("        VRR range: {}-{} Hz", min, max);
+            }
             if let Some(hz) = o.vrr_cursor_hz {
                 println!("        VRR cursor hz: {}", hz);
             }
@@ -1022,8 +1105,14 @@ impl Randr {
                 model_name: msg.model_name.to_string(),
                 gfx_api: msg.gfx_api.to_string(),
                 render_device: msg.render_device != 0,
+                formats: vec![],
             });
         });
+        jay_randr::DrmDeviceFormat::handle(tc, randr, data.clone(), |data, msg| {
+            let mut data = data.borrow_mut();
+            let dev = data.drm_devices.last_mut().unwrap();
+            dev.formats.push((msg.format.to_string(), msg.modifier));
+        });
         jay_randr::Connector::handle(tc, randr, data.clone(), |data, msg| {
             let mut data = data.borrow_mut();
             data.connectors.push(Connector {
@@ -1080,6 +1169,12 @@ impl Randr {
             let output = c.output.as_mut().unwrap();
             output.vrr_cursor_hz = Some(msg.hz);
         });
+        jay_randr::VrrRange::handle(tc, randr, data.clone(), move |data, msg| {
+            let mut data = data.borrow_mut();
+            let c = data.connectors.last_mut().unwrap();
+            let output = c.output.as_mut().unwrap();
+            output.vrr_range = Some((msg.min_hz, msg.max_hz));
+        });
         jay_randr::TearingState::handle(tc, randr, data.clone(), |data, msg| {
             let mut data = data.borrow_mut();
             let c = data.connectors.last_mut().unwrap();
@@ -1156,6 +1251,12 @@ impl Randr {
             let output = c.output.as_mut().unwrap();
             output.brightness = Some(msg.lux);
         });
+        jay_randr::HardwareCursor::handle(tc, randr, data.clone(), |data, msg| {
+            let mut data = data.borrow_mut();
+            let c = data.connectors.last_mut().unwrap();
+            let output = c.output.as_mut().unwrap();
+            output.hardware_cursor = Some(msg.enabled != 0);
+        });
         tc.round_trip().await;
         data.borrow_mut().clone()
     }
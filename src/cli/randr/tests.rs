@@ -0,0 +1,47 @@
+use crate::cli::randr::{ModeArgs, parse_mode};
+
+fn mode(mode: &str, height: Option<i32>, refresh_rate: Option<f64>) -> ModeArgs {
+    ModeArgs {
+        mode: mode.to_string(),
+        height,
+        refresh_rate,
+    }
+}
+
+#[test]
+fn mode_string() {
+    let m = parse_mode(&mode("2560x1440@144", None, None)).unwrap();
+    assert_eq!(m, (2560, 1440, Some(144.0)));
+    let m = parse_mode(&mode("1920x1080@59.94", None, None)).unwrap();
+    assert_eq!(m, (1920, 1080, Some(59.94)));
+}
+
+#[test]
+fn mode_string_without_refresh_rate() {
+    let m = parse_mode(&mode("1920x1080", None, None)).unwrap();
+    assert_eq!(m, (1920, 1080, None));
+}
+
+#[test]
+fn mode_separate_arguments() {
+    let m = parse_mode(&mode("1920", Some(1080), Some(60.0))).unwrap();
+    assert_eq!(m, (1920, 1080, Some(60.0)));
+    let m = parse_mode(&mode("1920", Some(1080), None)).unwrap();
+    assert_eq!(m, (1920, 1080, None));
+}
+
+#[test]
+fn mode_invalid() {
+    for s in [
+        "",
+        "1920",
+        "1920x",
+        "x1080",
+        "1920x1080@",
+        "1920x1080@fast",
+        "axb",
+    ] {
+        assert!(parse_mode(&mode(s, None, None)).is_err(), "{s}");
+    }
+    assert!(parse_mode(&mode("1920x1080", Some(1080), None)).is_err());
+}
@@ -53,6 +53,8 @@ enum QueryCmd {
     SelectWorkspace,
     /// Interactively select a window to query.
     SelectWindow,
+    /// Query all windows.
+    Windows,
 }
 
 #[derive(Args, Debug)]
@@ -167,6 +169,9 @@ impl Query<'_> {
                     toplevel: id,
                 });
             }
+            QueryCmd::Windows => {
+                self.tc.send(SetRootWindows { self_id: self.id });
+            }
         }
         let tl = self.tc;
         let id = self.id;
@@ -1,7 +1,7 @@
 use {
     crate::{
         async_engine::SpawnedFuture,
-        client::{error::LookupError, objects::Objects},
+        client::{error::LookupError, objects::Objects, protocol_trace::ProtocolTrace},
         criteria::{
             CritDestroyListener, CritMatcherId,
             clm::{CL_CHANGED_DESTROYED, CL_CHANGED_NEW, ClMatcherChange},
@@ -19,6 +19,7 @@ use {
             activation_token::ActivationToken,
             asyncevent::AsyncEvent,
             buffd::{MsgFormatter, MsgParser, MsgParserError, OutBufferSwapchain},
+            clonecell::UnsafeCellCloneSafe,
             copyhashmap::{CopyHashMap, Locked},
             errorfmt::ErrorFmt,
             numcell::NumCell,
@@ -47,6 +48,7 @@ pub use {
 
 mod error;
 mod objects;
+pub mod protocol_trace;
 mod tasks;
 
 bitflags! {
@@ -71,6 +73,8 @@ bitflags! {
 pub const CAPS_DEFAULT: ClientCaps = ClientCaps(CAP_LAYER_SHELL.0 | CAP_DRM_LEASE.0);
 pub const CAPS_DEFAULT_SANDBOXED: ClientCaps = ClientCaps(CAP_DRM_LEASE.0);
 
+unsafe impl UnsafeCellCloneSafe for ClientCaps {}
+
 #[derive(Debug, Copy, Clone, Hash, Ord, PartialOrd, Eq, PartialEq)]
 pub struct ClientId(u64);
 
@@ -94,6 +98,7 @@ pub struct Clients {
     next_client_id: NumCell<u64>,
     pub clients: RefCell<AHashMap<ClientId, ClientHolder>>,
     shutdown_clients: RefCell<AHashMap<ClientId, ClientHolder>>,
+    pub protocol_trace: ProtocolTrace,
 }
 
 impl Clients {
@@ -102,6 +107,7 @@ impl Clients {
             next_client_id: NumCell::new(1),
             clients: Default::default(),
             shutdown_clients: Default::default(),
+            protocol_trace: Default::default(),
         }
     }
 
@@ -188,9 +194,11 @@ impl Clients {
             )),
             wire_scale: Default::default(),
             focus_stealing_serial: Default::default(),
+            last_interaction_msec: Default::default(),
             changed_properties: Default::default(),
             destroyed: Default::default(),
             acceptor: acceptor.clone(),
+            shm_size: Default::default(),
         });
         track!(data, data);
         let display = Rc::new(WlDisplay::new(&data));
@@ -311,9 +319,11 @@ pub struct Client {
     pub commit_timelines: Rc<CommitTimelines>,
     pub wire_scale: Cell<Option<i32>>,
     pub focus_stealing_serial: Cell<Option<u64>>,
+    pub last_interaction_msec: Cell<Option<u64>>,
     pub changed_properties: Cell<ClMatcherChange>,
     pub destroyed: CopyHashMap<CritMatcherId, Weak<dyn CritDestroyListener<Rc<Self>>>>,
     pub acceptor: Rc<AcceptorMetadata>,
+    pub shm_size: Rc<NumCell<u64>>,
 }
 
 pub const NUM_CACHED_SERIAL_RANGES: usize = 64;
@@ -402,6 +412,10 @@ impl Client {
             obj.id(),
             res
         );
+        let trace = &self.state.clients.protocol_trace;
+        if trace.enabled() {
+            trace.request(self.id, obj.interface(), obj.id(), &res);
+        }
         Ok(res)
     }
 
@@ -435,6 +449,10 @@ impl Client {
         if log::log_enabled!(log::Level::Trace) {
             self.log_event(&event);
         }
+        let trace = &self.state.clients.protocol_trace;
+        if trace.enabled() {
+            trace.event(self.id, event.interface(), event.id(), &event);
+        }
         let mut fds = vec![];
         let mut swapchain = self.swapchain.borrow_mut();
         let mut fmt = MsgFormatter::new(&mut swapchain.cur, &mut fds);
@@ -491,6 +509,12 @@ impl Client {
 
     fn add_obj<T: WaylandObject>(&self, obj: &Rc<T>, client: bool) -> Result<(), ClientError> {
         if client {
+            if !self.is_xwayland
+                && let Some(limit) = self.state.client_object_limit.get()
+                && self.objects.len() as u64 >= limit
+            {
+                return Err(ClientError::TooManyObjects(limit));
+            }
             self.objects.add_client_object(obj.clone())?;
         } else {
             self.objects.add_server_object(obj.clone());
@@ -49,6 +49,8 @@ pub enum ClientError {
     LookupError(LookupError),
     #[error("Could not add object {0} to the client")]
     AddObjectError(ObjectId, #[source] Box<ClientError>),
+    #[error("The client exceeded the limit of {0} objects")]
+    TooManyObjects(u64),
 }
 
 #[derive(Debug, Error)]
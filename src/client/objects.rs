@@ -201,6 +201,10 @@ impl Objects {
         Ok(ObjectId::from_raw(MIN_SERVER_ID + offset).into())
     }
 
+    pub fn len(&self) -> usize {
+        self.registry.len()
+    }
+
     pub fn get_obj(&self, id: ObjectId) -> Result<Rc<dyn Object>, ClientError> {
         match self.registry.get(&id) {
             Some(o) => Ok(o),
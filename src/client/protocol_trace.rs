@@ -0,0 +1,123 @@
+use {
+    crate::{
+        client::ClientId,
+        object::{Interface, ObjectId},
+        time::Time,
+        wire::JayProtocolTrace,
+    },
+    ahash::AHashSet,
+    std::{
+        cell::{Cell, RefCell},
+        collections::VecDeque,
+        fmt::Debug,
+    },
+};
+
+pub const DEFAULT_CAPACITY: usize = 10_000;
+
+/// Records wayland messages into a bounded ring buffer while enabled.
+///
+/// An empty interface or client filter matches all interfaces or clients.
+pub struct ProtocolTrace {
+    enabled: Cell<bool>,
+    interfaces: RefCell<AHashSet<String>>,
+    clients: RefCell<AHashSet<ClientId>>,
+    capacity: Cell<usize>,
+    messages: RefCell<VecDeque<String>>,
+}
+
+impl Default for ProtocolTrace {
+    fn default() -> Self {
+        Self {
+            enabled: Cell::new(false),
+            interfaces: Default::default(),
+            clients: Default::default(),
+            capacity: Cell::new(DEFAULT_CAPACITY),
+            messages: Default::default(),
+        }
+    }
+}
+
+impl ProtocolTrace {
+    pub fn enabled(&self) -> bool {
+        self.enabled.get()
+    }
+
+    pub fn start(
+        &self,
+        interfaces: AHashSet<String>,
+        clients: AHashSet<ClientId>,
+        capacity: usize,
+    ) {
+        let capacity = capacity.max(1);
+        *self.interfaces.borrow_mut() = interfaces;
+        *self.clients.borrow_mut() = clients;
+        self.capacity.set(capacity);
+        let messages = &mut *self.messages.borrow_mut();
+        while messages.len() > capacity {
+            messages.pop_front();
+        }
+        self.enabled.set(true);
+    }
+
+    pub fn stop(&self) {
+        self.enabled.set(false);
+    }
+
+    pub fn clear(&self) {
+        self.messages.borrow_mut().clear();
+    }
+
+    pub fn messages(&self) -> Vec<String> {
+        self.messages.borrow().iter().cloned().collect()
+    }
+
+    pub fn request(&self, client: ClientId, interface: Interface, id: ObjectId, msg: &dyn Debug) {
+        self.record(client, "->", interface, id, msg);
+    }
+
+    pub fn event(&self, client: ClientId, interface: Interface, id: ObjectId, msg: &dyn Debug) {
+        self.record(client, "<=", interface, id, msg);
+    }
+
+    fn record(
+        &self,
+        client: ClientId,
+        direction: &str,
+        interface: Interface,
+        id: ObjectId,
+        msg: &dyn Debug,
+    ) {
+        if interface.name() == JayProtocolTrace.name() {
+            return;
+        }
+        {
+            let clients = self.clients.borrow();
+            if !clients.is_empty() && !clients.contains(&client) {
+                return;
+            }
+        }
+        {
+            let interfaces = self.interfaces.borrow();
+            if !interfaces.is_empty() && !interfaces.contains(interface.name()) {
+                return;
+            }
+        }
+        let usec = Time::now_unchecked().usec();
+        let line = format!(
+            "[{:10}.{:06}] Client {} {} {}@{}.{:?}",
+            usec / 1_000_000,
+            usec % 1_000_000,
+            client,
+            direction,
+            interface.name(),
+            id,
+            msg,
+        );
+        let messages = &mut *self.messages.borrow_mut();
+        if messages.len() >= self.capacity.get() {
+            messages.pop_front();
+        }
+        messages.push_back(line);
+    }
+}
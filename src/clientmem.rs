@@ -3,7 +3,7 @@ use {
         client::Client,
         cpu_worker::{AsyncCpuWork, CpuJob, CpuWork, CpuWorker},
         gfx_api::{ShmMemory, ShmMemoryBacking},
-        utils::vec_ext::VecExt,
+        utils::{numcell::NumCell, vec_ext::VecExt},
     },
     std::{
         cell::Cell,
@@ -37,6 +37,7 @@ pub struct ClientMem {
     sigbus_impossible: bool,
     data: *const [Cell<u8>],
     cpu: Option<Rc<CpuWorker>>,
+    accounting: Option<Rc<NumCell<u64>>>,
 }
 
 #[derive(Clone)]
@@ -110,9 +111,17 @@ impl ClientMem {
             sigbus_impossible,
             data,
             cpu: cpu.cloned(),
+            accounting: None,
         })
     }
 
+    /// Adds the size of the mapping to `counter` until the mapping is dropped.
+    pub fn with_accounting(mut self, counter: &Rc<NumCell<u64>>) -> Self {
+        counter.fetch_add(self.len() as u64);
+        self.accounting = Some(counter.clone());
+        self
+    }
+
     pub fn len(&self) -> usize {
         self.data.len()
     }
@@ -187,6 +196,9 @@ impl ClientMemOffset {
 
 impl Drop for ClientMem {
     fn drop(&mut self) {
+        if let Some(accounting) = &self.accounting {
+            accounting.fetch_sub(self.len() as u64);
+        }
         let fd = unsafe { ManuallyDrop::take(&mut self.fd) };
         if let Some(cpu) = &self.cpu {
             let pending = cpu.submit(Box::new(CloseMemWork {
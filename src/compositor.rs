@@ -54,7 +54,8 @@ use {
         tree::{
             DisplayNode, NodeIds, OutputNode, TearingMode, VrrMode, WorkspaceNode,
             container_layout, container_render_positions, container_render_titles, float_layout,
-            float_titles, output_render_data, placeholder_render_textures,
+            float_titles, handle_toplevel_handle_updates, output_render_data,
+            placeholder_render_textures,
         },
         user_session::import_environment,
         utils::{
@@ -79,6 +80,7 @@ use {
     forker::ForkerProxy,
     jay_config::{
         _private::DEFAULT_SEAT_NAME,
+        DecorationMode, FocusStealingPolicy,
         video::{GfxApi, Transform},
     },
     std::{cell::Cell, env, future::Future, ops::Deref, rc::Rc, sync::Arc, time::Duration},
@@ -214,12 +216,14 @@ fn start_compositor2(
         render_ctx_version: NumCell::new(1),
         render_ctx_ever_initialized: Cell::new(false),
         cursors: Default::default(),
+        themed_cursors: Default::default(),
         wheel,
         clients: Clients::new(),
         globals: Globals::new(),
         connector_ids: Default::default(),
         root: Rc::new(DisplayNode::new(node_ids.next())),
         workspaces: Default::default(),
+        workspace_output_bindings: Default::default(),
         dummy_output: Default::default(),
         node_ids,
         backend_events: AsyncQueue::new(),
@@ -242,6 +246,7 @@ fn start_compositor2(
         pending_toplevel_screencasts: Default::default(),
         pending_screencast_reallocs_or_reconfigures: Default::default(),
         pending_placeholder_render_textures: Default::default(),
+        pending_toplevel_handle_updates: Default::default(),
         dbus: Dbus::new(&engine, &ring, &run_toplevel),
         fdcloser: FdCloser::new(),
         logger: logger.clone(),
@@ -261,6 +266,7 @@ fn start_compositor2(
             inhibited_idle_notifications: Default::default(),
             backend_idle: Cell::new(true),
             in_grace_period: Cell::new(false),
+            dim: Cell::new(1.0),
         },
         run_args,
         xwayland: XWaylandState {
@@ -290,11 +296,17 @@ fn start_compositor2(
         },
         scales,
         cursor_sizes: Default::default(),
+        cursor_themes: Default::default(),
         hardware_tick_cursor: Default::default(),
         testers: Default::default(),
         render_ctx_watchers: Default::default(),
         workspace_watchers: Default::default(),
+        output_watchers: Default::default(),
         default_workspace_capture: Cell::new(true),
+        default_workspace_layout: Default::default(),
+        scratchpads: Default::default(),
+        swallow_app_ids: Default::default(),
+        sandbox_capabilities: Default::default(),
         default_gfx_api: Cell::new(GfxApi::Vulkan),
         activation_tokens: Default::default(),
         toplevel_lists: Default::default(),
@@ -331,6 +343,8 @@ fn start_compositor2(
         cpu_worker,
         ui_drag_enabled: Cell::new(true),
         ui_drag_threshold_squared: Cell::new(10),
+        clipboard_persistence: Cell::new(false),
+        clipboard_persistence_max_size: Cell::new(8 * 1024 * 1024),
         toplevels: Default::default(),
         const_40hz_latch: Default::default(),
         tray_item_ids: Default::default(),
@@ -341,6 +355,7 @@ fn start_compositor2(
         float_above_fullscreen: Cell::new(false),
         icons: Default::default(),
         show_pin_icon: Cell::new(false),
+        show_close_icon: Cell::new(false),
         cl_matcher_manager: ClMatcherManager::new(&crit_ids),
         tl_matcher_manager: TlMatcherManager::new(&crit_ids),
         caps_thread,
@@ -352,7 +367,17 @@ fn start_compositor2(
         head_managers: Default::default(),
         head_managers_async: Default::default(),
         show_bar: Cell::new(true),
+        inactive_window_opacity: Cell::new(1.0),
+        workspace_animation_duration: Cell::new(Duration::ZERO),
+        window_animation_duration: Cell::new(Duration::ZERO),
+        focus_stealing_policy: Cell::new(FocusStealingPolicy::NEVER),
+        decoration_mode: Cell::new(DecorationMode::SERVER_SIDE),
+        client_object_limit: Cell::new(None),
+        client_shm_limit: Cell::new(None),
         enable_primary_selection: Cell::new(true),
+        freeze_cursor_animation_when_idle: Cell::new(false),
+        low_power_mode: Cell::new(false),
+        low_power_max_fps: Cell::new(Some(30.0)),
     });
     state.tracker.register(ClientId::from_raw(0));
     create_dummy_output(&state);
@@ -522,6 +547,10 @@ fn start_global_event_handlers(state: &Rc<State>) -> Vec<SpawnedFuture<()>> {
             "workspace manager done",
             workspace_manager_done(state.clone()),
         ),
+        eng.spawn(
+            "toplevel handle updates",
+            handle_toplevel_handle_updates(state.clone()),
+        ),
         eng.spawn("cl matcher manager", handle_cl_changes(state.clone())),
         eng.spawn(
             "cl matcher leaf events",
@@ -624,6 +653,8 @@ fn create_dummy_output(state: &Rc<State>) {
         vrr_cursor_hz: Default::default(),
         tearing_mode: Cell::new(&TearingMode::Never),
         brightness: Cell::new(None),
+        cursor_size: Cell::new(None),
+        max_fps: Cell::new(None),
     });
     let mode = backend::Mode {
         width: 0,
@@ -688,12 +719,15 @@ fn create_dummy_output(state: &Rc<State>) {
         state: Cell::new(backend_state),
         head_managers: HeadManagers::new(head_name, head_state),
         wlr_output_heads: Default::default(),
+        output_power: Default::default(),
+        gamma_control: Default::default(),
     });
     let schedule = Rc::new(OutputSchedule::new(
         &state.ring,
         &state.eng,
         &connector_data,
         &persistent_state,
+        None,
     ));
     let dummy_output = Rc::new(OutputNode {
         id: state.node_ids.next(),
@@ -710,6 +744,7 @@ fn create_dummy_output(state: &Rc<State>) {
             Vec::new(),
             Primaries::SRGB,
             None,
+            None,
         )),
         jay_outputs: Default::default(),
         workspaces: Default::default(),
@@ -732,6 +767,8 @@ fn create_dummy_output(state: &Rc<State>) {
         update_render_data_scheduled: Cell::new(false),
         screencasts: Default::default(),
         hardware_cursor_needs_render: Cell::new(false),
+        hardware_cursor_fallback: Default::default(),
+        hardware_cursor_fallback_logged: Default::default(),
         screencopies: Default::default(),
         title_visible: Cell::new(false),
         schedule,
@@ -747,6 +784,10 @@ fn create_dummy_output(state: &Rc<State>) {
         ext_workspace_groups: Default::default(),
         pinned: Default::default(),
         tearing: Default::default(),
+        workspace_animation: Default::default(),
+        workspace_animation_task: Default::default(),
+        closing_windows: Default::default(),
+        closing_windows_task: Default::default(),
     });
     let dummy_workspace = Rc::new(WorkspaceNode {
         id: state.node_ids.next(),
@@ -767,6 +808,9 @@ fn create_dummy_output(state: &Rc<State>) {
         jay_workspaces: Default::default(),
         may_capture: Cell::new(false),
         has_capture: Cell::new(false),
+        layout: Default::default(),
+        inner_gap_override: Default::default(),
+        outer_gap_override: Default::default(),
         title_texture: Default::default(),
         attention_requests: Default::default(),
         render_highlight: Default::default(),
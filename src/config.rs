@@ -25,7 +25,7 @@ use {
             ConfigEntry, VERSION, bincode_ops,
             ipc::{InitMessage, ServerFeature, ServerMessage, V1InitMessage},
         },
-        input::{InputDevice, Seat, SwitchEvent},
+        input::{InputDevice, Seat, SwipeEvent, SwitchEvent},
         keyboard::{mods::Modifiers, syms::KeySym},
         video::{Connector, DrmDevice},
         window::{self, TileState},
@@ -161,6 +161,17 @@ impl ConfigProxy {
         });
     }
 
+    pub fn lock_requested(&self) {
+        self.send(&ServerMessage::LockRequested);
+    }
+
+    pub fn swipe_event(&self, seat: SeatId, event: SwipeEvent) {
+        self.send(&ServerMessage::SwipeEvent {
+            seat: Seat(seat.raw() as _),
+            event,
+        });
+    }
+
     pub fn toplevel_removed(&self, id: ToplevelIdentifier) {
         let Some(handler) = self.handler.get() else {
             return;
@@ -177,9 +188,20 @@ impl ConfigProxy {
         handler.auto_focus(data)
     }
 
+    pub fn may_inhibit_shortcuts(&self, data: &ToplevelData) -> bool {
+        let Some(handler) = self.handler.get() else {
+            return false;
+        };
+        handler.may_inhibit_shortcuts(data)
+    }
+
     pub fn initial_tile_state(&self, data: &ToplevelData) -> Option<TileState> {
         self.handler.get()?.initial_tile_state(data)
     }
+
+    pub fn initial_float_size(&self, data: &ToplevelData) -> Option<(i32, i32)> {
+        self.handler.get()?.initial_float_size(data)
+    }
 }
 
 impl Drop for ConfigProxy {
@@ -244,7 +266,9 @@ impl ConfigProxy {
             window_matcher_leafs: Default::default(),
             window_matcher_std_kinds: state.tl_matcher_manager.kind(window::CLIENT_WINDOW),
             window_matcher_no_auto_focus: Default::default(),
+            window_matcher_may_inhibit_shortcuts: Default::default(),
             window_matcher_initial_tile_state: Default::default(),
+            window_matcher_initial_float_size: Default::default(),
         });
         let init_msg = bincode_ops()
             .serialize(&InitMessage::V1(V1InitMessage {}))
@@ -370,6 +394,7 @@ unsafe extern "C" fn handle_msg(data: *const u8, msg: *const u8, size: usize) {
     }
 }
 
+#[derive(Copy, Clone)]
 pub struct InvokedShortcut {
     pub unmasked_mods: Modifiers,
     pub effective_mods: Modifiers,
@@ -4,9 +4,13 @@ use {
         backend::{
             self, BackendColorSpace, BackendTransferFunction, ConnectorId, DrmDeviceId,
             InputDeviceAccelProfile, InputDeviceCapability, InputDeviceClickMethod, InputDeviceId,
+            InputDeviceScrollMethod, InputDeviceSendEventsMode,
             transaction::BackendConnectorTransactionError,
         },
-        client::{Client, ClientId},
+        client::{
+            CAP_DATA_CONTROL_MANAGER, CAP_INPUT_METHOD, CAP_SCREENCOPY_MANAGER,
+            CAP_VIRTUAL_KEYBOARD_MANAGER, Client, ClientCaps, ClientId,
+        },
         cmm::cmm_transfer_function::TransferFunction,
         compositor::MAX_EXTENTS,
         config::ConfigProxy,
@@ -15,6 +19,7 @@ use {
             clm::ClmLeafMatcher,
             tlm::{TlmLeafMatcher, TlmUpstreamNode},
         },
+        fixed::Fixed,
         format::config_formats,
         ifs::{
             wl_seat::{SeatId, WlSeatGlobal},
@@ -27,10 +32,11 @@ use {
         state::{ConnectorData, DeviceHandlerData, DrmDevData, OutputData, State},
         theme::{Color, ThemeSized},
         tree::{
-            ContainerNode, ContainerSplit, FloatNode, Node, NodeVisitorBase, OutputNode,
+            self, ContainerNode, ContainerSplit, FloatNode, Node, NodeVisitorBase, OutputNode,
             TearingMode, ToplevelData, ToplevelNode, VrrMode, WorkspaceNode, WsMoveConfig,
-            move_ws_to_output, toplevel_create_split, toplevel_parent_container,
-            toplevel_set_floating, toplevel_set_workspace,
+            move_ws_to_output, toplevel_create_split, toplevel_move_to_scratchpad,
+            toplevel_parent_container, toplevel_set_floating, toplevel_set_workspace,
+            toplevel_toggle_scratchpad,
         },
         utils::{
             asyncevent::AsyncEvent,
@@ -51,8 +57,8 @@ use {
             WindowCriterionIpc, WindowCriterionStringField, WireMode, bincode_ops,
             ipc::{ClientMessage, Response, ServerMessage, WorkspaceSource},
         },
-        Axis, Direction, Workspace,
-        client::{Client as ConfigClient, ClientMatcher},
+        Axis, DecorationMode, Direction, FocusStealingPolicy, Workspace, WorkspaceLayout,
+        client::{self, Client as ConfigClient, ClientCapabilities, ClientMatcher},
         input::{
             FocusFollowsMouseMode, InputDevice, LayerDirection, Seat, Timeline,
             acceleration::{ACCEL_PROFILE_ADAPTIVE, ACCEL_PROFILE_FLAT, AccelProfile},
@@ -63,6 +69,14 @@ use {
             clickmethod::{
                 CLICK_METHOD_BUTTON_AREAS, CLICK_METHOD_CLICKFINGER, CLICK_METHOD_NONE, ClickMethod,
             },
+            scrollmethod::{
+                SCROLL_METHOD_EDGE, SCROLL_METHOD_NO_SCROLL, SCROLL_METHOD_ON_BUTTON_DOWN,
+                SCROLL_METHOD_TWO_FINGER, ScrollMethod,
+            },
+            sendeventsmode::{
+                SEND_EVENTS_MODE_DISABLED, SEND_EVENTS_MODE_DISABLED_ON_EXTERNAL_MOUSE,
+                SEND_EVENTS_MODE_ENABLED, SendEventsMode,
+            },
         },
         keyboard::{Keymap, mods::Modifiers, syms::KeySym},
         logging::LogLevel,
@@ -134,6 +148,8 @@ pub(super) struct ConfigProxyHandler {
     pub window_matcher_std_kinds: Rc<TlmUpstreamNode>,
     pub window_matcher_no_auto_focus:
         CopyHashMap<WindowMatcher, Rc<CachedCriterion<WindowCriterionIpc, ToplevelData>>>,
+    pub window_matcher_may_inhibit_shortcuts:
+        CopyHashMap<WindowMatcher, Rc<CachedCriterion<WindowCriterionIpc, ToplevelData>>>,
     pub window_matcher_initial_tile_state: CopyHashMap<
         WindowMatcher,
         (
@@ -141,6 +157,13 @@ pub(super) struct ConfigProxyHandler {
             TileState,
         ),
     >,
+    pub window_matcher_initial_float_size: CopyHashMap<
+        WindowMatcher,
+        (
+            Rc<CachedCriterion<WindowCriterionIpc, ToplevelData>>,
+            (i32, i32),
+        ),
+    >,
 }
 
 pub struct Pollable {
@@ -470,6 +493,64 @@ impl ConfigProxyHandler {
         Ok(())
     }
 
+    fn handle_set_focus_follows_mouse_delay(
+        &self,
+        seat: Seat,
+        delay: Duration,
+    ) -> Result<(), CphError> {
+        self.get_seat(seat)?.set_focus_follows_mouse_delay(delay);
+        Ok(())
+    }
+
+    fn handle_set_per_window_keyboard_layout(
+        &self,
+        seat: Seat,
+        enabled: bool,
+    ) -> Result<(), CphError> {
+        self.get_seat(seat)?.set_per_window_keyboard_layout(enabled);
+        Ok(())
+    }
+
+    fn handle_set_cursor_follows_focus(&self, seat: Seat, enabled: bool) -> Result<(), CphError> {
+        self.get_seat(seat)?.set_cursor_follows_focus(enabled);
+        Ok(())
+    }
+
+    fn handle_connector_set_zoom(&self, connector: Connector, zoom: f64) -> Result<(), CphError> {
+        self.get_output_node(connector)?.set_zoom(zoom);
+        Ok(())
+    }
+
+    fn handle_seat_adjust_zoom(&self, seat: Seat, factor: f64) -> Result<(), CphError> {
+        let output = self.get_seat(seat)?.pointer_cursor().output();
+        output.set_zoom(output.global.zoom.get() * factor);
+        Ok(())
+    }
+
+    fn handle_seat_reset_zoom(&self, seat: Seat) -> Result<(), CphError> {
+        self.get_seat(seat)?.pointer_cursor().output().set_zoom(1.0);
+        Ok(())
+    }
+
+    fn handle_set_hide_cursor_while_typing(
+        &self,
+        seat: Seat,
+        enabled: bool,
+    ) -> Result<(), CphError> {
+        self.get_seat(seat)?.set_hide_cursor_while_typing(enabled);
+        Ok(())
+    }
+
+    fn handle_seat_repeat_shortcut(&self, seat: Seat) -> Result<(), CphError> {
+        self.get_seat(seat)?.repeat_shortcut();
+        Ok(())
+    }
+
+    fn handle_seat_cycle_keyboard_layout(&self, seat: Seat, forward: bool) -> Result<(), CphError> {
+        self.get_seat(seat)?.cycle_keyboard_group(forward);
+        Ok(())
+    }
+
     fn handle_set_window_management_enabled(
         &self,
         seat: Seat,
@@ -619,6 +700,56 @@ impl ConfigProxyHandler {
         Ok(())
     }
 
+    fn handle_seat_move_window_to_output(
+        &self,
+        seat: Seat,
+        connector: Connector,
+    ) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        let output = self.get_output_node(connector)?;
+        seat.move_focused_to_output(&output);
+        Ok(())
+    }
+
+    fn handle_seat_move_window_to_adjacent_output(
+        &self,
+        seat: Seat,
+        direction: Direction,
+    ) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        seat.move_focused_to_adjacent_output(direction.into());
+        Ok(())
+    }
+
+    fn handle_seat_move_to_scratchpad(&self, seat: Seat, name: &str) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        if let Some(tl) = seat.get_keyboard_node().node_toplevel() {
+            toplevel_move_to_scratchpad(&self.state, tl, name);
+        }
+        Ok(())
+    }
+
+    fn handle_seat_toggle_scratchpad(&self, seat: Seat, name: &str) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        toplevel_toggle_scratchpad(&self.state, &seat, name);
+        Ok(())
+    }
+
+    fn handle_set_swallow_app_ids(&self, app_ids: Vec<String>) {
+        self.state.swallow_app_ids.clear();
+        for app_id in app_ids {
+            self.state.swallow_app_ids.set(app_id, ());
+        }
+    }
+
+    fn handle_set_clipboard_persistence_enabled(&self, enabled: bool) {
+        self.state.clipboard_persistence.set(enabled);
+    }
+
+    fn handle_set_clipboard_persistence_max_size(&self, max_size: u64) {
+        self.state.clipboard_persistence_max_size.set(max_size);
+    }
+
     fn handle_window_move(&self, window: Window, direction: Direction) -> Result<(), CphError> {
         let window = self.get_window(window)?;
         if let Some(c) = toplevel_parent_container(&*window) {
@@ -895,6 +1026,72 @@ impl ConfigProxyHandler {
         Ok(())
     }
 
+    fn handle_set_default_workspace_layout(&self, layout: WorkspaceLayout) -> Result<(), CphError> {
+        let layout = self.get_workspace_layout(layout)?;
+        self.state.default_workspace_layout.set(layout);
+        Ok(())
+    }
+
+    fn handle_set_workspace_layout(
+        &self,
+        workspace: Workspace,
+        layout: WorkspaceLayout,
+    ) -> Result<(), CphError> {
+        let layout = self.get_workspace_layout(layout)?;
+        if let Some(ws) = self.get_existing_workspace(workspace)? {
+            ws.layout.set(layout);
+        }
+        Ok(())
+    }
+
+    fn handle_set_workspace_gap(
+        &self,
+        workspace: Workspace,
+        sized: ThemeSized,
+        gap: Option<i32>,
+    ) -> Result<(), CphError> {
+        if let Some(gap) = gap
+            && (gap < sized.min() || gap > sized.max())
+        {
+            return Err(CphError::InvalidSize(gap, sized));
+        }
+        if let Some(ws) = self.get_existing_workspace(workspace)? {
+            let field = match sized {
+                ThemeSized::inner_gap => &ws.inner_gap_override,
+                _ => &ws.outer_gap_override,
+            };
+            if field.replace(gap) != gap {
+                ws.on_gaps_changed();
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_get_workspace_gaps(&self, workspace: Workspace) -> Result<(), CphError> {
+        let (inner, outer) = match self.get_existing_workspace(workspace)? {
+            Some(ws) => (ws.inner_gap(), ws.outer_gap()),
+            None => {
+                let sizes = &self.state.theme.sizes;
+                (sizes.inner_gap.get(), sizes.outer_gap.get())
+            }
+        };
+        self.respond(Response::GetWorkspaceGaps { inner, outer });
+        Ok(())
+    }
+
+    fn get_workspace_layout(
+        &self,
+        layout: WorkspaceLayout,
+    ) -> Result<tree::WorkspaceLayout, CphError> {
+        let layout = match layout {
+            WorkspaceLayout::MANUAL => tree::WorkspaceLayout::Manual,
+            WorkspaceLayout::BSP => tree::WorkspaceLayout::Bsp,
+            WorkspaceLayout::TABBED => tree::WorkspaceLayout::Tabbed,
+            _ => return Err(CphError::UnknownWorkspaceLayout(layout)),
+        };
+        Ok(layout)
+    }
+
     fn handle_set_gfx_api(&self, device: Option<DrmDevice>, api: GfxApi) -> Result<(), CphError> {
         match device {
             Some(dev) => self.get_drm_device(dev)?.dev.set_gfx_api(api),
@@ -997,6 +1194,33 @@ impl ConfigProxyHandler {
         Ok(())
     }
 
+    fn handle_seat_show_workspace_rel(&self, seat: Seat, forward: bool) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        let output = seat.get_output();
+        if output.is_dummy {
+            return Ok(());
+        }
+        let Some(current) = output.workspace.get() else {
+            return Ok(());
+        };
+        let workspaces: Vec<_> = output
+            .workspaces
+            .iter()
+            .map(|ws| ws.deref().clone())
+            .collect();
+        let Some(idx) = workspaces.iter().position(|ws| ws.id == current.id) else {
+            return Ok(());
+        };
+        let next = match forward {
+            true => workspaces.get(idx + 1),
+            false => idx.checked_sub(1).and_then(|idx| workspaces.get(idx)),
+        };
+        if let Some(ws) = next {
+            self.state.show_workspace2(Some(&seat), &output, ws);
+        }
+        Ok(())
+    }
+
     fn handle_set_seat_workspace(&self, seat: Seat, ws: Workspace) -> Result<(), CphError> {
         let seat = self.get_seat(seat)?;
         let name = self.get_workspace(ws)?;
@@ -1047,6 +1271,60 @@ impl ConfigProxyHandler {
         Ok(())
     }
 
+    fn handle_get_input_device_ids(&self, device: InputDevice) -> Result<(), CphError> {
+        let dev = self.get_device_handler_data(device)?;
+        self.respond(Response::GetInputDeviceIds {
+            vendor: dev.device.vendor_id().unwrap_or_default(),
+            product: dev.device.product_id().unwrap_or_default(),
+        });
+        Ok(())
+    }
+
+    fn handle_set_scroll_method(
+        &self,
+        device: InputDevice,
+        scroll_method: ScrollMethod,
+    ) -> Result<(), CphError> {
+        let dev = self.get_device_handler_data(device)?;
+        let method = match scroll_method {
+            SCROLL_METHOD_NO_SCROLL => InputDeviceScrollMethod::NoScroll,
+            SCROLL_METHOD_TWO_FINGER => InputDeviceScrollMethod::TwoFinger,
+            SCROLL_METHOD_EDGE => InputDeviceScrollMethod::Edge,
+            SCROLL_METHOD_ON_BUTTON_DOWN => InputDeviceScrollMethod::OnButtonDown,
+            _ => return Err(CphError::UnknownScrollMethod(scroll_method)),
+        };
+        dev.device.set_scroll_method(method);
+        Ok(())
+    }
+
+    fn handle_set_disable_while_typing_enabled(
+        &self,
+        device: InputDevice,
+        enabled: bool,
+    ) -> Result<(), CphError> {
+        let dev = self.get_device_handler_data(device)?;
+        dev.device.set_disable_while_typing_enabled(enabled);
+        Ok(())
+    }
+
+    fn handle_set_send_events_mode(
+        &self,
+        device: InputDevice,
+        send_events_mode: SendEventsMode,
+    ) -> Result<(), CphError> {
+        let dev = self.get_device_handler_data(device)?;
+        let mode = match send_events_mode {
+            SEND_EVENTS_MODE_ENABLED => InputDeviceSendEventsMode::Enabled,
+            SEND_EVENTS_MODE_DISABLED => InputDeviceSendEventsMode::Disabled,
+            SEND_EVENTS_MODE_DISABLED_ON_EXTERNAL_MOUSE => {
+                InputDeviceSendEventsMode::DisabledOnExternalMouse
+            }
+            _ => return Err(CphError::UnknownSendEventsMode(send_events_mode)),
+        };
+        dev.device.set_send_events_mode(mode);
+        Ok(())
+    }
+
     fn handle_move_to_output(
         &self,
         workspace: WorkspaceSource,
@@ -1217,6 +1495,13 @@ impl ConfigProxyHandler {
         Ok(())
     }
 
+    fn handle_set_cursor_theme(&self, seat: Seat, theme: Option<&str>) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        seat.cursor_group()
+            .set_cursor_theme(theme.map(|t| Rc::new(t.to_string())));
+        Ok(())
+    }
+
     fn handle_disable_pointer_constraint(&self, seat: Seat) -> Result<(), CphError> {
         let seat = self.get_seat(seat)?;
         seat.disable_pointer_constraint();
@@ -1316,6 +1601,21 @@ impl ConfigProxyHandler {
         Ok(())
     }
 
+    fn handle_connector_set_cursor_size(
+        &self,
+        connector: Connector,
+        size: Option<i32>,
+    ) -> Result<(), CphError> {
+        if let Some(size) = size
+            && size < 0
+        {
+            return Err(CphError::NegativeCursorSize);
+        }
+        let connector = self.get_output_node(connector)?;
+        connector.set_cursor_size(size.map(|s| s as u32));
+        Ok(())
+    }
+
     fn handle_set_float_above_fullscreen(&self, above: bool) {
         self.state.float_above_fullscreen.set(above);
         for seat in self.state.globals.seats.lock().values() {
@@ -1353,6 +1653,15 @@ impl ConfigProxyHandler {
         }
     }
 
+    fn handle_set_show_float_close_icon(&self, show: bool) {
+        self.state.show_close_icon.set(show);
+        for stacked in self.state.root.stacked.iter() {
+            if let Some(float) = stacked.deref().clone().node_into_float() {
+                float.schedule_render_titles();
+            }
+        }
+    }
+
     fn handle_get_seat_float_pinned(&self, seat: Seat) -> Result<(), CphError> {
         let seat = self.get_seat(seat)?;
         self.respond(Response::GetFloatPinned {
@@ -1511,6 +1820,29 @@ impl ConfigProxyHandler {
         Ok(())
     }
 
+    fn handle_get_workspace_connector(&self, workspace: Workspace) -> Result<(), CphError> {
+        let name = self.get_workspace(workspace)?;
+        let connector = self
+            .state
+            .workspace_output(&name)
+            .map_or(Connector(0), |o| {
+                Connector(o.global.connector.connector.id().raw() as _)
+            });
+        self.respond(Response::GetWorkspaceConnector { connector });
+        Ok(())
+    }
+
+    fn handle_bind_workspace_to_connector(
+        &self,
+        workspace: Workspace,
+        connector: Connector,
+    ) -> Result<(), CphError> {
+        let name = self.get_workspace(workspace)?;
+        let output = self.get_output_node(connector)?;
+        self.state.bind_workspace_to_output(&name, &output);
+        Ok(())
+    }
+
     fn handle_get_connector_workspaces(&self, connector: Connector) -> Result<(), CphError> {
         let output = self.get_output_node(connector)?;
         let workspaces = output
@@ -1769,6 +2101,130 @@ impl ConfigProxyHandler {
         Ok(())
     }
 
+    fn handle_get_window_opacity(&self, window: Window) -> Result<(), CphError> {
+        let window = self.get_window(window)?;
+        self.respond(Response::GetWindowOpacity {
+            opacity: window.tl_data().opacity.get(),
+        });
+        Ok(())
+    }
+
+    fn handle_set_window_opacity(&self, window: Window, opacity: f32) -> Result<(), CphError> {
+        let window = self.get_window(window)?;
+        let opacity = opacity.clamp(0.0, 1.0);
+        if window.tl_data().opacity.replace(opacity) != opacity {
+            self.state.damage(window.node_absolute_position());
+        }
+        Ok(())
+    }
+
+    fn handle_set_inactive_window_opacity(&self, opacity: f32) {
+        let opacity = opacity.clamp(0.0, 1.0);
+        if self.state.inactive_window_opacity.replace(opacity) != opacity {
+            self.state.damage(self.state.root.extents.get());
+        }
+    }
+
+    fn handle_set_workspace_animation_duration(&self, duration: Duration) {
+        self.state.workspace_animation_duration.set(duration);
+    }
+
+    fn handle_set_window_animation_duration(&self, duration: Duration) {
+        self.state.window_animation_duration.set(duration);
+    }
+
+    fn handle_set_focus_stealing_policy(&self, policy: FocusStealingPolicy) {
+        self.state.focus_stealing_policy.set(policy);
+    }
+
+    fn handle_set_decoration_mode(&self, mode: DecorationMode) {
+        self.state.decoration_mode.set(mode);
+    }
+
+    fn handle_set_client_object_limit(&self, limit: Option<u64>) {
+        self.state.client_object_limit.set(limit);
+    }
+
+    fn handle_set_client_shm_limit(&self, limit: Option<u64>) {
+        self.state.client_shm_limit.set(limit);
+    }
+
+    fn handle_set_sandbox_capabilities(&self, grants: Vec<(String, ClientCapabilities)>) {
+        let map = [
+            (client::CAP_SCREENCOPY, CAP_SCREENCOPY_MANAGER),
+            (client::CAP_VIRTUAL_KEYBOARD, CAP_VIRTUAL_KEYBOARD_MANAGER),
+            (client::CAP_INPUT_METHOD, CAP_INPUT_METHOD),
+            (client::CAP_DATA_CONTROL, CAP_DATA_CONTROL_MANAGER),
+        ];
+        self.state.sandbox_capabilities.clear();
+        for (app_id, caps) in grants {
+            let mut granted = ClientCaps::none();
+            for (cap, client_cap) in map {
+                if caps.0 & cap.0 != 0 {
+                    granted |= client_cap;
+                }
+            }
+            self.state.sandbox_capabilities.set(app_id, granted);
+        }
+    }
+
+    fn handle_connector_set_max_fps(
+        &self,
+        connector: Connector,
+        max_fps: Option<f64>,
+    ) -> Result<(), CphError> {
+        let max_fps = max_fps.filter(|fps| *fps > 0.0 && fps.is_finite());
+        let connector = self.get_output_node(connector)?;
+        connector.set_max_fps(max_fps);
+        Ok(())
+    }
+
+    fn handle_set_low_power_mode(&self, enabled: bool) {
+        self.state.set_low_power_mode(enabled);
+    }
+
+    fn handle_set_low_power_max_fps(&self, max_fps: Option<f64>) {
+        let max_fps = max_fps.filter(|fps| *fps > 0.0 && fps.is_finite());
+        self.state.low_power_max_fps.set(max_fps);
+    }
+
+    fn handle_set_idle_dim(&self, dim: f32) {
+        self.state.idle.set_dim(dim);
+    }
+
+    fn handle_seat_warp_pointer(&self, seat: Seat, x: f64, y: f64) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        seat.warp_pointer(Fixed::from_f64(x), Fixed::from_f64(y));
+        Ok(())
+    }
+
+    fn handle_seat_warp_pointer_to_connector(
+        &self,
+        seat: Seat,
+        connector: Connector,
+    ) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        let output = self.get_output_node(connector)?;
+        let (x, y) = output.node_absolute_position().center();
+        seat.warp_pointer(Fixed::from_int(x), Fixed::from_int(y));
+        Ok(())
+    }
+
+    fn handle_seat_warp_pointer_to_window(
+        &self,
+        seat: Seat,
+        window_id: Window,
+    ) -> Result<(), CphError> {
+        let seat = self.get_seat(seat)?;
+        let window = self.get_window(window_id)?;
+        if !window.node_visible() {
+            return Err(CphError::WindowNotVisible(window_id));
+        }
+        let (x, y) = window.node_absolute_position().center();
+        seat.warp_pointer(Fixed::from_int(x), Fixed::from_int(y));
+        Ok(())
+    }
+
     fn handle_add_pollable(self: &Rc<Self>, fd: i32) -> Result<(), CphError> {
         let fd = match fcntl_dupfd_cloexec(fd, 0) {
             Ok(fd) => Rc::new(fd),
@@ -2097,7 +2553,9 @@ impl ConfigProxyHandler {
         self.window_matchers.remove(&matcher);
         self.window_matcher_leafs.remove(&matcher);
         self.window_matcher_no_auto_focus.remove(&matcher);
+        self.window_matcher_may_inhibit_shortcuts.remove(&matcher);
         self.window_matcher_initial_tile_state.remove(&matcher);
+        self.window_matcher_initial_float_size.remove(&matcher);
     }
 
     fn handle_enable_window_matcher_events(
@@ -2141,6 +2599,20 @@ impl ConfigProxyHandler {
         Ok(())
     }
 
+    fn handle_set_window_matcher_may_inhibit_shortcuts(
+        &self,
+        matcher: WindowMatcher,
+        may_inhibit: bool,
+    ) -> Result<(), CphError> {
+        if may_inhibit {
+            let m = self.get_window_matcher(matcher)?;
+            self.window_matcher_may_inhibit_shortcuts.set(matcher, m);
+        } else {
+            self.window_matcher_may_inhibit_shortcuts.remove(&matcher);
+        }
+        Ok(())
+    }
+
     fn handle_set_window_matcher_initial_tile_state(
         &self,
         matcher: WindowMatcher,
@@ -2152,6 +2624,18 @@ impl ConfigProxyHandler {
         Ok(())
     }
 
+    fn handle_set_window_matcher_initial_float_size(
+        &self,
+        matcher: WindowMatcher,
+        width: i32,
+        height: i32,
+    ) -> Result<(), CphError> {
+        let m = self.get_window_matcher(matcher)?;
+        self.window_matcher_initial_float_size
+            .set(matcher, (m, (width.max(1), height.max(1))));
+        Ok(())
+    }
+
     fn handle_set_pointer_revert_key(&self, seat: Seat, key: KeySym) -> Result<(), CphError> {
         self.get_seat(seat)?.set_pointer_revert_key(key);
         Ok(())
@@ -2209,6 +2693,10 @@ impl ConfigProxyHandler {
         self.state.enable_primary_selection.set(enabled);
     }
 
+    fn handle_set_freeze_cursor_animation_when_idle(&self, freeze: bool) {
+        self.state.set_freeze_cursor_animation_when_idle(freeze);
+    }
+
     fn handle_seat_create_mark(&self, seat: Seat, kc: Option<u32>) -> Result<(), CphError> {
         let seat = self.get_seat(seat)?;
         if let Some(kc) = kc {
@@ -2278,6 +2766,11 @@ impl ConfigProxyHandler {
         let sized = match sized {
             TITLE_HEIGHT => ThemeSized::title_height,
             BORDER_WIDTH => ThemeSized::border_width,
+            SHADOW_SIZE => ThemeSized::shadow_size,
+            SHADOW_OFFSET_X => ThemeSized::shadow_offset_x,
+            SHADOW_OFFSET_Y => ThemeSized::shadow_offset_y,
+            INNER_GAP => ThemeSized::inner_gap,
+            OUTER_GAP => ThemeSized::outer_gap,
             _ => return Err(CphError::UnknownSized(sized.0)),
         };
         Ok(sized)
@@ -2350,6 +2843,7 @@ impl ConfigProxyHandler {
             BAR_STATUS_TEXT_COLOR => &colors.bar_text,
             ATTENTION_REQUESTED_BACKGROUND_COLOR => &colors.attention_requested_background,
             HIGHLIGHT_COLOR => &colors.highlight,
+            SHADOW_COLOR => &colors.shadow,
             _ => return Err(CphError::UnknownColor(colorable.0)),
         };
         Ok(colorable)
@@ -3107,6 +3601,159 @@ impl ConfigProxyHandler {
             ClientMessage::SeatCopyMark { seat, src, dst } => self
                 .handle_seat_copy_mark(seat, src, dst)
                 .wrn("seat_copy_mark")?,
+            ClientMessage::ConnectorSetCursorSize { connector, size } => self
+                .handle_connector_set_cursor_size(connector, size)
+                .wrn("connector_set_cursor_size")?,
+            ClientMessage::SetFreezeCursorAnimationWhenIdle { freeze } => {
+                self.handle_set_freeze_cursor_animation_when_idle(freeze)
+            }
+            ClientMessage::SetCursorTheme { seat, theme } => self
+                .handle_set_cursor_theme(seat, theme)
+                .wrn("set_cursor_theme")?,
+            ClientMessage::GetWorkspaceConnector { workspace } => self
+                .handle_get_workspace_connector(workspace)
+                .wrn("get_workspace_connector")?,
+            ClientMessage::BindWorkspaceToConnector {
+                workspace,
+                connector,
+            } => self
+                .handle_bind_workspace_to_connector(workspace, connector)
+                .wrn("bind_workspace_to_connector")?,
+            ClientMessage::SetWindowMatcherMayInhibitShortcuts {
+                matcher,
+                may_inhibit,
+            } => self
+                .handle_set_window_matcher_may_inhibit_shortcuts(matcher, may_inhibit)
+                .wrn("set_window_matcher_may_inhibit_shortcuts")?,
+            ClientMessage::GetWindowOpacity { window } => self
+                .handle_get_window_opacity(window)
+                .wrn("get_window_opacity")?,
+            ClientMessage::SetWindowOpacity { window, opacity } => self
+                .handle_set_window_opacity(window, opacity)
+                .wrn("set_window_opacity")?,
+            ClientMessage::SetInactiveWindowOpacity { opacity } => {
+                self.handle_set_inactive_window_opacity(opacity)
+            }
+            ClientMessage::SetWorkspaceAnimationDuration { duration } => {
+                self.handle_set_workspace_animation_duration(duration)
+            }
+            ClientMessage::SetWindowAnimationDuration { duration } => {
+                self.handle_set_window_animation_duration(duration)
+            }
+            ClientMessage::SetWindowMatcherInitialFloatSize {
+                matcher,
+                width,
+                height,
+            } => self
+                .handle_set_window_matcher_initial_float_size(matcher, width, height)
+                .wrn("set_window_matcher_initial_float_size")?,
+            ClientMessage::SetFocusFollowsMouseDelay { seat, delay } => self
+                .handle_set_focus_follows_mouse_delay(seat, delay)
+                .wrn("set_focus_follows_mouse_delay")?,
+            ClientMessage::SetFocusStealingPolicy { policy } => {
+                self.handle_set_focus_stealing_policy(policy)
+            }
+            ClientMessage::SetPerWindowKeyboardLayout { seat, enabled } => self
+                .handle_set_per_window_keyboard_layout(seat, enabled)
+                .wrn("set_per_window_keyboard_layout")?,
+            ClientMessage::SeatCycleKeyboardLayout { seat, forward } => self
+                .handle_seat_cycle_keyboard_layout(seat, forward)
+                .wrn("seat_cycle_keyboard_layout")?,
+            ClientMessage::SeatRepeatShortcut { seat } => self
+                .handle_seat_repeat_shortcut(seat)
+                .wrn("seat_repeat_shortcut")?,
+            ClientMessage::GetInputDeviceIds { device } => self
+                .handle_get_input_device_ids(device)
+                .wrn("get_input_device_ids")?,
+            ClientMessage::SetScrollMethod { device, method } => self
+                .handle_set_scroll_method(device, method)
+                .wrn("set_scroll_method")?,
+            ClientMessage::SetDisableWhileTypingEnabled { device, enabled } => self
+                .handle_set_disable_while_typing_enabled(device, enabled)
+                .wrn("set_disable_while_typing_enabled")?,
+            ClientMessage::SetSendEventsMode { device, mode } => self
+                .handle_set_send_events_mode(device, mode)
+                .wrn("set_send_events_mode")?,
+            ClientMessage::SeatShowWorkspaceRel { seat, forward } => self
+                .handle_seat_show_workspace_rel(seat, forward)
+                .wrn("seat_show_workspace_rel")?,
+            ClientMessage::SetDefaultWorkspaceLayout { layout } => self
+                .handle_set_default_workspace_layout(layout)
+                .wrn("set_default_workspace_layout")?,
+            ClientMessage::SetWorkspaceLayout { workspace, layout } => self
+                .handle_set_workspace_layout(workspace, layout)
+                .wrn("set_workspace_layout")?,
+            ClientMessage::SetWorkspaceInnerGap { workspace, gap } => self
+                .handle_set_workspace_gap(workspace, ThemeSized::inner_gap, gap)
+                .wrn("set_workspace_inner_gap")?,
+            ClientMessage::SetWorkspaceOuterGap { workspace, gap } => self
+                .handle_set_workspace_gap(workspace, ThemeSized::outer_gap, gap)
+                .wrn("set_workspace_outer_gap")?,
+            ClientMessage::GetWorkspaceGaps { workspace } => self
+                .handle_get_workspace_gaps(workspace)
+                .wrn("get_workspace_gaps")?,
+            ClientMessage::SeatMoveWindowToOutput { seat, connector } => self
+                .handle_seat_move_window_to_output(seat, connector)
+                .wrn("seat_move_window_to_output")?,
+            ClientMessage::SeatMoveWindowToAdjacentOutput { seat, direction } => self
+                .handle_seat_move_window_to_adjacent_output(seat, direction)
+                .wrn("seat_move_window_to_adjacent_output")?,
+            ClientMessage::SeatMoveToScratchpad { seat, name } => self
+                .handle_seat_move_to_scratchpad(seat, name)
+                .wrn("seat_move_to_scratchpad")?,
+            ClientMessage::SeatToggleScratchpad { seat, name } => self
+                .handle_seat_toggle_scratchpad(seat, name)
+                .wrn("seat_toggle_scratchpad")?,
+            ClientMessage::SetSwallowAppIds { app_ids } => self.handle_set_swallow_app_ids(app_ids),
+            ClientMessage::SetShowFloatCloseIcon { show } => {
+                self.handle_set_show_float_close_icon(show)
+            }
+            ClientMessage::SetDecorationMode { mode } => self.handle_set_decoration_mode(mode),
+            ClientMessage::SetClipboardPersistenceEnabled { enabled } => {
+                self.handle_set_clipboard_persistence_enabled(enabled)
+            }
+            ClientMessage::SetClipboardPersistenceMaxSize { max_size } => {
+                self.handle_set_clipboard_persistence_max_size(max_size)
+            }
+            ClientMessage::SetClientObjectLimit { limit } => {
+                self.handle_set_client_object_limit(limit)
+            }
+            ClientMessage::SetClientShmLimit { limit } => self.handle_set_client_shm_limit(limit),
+            ClientMessage::SetSandboxCapabilities { grants } => {
+                self.handle_set_sandbox_capabilities(grants)
+            }
+            ClientMessage::ConnectorSetMaxFps { connector, max_fps } => self
+                .handle_connector_set_max_fps(connector, max_fps)
+                .wrn("connector_set_max_fps")?,
+            ClientMessage::SetLowPowerMode { enabled } => self.handle_set_low_power_mode(enabled),
+            ClientMessage::SetLowPowerMaxFps { max_fps } => {
+                self.handle_set_low_power_max_fps(max_fps)
+            }
+            ClientMessage::SetIdleDim { dim } => self.handle_set_idle_dim(dim),
+            ClientMessage::SeatWarpPointer { seat, x, y } => self
+                .handle_seat_warp_pointer(seat, x, y)
+                .wrn("seat_warp_pointer")?,
+            ClientMessage::SeatWarpPointerToConnector { seat, connector } => self
+                .handle_seat_warp_pointer_to_connector(seat, connector)
+                .wrn("seat_warp_pointer_to_connector")?,
+            ClientMessage::SeatWarpPointerToWindow { seat, window } => self
+                .handle_seat_warp_pointer_to_window(seat, window)
+                .wrn("seat_warp_pointer_to_window")?,
+            ClientMessage::SetCursorFollowsFocus { seat, enabled } => self
+                .handle_set_cursor_follows_focus(seat, enabled)
+                .wrn("set_cursor_follows_focus")?,
+            ClientMessage::SetHideCursorWhileTyping { seat, enabled } => self
+                .handle_set_hide_cursor_while_typing(seat, enabled)
+                .wrn("set_hide_cursor_while_typing")?,
+            ClientMessage::ConnectorSetZoom { connector, zoom } => self
+                .handle_connector_set_zoom(connector, zoom)
+                .wrn("connector_set_zoom")?,
+            ClientMessage::SeatAdjustZoom { seat, factor } => self
+                .handle_seat_adjust_zoom(seat, factor)
+                .wrn("seat_adjust_zoom")?,
+            ClientMessage::SeatResetZoom { seat } => {
+                self.handle_seat_reset_zoom(seat).wrn("seat_reset_zoom")?
+            }
         }
         Ok(())
     }
@@ -3120,6 +3767,15 @@ impl ConfigProxyHandler {
         true
     }
 
+    pub fn may_inhibit_shortcuts(&self, data: &ToplevelData) -> bool {
+        for matcher in self.window_matcher_may_inhibit_shortcuts.lock().values() {
+            if matcher.node.pull(data) {
+                return true;
+            }
+        }
+        false
+    }
+
     pub fn initial_tile_state(&self, data: &ToplevelData) -> Option<TileState> {
         for (matcher, state) in self.window_matcher_initial_tile_state.lock().values() {
             if matcher.node.pull(data) {
@@ -3128,6 +3784,15 @@ impl ConfigProxyHandler {
         }
         None
     }
+
+    pub fn initial_float_size(&self, data: &ToplevelData) -> Option<(i32, i32)> {
+        for (matcher, size) in self.window_matcher_initial_float_size.lock().values() {
+            if matcher.node.pull(data) {
+                return Some(*size);
+            }
+        }
+        None
+    }
 }
 
 #[derive(Debug, Error)]
@@ -3138,6 +3803,12 @@ enum CphError {
     UnknownCapability(Capability),
     #[error("Tried to set an unknown click method: {}", (.0).0)]
     UnknownClickMethod(ClickMethod),
+    #[error("Tried to set an unknown scroll method: {}", (.0).0)]
+    UnknownScrollMethod(ScrollMethod),
+    #[error("Tried to set an unknown send-events mode: {}", (.0).0)]
+    UnknownSendEventsMode(SendEventsMode),
+    #[error("Tried to set an unknown workspace layout: {}", (.0).0)]
+    UnknownWorkspaceLayout(WorkspaceLayout),
     #[error("The sized {} is outside the valid range [{}, {}] for component {}", .0, .1.min(), .1.max(), .1.name())]
     InvalidSize(i32, ThemeSized),
     #[error("The ol' forker is not available")]
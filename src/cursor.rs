@@ -74,6 +74,15 @@ pub trait Cursor {
     fn set_visible(&self, visible: bool) {
         let _ = visible;
     }
+
+    fn animation_info(&self) -> Option<CursorAnimationInfo> {
+        None
+    }
+}
+
+pub struct CursorAnimationInfo {
+    pub current_frame: usize,
+    pub frame_delays_ns: Vec<u64>,
 }
 
 pub struct ServerCursors {
@@ -156,7 +165,11 @@ pub enum KnownCursor {
 }
 
 impl ServerCursors {
-    pub fn load(ctx: &Rc<dyn GfxContext>, state: &State) -> Result<Option<Self>, CursorError> {
+    pub fn load(
+        ctx: &Rc<dyn GfxContext>,
+        state: &State,
+        theme: Option<&str>,
+    ) -> Result<Option<Self>, CursorError> {
         let paths = find_cursor_paths();
         log::debug!("Trying to load cursors from paths {:?}", paths);
         let sizes = state.cursor_sizes.to_vec();
@@ -165,7 +178,10 @@ impl ServerCursors {
             return Ok(None);
         }
         let xcursor_theme = env::var_os(XCURSOR_THEME);
-        let theme = xcursor_theme.as_ref().map(|theme| BStr::new(theme.bytes()));
+        let theme = match theme {
+            Some(theme) => Some(BStr::new(theme.as_bytes())),
+            None => xcursor_theme.as_ref().map(|theme| BStr::new(theme.bytes())),
+        };
 
         let load =
             |names: &[&str]| ServerCursorTemplate::load(names, theme, &scales, &sizes, &paths, ctx);
@@ -486,7 +502,14 @@ impl Cursor for AnimatedCursor {
         let idx = (self.idx.get() + 1) % self.images.len();
         self.idx.set(idx);
         let image = &self.images[idx];
-        self.next.fetch_add(image.delay_ns);
+        let mut next = self.next.get() + image.delay_ns;
+        let dist = dist.as_nanos() as u64;
+        if next <= dist {
+            // The animation was not ticked for more than a frame, e.g. because it was
+            // frozen. Don't try to catch up.
+            next = dist + image.delay_ns;
+        }
+        self.next.set(next);
     }
 
     fn needs_tick(&self) -> bool {
@@ -499,6 +522,13 @@ impl Cursor for AnimatedCursor {
         let nanos = self.next.get().saturating_sub(dist);
         Duration::from_nanos(nanos)
     }
+
+    fn animation_info(&self) -> Option<CursorAnimationInfo> {
+        Some(CursorAnimationInfo {
+            current_frame: self.idx.get(),
+            frame_delays_ns: self.images.iter().map(|i| i.delay_ns).collect(),
+        })
+    }
 }
 
 struct OpenCursorResult {
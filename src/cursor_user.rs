@@ -1,21 +1,26 @@
 use {
     crate::{
         backend::HardwareCursorUpdate,
-        cursor::{Cursor, DEFAULT_CURSOR_SIZE, KnownCursor},
+        cursor::{Cursor, DEFAULT_CURSOR_SIZE, KnownCursor, ServerCursors},
         fixed::Fixed,
         gfx_api::{AcquireSync, ReleaseSync},
         rect::Rect,
         scale::Scale,
         state::State,
+        time::Time,
         tree::OutputNode,
         utils::{
             clonecell::CloneCell, copyhashmap::CopyHashMap, errorfmt::ErrorFmt,
             hash_map_ext::HashMapExt, rc_eq::rc_eq, transform_ext::TransformExt,
         },
     },
-    std::{cell::Cell, ops::Deref, rc::Rc},
+    std::{cell::Cell, ops::Deref, rc::Rc, time::Duration},
 };
 
+/// The time without pointer motion after which the cursor animation is frozen if
+/// `freeze_cursor_animation_when_idle` is enabled.
+const POINTER_IDLE_TIMEOUT: Duration = Duration::from_secs(10);
+
 linear_ids!(CursorUserGroupIds, CursorUserGroupId, u64);
 linear_ids!(CursorUserIds, CursorUserId, u64);
 
@@ -31,7 +36,11 @@ pub struct CursorUserGroup {
     users: CopyHashMap<CursorUserId, Rc<CursorUser>>,
     hardware_cursor: Cell<bool>,
     size: Cell<u32>,
+    theme: CloneCell<Option<Rc<String>>>,
     latest_output: CloneCell<Rc<OutputNode>>,
+    visible: Cell<bool>,
+    hidden: Cell<bool>,
+    last_motion: Cell<Time>,
 }
 
 pub struct CursorUser {
@@ -65,7 +74,11 @@ impl CursorUserGroup {
             users: Default::default(),
             hardware_cursor: Cell::new(hardware_cursor),
             size: Cell::new(*DEFAULT_CURSOR_SIZE),
+            theme: Default::default(),
             latest_output: CloneCell::new(output),
+            visible: Cell::new(state.root_visible()),
+            hidden: Cell::new(false),
+            last_motion: Cell::new(state.now()),
         });
         state.add_cursor_size(*DEFAULT_CURSOR_SIZE);
         state.cursor_user_groups.set(group.id, group.clone());
@@ -113,6 +126,9 @@ impl CursorUserGroup {
         self.latest_output
             .set(self.state.dummy_output.get().unwrap());
         self.state.remove_cursor_size(self.size.get());
+        if let Some(theme) = self.theme.take() {
+            self.state.remove_cursor_theme(&theme);
+        }
         self.state.cursor_user_groups.remove(&self.id);
         for user in self.users.lock().drain_values() {
             user.detach();
@@ -136,13 +152,51 @@ impl CursorUserGroup {
     }
 
     pub fn set_visible(&self, visible: bool) {
-        if let Some(user) = self.active.get()
-            && let Some(cursor) = user.cursor.get()
-        {
-            cursor.set_visible(visible);
+        let changed = self.visible.replace(visible) != visible;
+        if let Some(user) = self.active.get() {
+            if let Some(cursor) = user.cursor.get() {
+                cursor.set_visible(visible && !self.hidden.get());
+            }
+            if changed && visible {
+                // Restart the animation if it was frozen.
+                user.update_hardware_cursor_(false);
+            }
+        }
+    }
+
+    /// Returns whether animated cursors of this group should not be advanced.
+    ///
+    /// Hidden cursors are never animated. If `freeze_cursor_animation_when_idle` is
+    /// enabled, the animation is also frozen while the compositor is idle and after the
+    /// pointer has not moved for a while.
+    pub fn animation_frozen(&self) -> bool {
+        if !self.visible.get() || self.hidden.get() {
+            return true;
+        }
+        let state = &self.state;
+        if state.cursor_animation_frozen() {
+            return true;
+        }
+        state.freeze_cursor_animation_when_idle.get()
+            && state.now() - self.last_motion.get() >= POINTER_IDLE_TIMEOUT
+    }
+
+    /// Hides the cursor without changing the cursor that is set.
+    pub fn set_hidden(&self, hidden: bool) {
+        if self.hidden.replace(hidden) == hidden {
+            return;
+        }
+        self.set_visible(self.visible.get());
+        self.damage_active();
+        if let Some(active) = self.active.get() {
+            active.update_hardware_cursor();
         }
     }
 
+    pub fn hidden(&self) -> bool {
+        self.hidden.get()
+    }
+
     pub fn active(&self) -> Option<Rc<CursorUser>> {
         self.active.get()
     }
@@ -198,6 +252,28 @@ impl CursorUserGroup {
         }
     }
 
+    pub fn set_cursor_theme(&self, theme: Option<Rc<String>>) {
+        if self.theme.get() == theme {
+            return;
+        }
+        if let Some(theme) = &theme {
+            self.state.add_cursor_theme(theme);
+        }
+        if let Some(old) = self.theme.set(theme) {
+            self.state.remove_cursor_theme(&old);
+        }
+        self.reload_known_cursor();
+    }
+
+    fn cursors(&self) -> Option<Rc<ServerCursors>> {
+        if let Some(theme) = self.theme.get()
+            && let Some(cursors) = self.state.themed_cursors.get(&theme)
+        {
+            return Some(cursors);
+        }
+        self.state.cursors.get()
+    }
+
     fn output_center(&self, output: &Rc<OutputNode>) -> (Fixed, Fixed) {
         let pos = output.global.pos.get();
         let x = Fixed::from_int((pos.x1() + pos.x2()) / 2);
@@ -237,6 +313,14 @@ impl CursorUserGroup {
         }
     }
 
+    pub fn output_cursor_size_changed(&self, output: &Rc<OutputNode>) {
+        for user in self.users.lock().values() {
+            if user.output.get().id == output.id {
+                user.reload_known_cursor();
+            }
+        }
+    }
+
     pub fn present_hardware_cursor(
         &self,
         output: &Rc<OutputNode>,
@@ -246,6 +330,10 @@ impl CursorUserGroup {
             hc.set_enabled(false);
             return;
         };
+        if self.hidden.get() {
+            hc.set_enabled(false);
+            return;
+        }
         active.present_hardware_cursor(output, hc);
     }
 }
@@ -273,6 +361,8 @@ impl CursorUser {
         }
         self.group.latest_output.set(self.output.get());
         self.group.active.set(Some(self.clone()));
+        // A different cursor is shown now, so let its animation run.
+        self.group.last_motion.set(self.group.state.now());
         self.update_hardware_cursor();
         if self.software_cursor() {
             self.group.damage_active();
@@ -286,7 +376,7 @@ impl CursorUser {
 
     pub fn set_known(&self, cursor: KnownCursor) {
         self.desired_known_cursor.set(Some(cursor));
-        let cursors = match self.group.state.cursors.get() {
+        let cursors = match self.group.cursors() {
             Some(c) => c,
             None => {
                 self.set_cursor2(None);
@@ -331,12 +421,21 @@ impl CursorUser {
             KnownCursor::DndAsk => &cursors.dnd_ask,
             KnownCursor::AllResize => &cursors.all_resize,
         };
-        self.set_cursor2(Some(
-            tpl.instantiate(&self.group.state, self.group.size.get()),
-        ));
+        self.set_cursor2(Some(tpl.instantiate(&self.group.state, self.cursor_size())));
+    }
+
+    fn cursor_size(&self) -> u32 {
+        self.output
+            .get()
+            .global
+            .persistent
+            .cursor_size
+            .get()
+            .unwrap_or(self.group.size.get())
     }
 
     fn set_output(&self, output: &Rc<OutputNode>) {
+        let old_size = self.cursor_size();
         self.output.set(output.clone());
         self.output_pos.set(output.global.pos.get());
         if self.is_active() {
@@ -348,6 +447,9 @@ impl CursorUser {
         if let Some(owner) = self.owner.get() {
             owner.output_changed(output);
         }
+        if self.cursor_size() != old_size {
+            self.reload_known_cursor();
+        }
     }
 
     pub fn output(&self) -> Rc<OutputNode> {
@@ -376,7 +478,7 @@ impl CursorUser {
                 return;
             }
             old.handle_unset();
-            if self.software_cursor() {
+            if self.composited_cursor() {
                 self.group.damage_active();
             }
         }
@@ -385,8 +487,11 @@ impl CursorUser {
             cursor.set_output(&self.output.get());
         }
         self.cursor.set(cursor.clone());
+        if self.is_active() {
+            self.group.last_motion.set(self.group.state.now());
+        }
         self.update_hardware_cursor();
-        if self.software_cursor() {
+        if self.composited_cursor() {
             self.group.damage_active();
         }
     }
@@ -409,7 +514,7 @@ impl CursorUser {
             x = x.apply_fract(x_tmp);
             y = y.apply_fract(y_tmp);
         }
-        if self.software_cursor()
+        if self.composited_cursor()
             && let Some(cursor) = self.cursor.get()
         {
             let (old_x, old_y) = self.pos.get();
@@ -422,6 +527,7 @@ impl CursorUser {
             self.group.state.damage2(true, extents.move_(x_int, y_int));
         }
         self.pos.set((x, y));
+        self.group.last_motion.set(self.group.state.now());
         self.update_hardware_cursor_(false);
         (x, y)
     }
@@ -464,12 +570,17 @@ impl CursorUser {
             hc.set_enabled(false);
             return;
         };
+        if output.global.zoomed() {
+            // The cursor has to be zoomed together with the rest of the output.
+            hc.set_enabled(false);
+            return;
+        }
         let (x, y) = self.pos.get();
         let transform = output.global.persistent.transform.get();
         let render = output.hardware_cursor_needs_render.take();
         let scale = output.global.persistent.scale.get();
         let cd = output.global.color_description.get();
-        if render {
+        if render && !self.group.animation_frozen() {
             cursor.tick();
         }
         let extents = cursor.extents_at_scale(scale);
@@ -477,6 +588,7 @@ impl CursorUser {
         if render {
             let (max_width, max_height) = transform.maybe_swap((hc_width, hc_height));
             if extents.width() > max_width || extents.height() > max_height {
+                self.set_hardware_cursor_fallback(output, true);
                 hc.set_enabled(false);
                 return;
             }
@@ -514,9 +626,13 @@ impl CursorUser {
                 Ok(sync_file) => {
                     hc.set_sync_file(sync_file);
                     hc.swap_buffer();
+                    self.set_hardware_cursor_fallback(output, false);
                 }
                 Err(e) => {
                     log::error!("Could not render hardware cursor: {}", ErrorFmt(e));
+                    self.set_hardware_cursor_fallback(output, true);
+                    hc.set_enabled(false);
+                    return;
                 }
             }
         }
@@ -528,6 +644,35 @@ impl CursorUser {
         hc.set_position(x_rel - hot_x, y_rel - hot_y);
     }
 
+    fn set_hardware_cursor_fallback(&self, output: &OutputNode, fallback: bool) {
+        if output.hardware_cursor_fallback.replace(fallback) == fallback {
+            return;
+        }
+        if fallback && !output.hardware_cursor_fallback_logged.replace(true) {
+            log::warn!(
+                "Cannot use the hardware cursor on output {}. Rendering the cursor in software.",
+                output.global.connector.name,
+            );
+        }
+        self.group.damage_active();
+    }
+
+    /// Returns whether the cursor is part of the rendered scene on some output.
+    fn composited_cursor(&self) -> bool {
+        if self.software_cursor() {
+            return true;
+        }
+        self.hardware_cursor()
+            && self
+                .group
+                .state
+                .root
+                .outputs
+                .lock()
+                .values()
+                .any(|o| o.hardware_cursor_fallback.get() || o.global.zoomed())
+    }
+
     fn reload_known_cursor(&self) {
         if let Some(kc) = self.desired_known_cursor.get() {
             self.set_known(kc);
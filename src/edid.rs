@@ -1229,7 +1229,6 @@ pub enum CtaDataBlock {
 #[derive(Debug)]
 pub struct CtaAmdVendorDataBlock {
     pub minimum_refresh_hz: u8,
-    #[expect(dead_code)]
     pub maximum_refresh_hz: u8,
 }
 
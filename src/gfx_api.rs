@@ -12,7 +12,7 @@ use {
         scale::Scale,
         state::State,
         theme::Color,
-        tree::{Node, OutputNode},
+        tree::{Node, OutputNode, OutputZoom},
         utils::{clonecell::UnsafeCellCloneSafe, transform_ext::TransformExt},
         video::{Modifier, dmabuf::DmaBuf, drm::sync_obj::SyncObjCtx},
     },
@@ -33,12 +33,46 @@ use {
     uapi::OwnedFd,
 };
 
+#[derive(Clone)]
 pub enum GfxApiOpt {
     Sync,
     FillRect(FillRect),
     CopyTexture(CopyTexture),
 }
 
+impl GfxApiOpt {
+    pub fn multiply_alpha(&mut self, alpha: f32) {
+        match self {
+            GfxApiOpt::Sync => {}
+            GfxApiOpt::FillRect(fr) => fr.alpha = Some(fr.alpha.unwrap_or(1.0) * alpha),
+            GfxApiOpt::CopyTexture(ct) => {
+                ct.alpha = Some(ct.alpha.unwrap_or(1.0) * alpha);
+                ct.opaque = false;
+            }
+        }
+    }
+}
+
+/// Scales the targets of `ops` by `factor` such that the framebuffer point `from` is
+/// moved to `to`.
+pub fn scale_ops(ops: &mut [GfxApiOpt], factor: f32, from: [f32; 2], to: [f32; 2]) {
+    let [fx, fy] = from;
+    let [tx, ty] = to;
+    let scale = |rect: &mut FramebufferRect| {
+        rect.x1 = (rect.x1 - fx) * factor + tx;
+        rect.x2 = (rect.x2 - fx) * factor + tx;
+        rect.y1 = (rect.y1 - fy) * factor + ty;
+        rect.y2 = (rect.y2 - fy) * factor + ty;
+    };
+    for op in ops {
+        match op {
+            GfxApiOpt::Sync => {}
+            GfxApiOpt::FillRect(fr) => scale(&mut fr.rect),
+            GfxApiOpt::CopyTexture(ct) => scale(&mut ct.target),
+        }
+    }
+}
+
 pub struct GfxRenderPass {
     pub ops: Vec<GfxApiOpt>,
     pub clear: Option<Color>,
@@ -191,7 +225,7 @@ impl FramebufferRect {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct FillRect {
     pub rect: FramebufferRect,
     pub color: Color,
@@ -209,6 +243,7 @@ impl FillRect {
     }
 }
 
+#[derive(Clone)]
 pub struct CopyTexture {
     pub tex: Rc<dyn GfxTexture>,
     pub source: SampleRect,
@@ -487,6 +522,7 @@ impl dyn GfxFramebuffer {
         black_background: bool,
         fill_black_in_grace_period: bool,
         transform: Transform,
+        zoom: Option<OutputZoom>,
         visualizer: Option<&DamageVisualizer>,
     ) -> GfxRenderPass {
         create_render_pass(
@@ -500,6 +536,7 @@ impl dyn GfxFramebuffer {
             black_background,
             fill_black_in_grace_period,
             transform,
+            zoom,
             visualizer,
         )
     }
@@ -541,10 +578,7 @@ impl dyn GfxFramebuffer {
         blend_buffer: Option<&Rc<dyn GfxBlendBuffer>>,
         blend_cd: &Rc<ColorDescription>,
     ) -> Result<Option<SyncFile>, GfxError> {
-        self.render_node(
-            acquire_sync,
-            release_sync,
-            cd,
+        let pass = self.create_render_pass(
             node,
             state,
             cursor_rect,
@@ -554,6 +588,15 @@ impl dyn GfxFramebuffer {
             node.has_fullscreen(),
             fill_black_in_grace_period,
             node.global.persistent.transform.get(),
+            node.zoom(),
+            None,
+        );
+        self.perform_render_pass(
+            acquire_sync,
+            release_sync,
+            cd,
+            &pass,
+            &self.full_region(),
             blend_buffer,
             blend_cd,
         )
@@ -587,6 +630,7 @@ impl dyn GfxFramebuffer {
             fill_black_in_grace_period,
             transform,
             None,
+            None,
         );
         self.perform_render_pass(
             acquire_sync,
@@ -619,6 +663,7 @@ impl dyn GfxFramebuffer {
                 Rect::new(0, 0, width, height).unwrap()
             },
             icons: None,
+            toplevel_alpha: None,
         };
         cursor.render_hardware_cursor(&mut renderer);
         self.render(
@@ -889,9 +934,11 @@ pub fn create_render_pass(
     black_background: bool,
     fill_black_in_grace_period: bool,
     transform: Transform,
+    zoom: Option<OutputZoom>,
     visualizer: Option<&DamageVisualizer>,
 ) -> GfxRenderPass {
-    if fill_black_in_grace_period && state.idle.in_grace_period.get() {
+    let in_grace_period = fill_black_in_grace_period && state.idle.in_grace_period.get();
+    if in_grace_period && state.idle.dim.get() >= 1.0 {
         return GfxRenderPass {
             ops: vec![],
             clear: Some(Color::SOLID_BLACK),
@@ -908,6 +955,7 @@ pub fn create_render_pass(
             Rect::new(0, 0, width, height).unwrap()
         },
         icons: state.icons.get(state, scale),
+        toplevel_alpha: None,
     };
     node.node_render(&mut renderer, 0, 0, None);
     if let Some(rect) = cursor_rect {
@@ -938,10 +986,13 @@ pub fn create_render_pass(
             if render_cursor {
                 let cursor_user_group = seat.cursor_group();
                 if (render_hardware_cursor || !cursor_user_group.hardware_cursor())
+                    && !cursor_user_group.hidden()
                     && let Some(cursor_user) = cursor_user_group.active()
                     && let Some(cursor) = cursor_user.get()
                 {
-                    cursor.tick();
+                    if !cursor_user_group.animation_frozen() {
+                        cursor.tick();
+                    }
                     let (mut x, mut y) = cursor_user.position();
                     x -= Fixed::from_int(rect.x1());
                     y -= Fixed::from_int(rect.y1());
@@ -950,6 +1001,22 @@ pub fn create_render_pass(
             }
         }
     }
+    if let Some(zoom) = zoom {
+        let base = &mut renderer.base;
+        zoom.apply(base.ops, base.scalef, base.fb_width, base.fb_height);
+    }
+    if in_grace_period {
+        let srgb_srgb = state.color_manager.srgb_srgb();
+        renderer.base.fill_boxes3(
+            &[renderer.pixel_extents],
+            &Color::SOLID_BLACK,
+            Some(state.idle.dim.get()),
+            &srgb_srgb.linear,
+            0,
+            0,
+            true,
+        );
+    }
     if let Some(visualizer) = visualizer
         && let Some(cursor_rect) = cursor_rect
     {
@@ -13,6 +13,7 @@ use {
 };
 
 pub mod gl;
+mod software;
 mod vulkan;
 
 pub fn create_gfx_context(
@@ -22,7 +23,7 @@ pub fn create_gfx_context(
     api: GfxApi,
     caps_thread: Option<&PrCapsThread>,
 ) -> Result<Rc<dyn GfxContext>, GfxError> {
-    let mut apis = [GfxApi::OpenGl, GfxApi::Vulkan];
+    let mut apis = [GfxApi::OpenGl, GfxApi::Vulkan, GfxApi::Software];
     apis.sort_by_key(|&a| if a == api { -1 } else { a as i32 });
     let mut last_err = None;
     for api in apis {
@@ -48,6 +49,7 @@ fn create_gfx_context_(
     match api {
         GfxApi::OpenGl => gl::create_gfx_context(drm),
         GfxApi::Vulkan => vulkan::create_graphics_context(eng, ring, drm, caps_thread),
+        GfxApi::Software => software::create_gfx_context(drm),
         _ => unreachable!(),
     }
 }
@@ -0,0 +1,767 @@
+#[cfg(test)]
+mod tests;
+
+use {
+    crate::{
+        allocator::{Allocator, AllocatorError, BufferObject, BufferUsage},
+        cmm::cmm_description::{ColorDescription, LinearColorDescription},
+        cpu_worker::CpuWorker,
+        format::{ARGB8888, Format, XRGB8888},
+        gfx_api::{
+            AcquireSync, AsyncShmGfxTexture, AsyncShmGfxTextureCallback, CopyTexture, FillRect,
+            GfxApiOpt, GfxBlendBuffer, GfxContext, GfxError, GfxFormat, GfxFramebuffer, GfxImage,
+            GfxInternalFramebuffer, GfxStagingBuffer, GfxTexture, GfxWriteModifier,
+            PendingShmTransfer, ReleaseSync, ResetStatus, ShmGfxTexture, ShmMemory, SyncFile,
+        },
+        rect::{Rect, Region},
+        theme::Color,
+        utils::{errorfmt::ErrorFmt, oserror::OsError},
+        video::{
+            LINEAR_MODIFIER,
+            dmabuf::{DMA_BUF_SYNC_READ, DMA_BUF_SYNC_WRITE, DmaBuf, dma_buf_export_sync_file},
+            drm::{Drm, DrmError, sync_obj::SyncObjCtx},
+            gbm::{GbmDevice, GbmError},
+        },
+    },
+    ahash::AHashMap,
+    indexmap::IndexSet,
+    jay_config::video::GfxApi,
+    std::{
+        any::Any,
+        cell::{Cell, RefCell},
+        error::Error,
+        ffi::CString,
+        fmt::{Debug, Formatter},
+        ptr,
+        rc::Rc,
+    },
+    thiserror::Error,
+    uapi::{OwnedFd, c},
+};
+
+#[derive(Debug, Error)]
+enum SoftwareError {
+    #[error("Could not create a GBM device")]
+    CreateGbmDevice(#[source] GbmError),
+    #[error("Could not retrieve the render node")]
+    RenderNode(#[source] DrmError),
+    #[error("Could not import dmabuf")]
+    ImportDmaBuf(#[source] AllocatorError),
+    #[error("Could not map dmabuf")]
+    MapDmaBuf(#[source] AllocatorError),
+    #[error("Only linear buffers are supported")]
+    NotLinear,
+    #[error("Format {0} is not supported")]
+    UnsupportedFormat(&'static str),
+    #[error("The stride is too small for the width of the buffer")]
+    SmallStride,
+    #[error("The buffer size is out of bounds")]
+    InvalidSize,
+    #[error("The image buffer is too small")]
+    SmallImageBuffer,
+    #[error("Could not access the client memory")]
+    AccessFailed(#[source] Box<dyn Error + Sync + Send>),
+    #[error("The software renderer does not support blend buffers")]
+    NoBlendBuffer,
+    #[error("Non-software texture passed into the software renderer")]
+    ForeignTexture,
+    #[error("The framebuffer is still in use")]
+    FramebufferBusy,
+}
+
+impl From<SoftwareError> for GfxError {
+    fn from(value: SoftwareError) -> Self {
+        Self(Box::new(value))
+    }
+}
+
+pub(super) fn create_gfx_context(drm: &Drm) -> Result<Rc<dyn GfxContext>, GfxError> {
+    SoftwareContext::new(drm)
+        .map(|v| Rc::new(v) as Rc<dyn GfxContext>)
+        .map_err(|e| e.into())
+}
+
+/// A graphics context that renders on the CPU.
+///
+/// This context only supports linear XRGB8888 and ARGB8888 buffers and ignores color
+/// management. It exists so that the compositor remains usable on systems where
+/// neither OpenGL nor Vulkan is available.
+struct SoftwareContext {
+    gbm: Rc<GbmDevice>,
+    sync_ctx: Rc<SyncObjCtx>,
+    render_node: Option<Rc<CString>>,
+    formats: Rc<AHashMap<u32, GfxFormat>>,
+}
+
+impl Debug for SoftwareContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SoftwareContext").finish_non_exhaustive()
+    }
+}
+
+impl SoftwareContext {
+    fn new(drm: &Drm) -> Result<Self, SoftwareError> {
+        let render_node = drm
+            .get_render_node()
+            .map_err(SoftwareError::RenderNode)?
+            .map(Rc::new);
+        let gbm = GbmDevice::new(drm).map_err(SoftwareError::CreateGbmDevice)?;
+        let mut modifiers = IndexSet::new();
+        modifiers.insert(LINEAR_MODIFIER);
+        let mut formats = AHashMap::new();
+        for format in [XRGB8888, ARGB8888] {
+            formats.insert(
+                format.drm,
+                GfxFormat {
+                    format,
+                    read_modifiers: modifiers.clone(),
+                    write_modifiers: modifiers
+                        .iter()
+                        .map(|&m| {
+                            (
+                                m,
+                                GfxWriteModifier {
+                                    needs_render_usage: false,
+                                },
+                            )
+                        })
+                        .collect(),
+                },
+            );
+        }
+        Ok(Self {
+            sync_ctx: Rc::new(SyncObjCtx::new(gbm.drm.fd())),
+            gbm: Rc::new(gbm),
+            render_node,
+            formats: Rc::new(formats),
+        })
+    }
+
+    fn check_format(&self, format: &'static Format) -> Result<(), SoftwareError> {
+        match self.formats.contains_key(&format.drm) {
+            true => Ok(()),
+            false => Err(SoftwareError::UnsupportedFormat(format.name)),
+        }
+    }
+
+    fn shm_image(
+        &self,
+        format: &'static Format,
+        width: i32,
+        height: i32,
+        stride: i32,
+    ) -> Result<Rc<SoftwareImage>, SoftwareError> {
+        self.check_format(format)?;
+        let size = image_size(width, height, stride)?;
+        Ok(Rc::new(SoftwareImage::Shm(ShmImage {
+            data: RefCell::new(vec![0; size]),
+            width,
+            height,
+            stride,
+            format,
+        })))
+    }
+}
+
+impl GfxContext for SoftwareContext {
+    fn reset_status(&self) -> Option<ResetStatus> {
+        None
+    }
+
+    fn render_node(&self) -> Option<Rc<CString>> {
+        self.render_node.clone()
+    }
+
+    fn formats(&self) -> Rc<AHashMap<u32, GfxFormat>> {
+        self.formats.clone()
+    }
+
+    fn dmabuf_img(self: Rc<Self>, buf: &DmaBuf) -> Result<Rc<dyn GfxImage>, GfxError> {
+        self.check_format(buf.format)?;
+        if buf.modifier != LINEAR_MODIFIER || buf.planes.len() != 1 {
+            return Err(SoftwareError::NotLinear.into());
+        }
+        let bo = self
+            .gbm
+            .import_dmabuf(buf, BufferUsage::none())
+            .map_err(SoftwareError::ImportDmaBuf)?;
+        Ok(Rc::new(SoftwareImage::DmaBuf(DmaBufImage {
+            buf: buf.clone(),
+            bo,
+        })))
+    }
+
+    fn shmem_texture(
+        self: Rc<Self>,
+        _old: Option<Rc<dyn ShmGfxTexture>>,
+        data: &[Cell<u8>],
+        format: &'static Format,
+        width: i32,
+        height: i32,
+        stride: i32,
+        _damage: Option<&[Rect]>,
+    ) -> Result<Rc<dyn ShmGfxTexture>, GfxError> {
+        let img = self.shm_image(format, width, height, stride)?;
+        img.clone().sync_upload(data, Region::default())?;
+        Ok(img)
+    }
+
+    fn async_shmem_texture(
+        self: Rc<Self>,
+        format: &'static Format,
+        width: i32,
+        height: i32,
+        stride: i32,
+        _cpu_worker: &Rc<CpuWorker>,
+    ) -> Result<Rc<dyn AsyncShmGfxTexture>, GfxError> {
+        Ok(self.shm_image(format, width, height, stride)?)
+    }
+
+    fn allocator(&self) -> Rc<dyn Allocator> {
+        self.gbm.clone()
+    }
+
+    fn gfx_api(&self) -> GfxApi {
+        GfxApi::Software
+    }
+
+    fn create_internal_fb(
+        self: Rc<Self>,
+        _cpu_worker: &Rc<CpuWorker>,
+        width: i32,
+        height: i32,
+        stride: i32,
+        format: &'static Format,
+    ) -> Result<Rc<dyn GfxInternalFramebuffer>, GfxError> {
+        Ok(Rc::new(SoftwareFramebuffer {
+            img: self.shm_image(format, width, height, stride)?,
+        }))
+    }
+
+    fn sync_obj_ctx(&self) -> Option<&Rc<SyncObjCtx>> {
+        Some(&self.sync_ctx)
+    }
+
+    fn acquire_blend_buffer(
+        &self,
+        _width: i32,
+        _height: i32,
+    ) -> Result<Rc<dyn GfxBlendBuffer>, GfxError> {
+        Err(SoftwareError::NoBlendBuffer.into())
+    }
+}
+
+enum SoftwareImage {
+    Shm(ShmImage),
+    DmaBuf(DmaBufImage),
+}
+
+struct ShmImage {
+    data: RefCell<Vec<u8>>,
+    width: i32,
+    height: i32,
+    stride: i32,
+    format: &'static Format,
+}
+
+struct DmaBufImage {
+    buf: DmaBuf,
+    bo: Rc<dyn BufferObject>,
+}
+
+/// A view of the pixels of an image.
+///
+/// Pixels are stored as little-endian 32-bit values with premultiplied alpha, i.e. the
+/// bytes are in BGRA order.
+struct Pixels {
+    data: *mut u8,
+    width: i32,
+    height: i32,
+    stride: i32,
+    has_alpha: bool,
+}
+
+impl Pixels {
+    unsafe fn get(&self, x: i32, y: i32) -> [u8; 4] {
+        let mut px = unsafe {
+            *self
+                .data
+                .add((y * self.stride + x * 4) as usize)
+                .cast::<[u8; 4]>()
+        };
+        if !self.has_alpha {
+            px[3] = 255;
+        }
+        px
+    }
+
+    unsafe fn blend(&self, x: i32, y: i32, src: [u8; 4]) {
+        let dst = unsafe {
+            &mut *self
+                .data
+                .add((y * self.stride + x * 4) as usize)
+                .cast::<[u8; 4]>()
+        };
+        let inv = 255 - src[3] as u32;
+        if inv == 0 {
+            *dst = src;
+            return;
+        }
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = (s as u32 + (*d as u32 * inv + 127) / 255).min(255) as u8;
+        }
+    }
+
+    unsafe fn fill(&self, rect: Rect, color: [u8; 4]) {
+        for y in rect.y1()..rect.y2() {
+            for x in rect.x1()..rect.x2() {
+                unsafe {
+                    *self
+                        .data
+                        .add((y * self.stride + x * 4) as usize)
+                        .cast::<[u8; 4]>() = color;
+                }
+            }
+        }
+    }
+
+    fn bounds(&self) -> Rect {
+        Rect::new_sized_unchecked(0, 0, self.width, self.height)
+    }
+}
+
+impl SoftwareImage {
+    fn format(&self) -> &'static Format {
+        match self {
+            SoftwareImage::Shm(s) => s.format,
+            SoftwareImage::DmaBuf(d) => d.buf.format,
+        }
+    }
+
+    fn dmabuf(&self) -> Option<&DmaBuf> {
+        match self {
+            SoftwareImage::Shm(_) => None,
+            SoftwareImage::DmaBuf(d) => Some(&d.buf),
+        }
+    }
+
+    fn with_pixels<T>(
+        &self,
+        write: bool,
+        f: impl FnOnce(&Pixels) -> T,
+    ) -> Result<T, SoftwareError> {
+        let has_alpha = self.format().has_alpha;
+        match self {
+            SoftwareImage::Shm(s) => {
+                let data = &mut *s.data.borrow_mut();
+                Ok(f(&Pixels {
+                    data: data.as_mut_ptr(),
+                    width: s.width,
+                    height: s.height,
+                    stride: s.stride,
+                    has_alpha,
+                }))
+            }
+            SoftwareImage::DmaBuf(d) => {
+                let map = match write {
+                    true => d.bo.clone().map_write(),
+                    false => d.bo.clone().map_read(),
+                };
+                let map = map.map_err(SoftwareError::MapDmaBuf)?;
+                image_size(d.buf.width, d.buf.height, map.stride())?;
+                Ok(f(&Pixels {
+                    data: map.data_ptr(),
+                    width: d.buf.width,
+                    height: d.buf.height,
+                    stride: map.stride(),
+                    has_alpha,
+                }))
+            }
+        }
+    }
+
+    fn read_pixels(&self, shm: &[Cell<u8>]) -> Result<(), SoftwareError> {
+        self.with_pixels(false, |px| {
+            let size = image_size(px.width, px.height, px.stride)?;
+            if shm.len() < size {
+                return Err(SoftwareError::SmallImageBuffer);
+            }
+            unsafe {
+                ptr::copy_nonoverlapping(px.data, shm.as_ptr() as *mut u8, size);
+            }
+            Ok(())
+        })?
+    }
+}
+
+impl Debug for SoftwareImage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SoftwareImage").finish_non_exhaustive()
+    }
+}
+
+impl GfxTexture for SoftwareImage {
+    fn size(&self) -> (i32, i32) {
+        (self.width(), self.height())
+    }
+
+    fn dmabuf(&self) -> Option<&DmaBuf> {
+        self.dmabuf()
+    }
+
+    fn format(&self) -> &'static Format {
+        self.format()
+    }
+}
+
+impl ShmGfxTexture for SoftwareImage {}
+
+impl AsyncShmGfxTexture for SoftwareImage {
+    fn async_upload(
+        self: Rc<Self>,
+        _staging: &Rc<dyn GfxStagingBuffer>,
+        _callback: Rc<dyn AsyncShmGfxTextureCallback>,
+        mem: Rc<dyn ShmMemory>,
+        _damage: Region,
+    ) -> Result<Option<PendingShmTransfer>, GfxError> {
+        let mut res = Ok(());
+        mem.access(&mut |data| {
+            res = self.clone().sync_upload(data, Region::default());
+        })
+        .map_err(SoftwareError::AccessFailed)?;
+        res.map(|_| None)
+    }
+
+    fn sync_upload(self: Rc<Self>, mem: &[Cell<u8>], _damage: Region) -> Result<(), GfxError> {
+        let SoftwareImage::Shm(shm) = &*self else {
+            unreachable!();
+        };
+        let data = &mut *shm.data.borrow_mut();
+        if mem.len() < data.len() {
+            return Err(SoftwareError::SmallImageBuffer.into());
+        }
+        unsafe {
+            ptr::copy_nonoverlapping(mem.as_ptr() as *const u8, data.as_mut_ptr(), data.len());
+        }
+        Ok(())
+    }
+
+    fn compatible_with(
+        &self,
+        format: &'static Format,
+        width: i32,
+        height: i32,
+        stride: i32,
+    ) -> bool {
+        let SoftwareImage::Shm(shm) = self else {
+            return false;
+        };
+        shm.format == format && shm.width == width && shm.height == height && shm.stride == stride
+    }
+}
+
+impl GfxImage for SoftwareImage {
+    fn to_framebuffer(self: Rc<Self>) -> Result<Rc<dyn GfxFramebuffer>, GfxError> {
+        Ok(Rc::new(SoftwareFramebuffer { img: self }))
+    }
+
+    fn to_texture(self: Rc<Self>) -> Result<Rc<dyn GfxTexture>, GfxError> {
+        Ok(self)
+    }
+
+    fn width(&self) -> i32 {
+        match self {
+            SoftwareImage::Shm(v) => v.width,
+            SoftwareImage::DmaBuf(v) => v.buf.width,
+        }
+    }
+
+    fn height(&self) -> i32 {
+        match self {
+            SoftwareImage::Shm(v) => v.height,
+            SoftwareImage::DmaBuf(v) => v.buf.height,
+        }
+    }
+}
+
+struct SoftwareFramebuffer {
+    img: Rc<SoftwareImage>,
+}
+
+impl Debug for SoftwareFramebuffer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SoftwareFramebuffer")
+            .finish_non_exhaustive()
+    }
+}
+
+impl SoftwareFramebuffer {
+    fn render(
+        &self,
+        acquire_sync: &AcquireSync,
+        ops: &[GfxApiOpt],
+        clear: Option<&Color>,
+        region: &Region,
+    ) -> Result<(), SoftwareError> {
+        if !sync_signaled(acquire_sync, self.img.dmabuf(), DMA_BUF_SYNC_WRITE) {
+            return Err(SoftwareError::FramebufferBusy);
+        }
+        self.img.with_pixels(true, |dst| {
+            let clip: Vec<_> = region
+                .rects()
+                .iter()
+                .map(|r| r.intersect(dst.bounds()))
+                .filter(|r| !r.is_empty())
+                .collect();
+            if let Some(clear) = clear {
+                let color = to_bgra(*clear);
+                for rect in &clip {
+                    unsafe {
+                        dst.fill(*rect, color);
+                    }
+                }
+            }
+            for op in ops {
+                match op {
+                    GfxApiOpt::Sync => {}
+                    GfxApiOpt::FillRect(f) => fill_rect(dst, &clip, f),
+                    GfxApiOpt::CopyTexture(c) => {
+                        if let Err(e) = copy_texture(dst, &clip, c) {
+                            log::warn!("Skipping a texture: {}", ErrorFmt(e));
+                        }
+                    }
+                }
+            }
+        })
+    }
+}
+
+impl GfxFramebuffer for SoftwareFramebuffer {
+    fn physical_size(&self) -> (i32, i32) {
+        (self.img.width(), self.img.height())
+    }
+
+    fn render_with_region(
+        self: Rc<Self>,
+        acquire_sync: AcquireSync,
+        _release_sync: ReleaseSync,
+        _cd: &Rc<ColorDescription>,
+        ops: &[GfxApiOpt],
+        clear: Option<&Color>,
+        _clear_cd: &Rc<LinearColorDescription>,
+        region: &Region,
+        _blend_buffer: Option<&Rc<dyn GfxBlendBuffer>>,
+        _blend_cd: &Rc<ColorDescription>,
+    ) -> Result<Option<SyncFile>, GfxError> {
+        self.render(&acquire_sync, ops, clear, region)?;
+        Ok(None)
+    }
+
+    fn format(&self) -> &'static Format {
+        self.img.format()
+    }
+}
+
+impl GfxInternalFramebuffer for SoftwareFramebuffer {
+    fn stride(&self) -> i32 {
+        let SoftwareImage::Shm(shm) = &*self.img else {
+            unreachable!();
+        };
+        shm.stride
+    }
+
+    fn staging_size(&self) -> usize {
+        0
+    }
+
+    fn download(
+        self: Rc<Self>,
+        _staging: &Rc<dyn GfxStagingBuffer>,
+        _callback: Rc<dyn AsyncShmGfxTextureCallback>,
+        mem: Rc<dyn ShmMemory>,
+        _damage: Region,
+    ) -> Result<Option<PendingShmTransfer>, GfxError> {
+        let mut res = Ok(());
+        mem.access(&mut |mem| res = self.img.read_pixels(mem))
+            .map_err(SoftwareError::AccessFailed)?;
+        res?;
+        Ok(None)
+    }
+}
+
+fn to_bgra(color: Color) -> [u8; 4] {
+    let [r, g, b, a] = color.to_srgba_premultiplied();
+    [b, g, r, a]
+}
+
+/// Returns the pixel rectangle covered by the four corners in normalized device
+/// coordinates.
+fn pixel_bounds(points: &[[f32; 2]; 4], width: i32, height: i32) -> Rect {
+    let mut x1 = f32::INFINITY;
+    let mut y1 = f32::INFINITY;
+    let mut x2 = f32::NEG_INFINITY;
+    let mut y2 = f32::NEG_INFINITY;
+    for [x, y] in points {
+        x1 = x1.min(*x);
+        y1 = y1.min(*y);
+        x2 = x2.max(*x);
+        y2 = y2.max(*y);
+    }
+    let to_px = |v: f32, size: i32| ((v + 1.0) / 2.0 * size as f32).round() as i32;
+    Rect::new(
+        to_px(x1, width),
+        to_px(y1, height),
+        to_px(x2, width),
+        to_px(y2, height),
+    )
+    .unwrap_or_default()
+}
+
+fn fill_rect(dst: &Pixels, clip: &[Rect], f: &FillRect) {
+    let color = to_bgra(f.effective_color());
+    let bounds = pixel_bounds(&f.rect.to_points(), dst.width, dst.height);
+    for rect in clip {
+        let rect = rect.intersect(bounds);
+        if color[3] == 255 {
+            unsafe {
+                dst.fill(rect, color);
+            }
+            continue;
+        }
+        for y in rect.y1()..rect.y2() {
+            for x in rect.x1()..rect.x2() {
+                unsafe {
+                    dst.blend(x, y, color);
+                }
+            }
+        }
+    }
+}
+
+fn copy_texture(dst: &Pixels, clip: &[Rect], c: &CopyTexture) -> Result<(), SoftwareError> {
+    let Some(tex) = (&*c.tex as &dyn Any).downcast_ref::<SoftwareImage>() else {
+        return Err(SoftwareError::ForeignTexture);
+    };
+    if !sync_signaled(&c.acquire_sync, tex.dmabuf(), DMA_BUF_SYNC_READ) {
+        log::debug!("Skipping a texture whose fence has not yet been signaled");
+        return Ok(());
+    }
+    let pos = c.target.to_points();
+    let bounds = pixel_bounds(&pos, dst.width, dst.height);
+    if bounds.is_empty() {
+        return Ok(());
+    }
+    let tex_pos = c.source.to_points();
+    // The corners are top-right, top-left, bottom-right, and bottom-left. Both
+    // mappings are affine, so the texture coordinates of a pixel follow from its
+    // position relative to the top-left corner along the two edges of the quad.
+    let [o_x, o_y] = pos[1];
+    let (u_x, u_y) = (pos[0][0] - o_x, pos[0][1] - o_y);
+    let (v_x, v_y) = (pos[3][0] - o_x, pos[3][1] - o_y);
+    let det = u_x * v_y - u_y * v_x;
+    if det == 0.0 {
+        return Ok(());
+    }
+    let [t_o_x, t_o_y] = tex_pos[1];
+    let (t_u_x, t_u_y) = (tex_pos[0][0] - t_o_x, tex_pos[0][1] - t_o_y);
+    let (t_v_x, t_v_y) = (tex_pos[3][0] - t_o_x, tex_pos[3][1] - t_o_y);
+    let alpha = c.alpha.filter(|a| *a < 1.0);
+    let opaque = c.opaque && alpha.is_none();
+    tex.with_pixels(false, |src| {
+        if src.width == 0 || src.height == 0 {
+            return;
+        }
+        let scale_x = 2.0 / dst.width as f32;
+        let scale_y = 2.0 / dst.height as f32;
+        for rect in clip {
+            let rect = rect.intersect(bounds);
+            for y in rect.y1()..rect.y2() {
+                let n_y = (y as f32 + 0.5) * scale_y - 1.0 - o_y;
+                for x in rect.x1()..rect.x2() {
+                    let n_x = (x as f32 + 0.5) * scale_x - 1.0 - o_x;
+                    let a = (n_x * v_y - n_y * v_x) / det;
+                    let b = (u_x * n_y - u_y * n_x) / det;
+                    let t_x = t_o_x + a * t_u_x + b * t_v_x;
+                    let t_y = t_o_y + a * t_u_y + b * t_v_y;
+                    let s_x = ((t_x * src.width as f32) as i32).clamp(0, src.width - 1);
+                    let s_y = ((t_y * src.height as f32) as i32).clamp(0, src.height - 1);
+                    let mut px = unsafe { src.get(s_x, s_y) };
+                    if opaque {
+                        px[3] = 255;
+                    }
+                    if let Some(alpha) = alpha {
+                        for v in &mut px {
+                            *v = (*v as f32 * alpha).round() as u8;
+                        }
+                    }
+                    unsafe {
+                        dst.blend(x, y, px);
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Returns the size in bytes of an image with the given dimensions.
+///
+/// The size is guaranteed to fit into an `i32` so that pixel offsets can be computed
+/// without overflow.
+fn image_size(width: i32, height: i32, stride: i32) -> Result<usize, SoftwareError> {
+    if width < 0 || height < 0 {
+        return Err(SoftwareError::InvalidSize);
+    }
+    let min_stride = width.checked_mul(4).ok_or(SoftwareError::InvalidSize)?;
+    if stride < min_stride {
+        return Err(SoftwareError::SmallStride);
+    }
+    let size = stride
+        .checked_mul(height)
+        .ok_or(SoftwareError::InvalidSize)?;
+    usize::try_from(size).map_err(|_| SoftwareError::InvalidSize)
+}
+
+/// Returns whether the acquire sync has been signaled.
+///
+/// This never blocks. Client buffers are waited for asynchronously by the commit
+/// timeline before they are committed, so their fences have usually already been
+/// signaled when they are rendered.
+fn sync_signaled(sync: &AcquireSync, dmabuf: Option<&DmaBuf>, flags: u32) -> bool {
+    match sync {
+        AcquireSync::None | AcquireSync::Unnecessary => true,
+        AcquireSync::SyncFile { sync_file } => fence_signaled(sync_file),
+        AcquireSync::Implicit => {
+            let Some(dmabuf) = dmabuf else {
+                return true;
+            };
+            for plane in &dmabuf.planes {
+                match dma_buf_export_sync_file(&plane.fd, flags) {
+                    Ok(fd) => {
+                        if !fence_signaled(&fd) {
+                            return false;
+                        }
+                    }
+                    Err(e) => {
+                        log::warn!("Could not export sync file from dmabuf: {}", ErrorFmt(e));
+                    }
+                }
+            }
+            true
+        }
+    }
+}
+
+fn fence_signaled(fd: &OwnedFd) -> bool {
+    let mut fds = [c::pollfd {
+        fd: fd.raw(),
+        events: c::POLLIN,
+        revents: 0,
+    }];
+    match uapi::poll(&mut fds, 0) {
+        Ok(n) => n > 0,
+        Err(e) => {
+            log::warn!("Could not poll a fence: {}", ErrorFmt(OsError::from(e)));
+            true
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use {
+    crate::{
+        cmm::cmm_manager::ColorManager,
+        format::{ARGB8888, Format, XRGB8888},
+        gfx_api::{
+            AcquireSync, AsyncShmGfxTexture, CopyTexture, FillRect, FramebufferRect, GfxApiOpt,
+            ReleaseSync, SampleRect,
+        },
+        gfx_apis::software::{
+            Pixels, ShmImage, SoftwareError, SoftwareFramebuffer, SoftwareImage, image_size,
+        },
+        rect::{Rect, Region},
+        theme::Color,
+    },
+    jay_config::video::Transform,
+    std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    },
+};
+
+const RED: [u8; 4] = [0, 0, 255, 255];
+const GREEN: [u8; 4] = [0, 255, 0, 255];
+const BLUE: [u8; 4] = [255, 0, 0, 255];
+const WHITE: [u8; 4] = [255, 255, 255, 255];
+
+fn image(format: &'static Format, width: i32, height: i32) -> Rc<SoftwareImage> {
+    Rc::new(SoftwareImage::Shm(ShmImage {
+        data: RefCell::new(vec![0; (width * height * 4) as usize]),
+        width,
+        height,
+        stride: width * 4,
+        format,
+    }))
+}
+
+fn pixel(img: &SoftwareImage, x: i32, y: i32) -> [u8; 4] {
+    let SoftwareImage::Shm(shm) = img else {
+        unreachable!();
+    };
+    let offset = (y * shm.stride + x * 4) as usize;
+    shm.data.borrow()[offset..offset + 4].try_into().unwrap()
+}
+
+#[test]
+fn shm_composition() {
+    let cm = ColorManager::new();
+    let cd = cm.srgb_srgb();
+    let tex = image(ARGB8888, 2, 2);
+    let pixels: Vec<_> = [RED, GREEN, BLUE, [0, 0, 0, 0]]
+        .into_iter()
+        .flatten()
+        .map(Cell::new)
+        .collect();
+    tex.clone().sync_upload(&pixels, Region::default()).unwrap();
+    let fb = SoftwareFramebuffer {
+        img: image(XRGB8888, 4, 4),
+    };
+    let ops = [
+        GfxApiOpt::CopyTexture(CopyTexture {
+            tex: tex.clone(),
+            source: SampleRect::identity(),
+            target: FramebufferRect::new(1.0, 1.0, 3.0, 3.0, Transform::None, 4.0, 4.0),
+            buffer_resv: None,
+            acquire_sync: AcquireSync::Unnecessary,
+            release_sync: ReleaseSync::None,
+            alpha: None,
+            opaque: false,
+            cd: cd.clone(),
+        }),
+        GfxApiOpt::FillRect(FillRect {
+            rect: FramebufferRect::new(0.0, 3.0, 4.0, 4.0, Transform::None, 4.0, 4.0),
+            color: Color::from_srgb(0, 0, 255),
+            alpha: None,
+            cd: cd.linear.clone(),
+        }),
+    ];
+    let region = Region::new(Rect::new_sized_unchecked(0, 0, 4, 4));
+    fb.render(
+        &AcquireSync::Unnecessary,
+        &ops,
+        Some(&Color::from_srgb(255, 255, 255)),
+        &region,
+    )
+    .unwrap();
+    assert_eq!(pixel(&fb.img, 0, 0), WHITE);
+    assert_eq!(pixel(&fb.img, 1, 1), RED);
+    assert_eq!(pixel(&fb.img, 2, 1), GREEN);
+    assert_eq!(pixel(&fb.img, 1, 2), BLUE);
+    assert_eq!(pixel(&fb.img, 2, 2), WHITE);
+    for x in 0..4 {
+        assert_eq!(pixel(&fb.img, x, 3), BLUE);
+    }
+}
+
+#[test]
+fn shm_composition_clipped() {
+    let cm = ColorManager::new();
+    let cd = cm.srgb_srgb();
+    let fb = SoftwareFramebuffer {
+        img: image(XRGB8888, 4, 4),
+    };
+    let ops = [GfxApiOpt::FillRect(FillRect {
+        rect: FramebufferRect::new(0.0, 0.0, 4.0, 4.0, Transform::None, 4.0, 4.0),
+        color: Color::from_srgb(255, 0, 0),
+        alpha: None,
+        cd: cd.linear.clone(),
+    })];
+    let region = Region::new(Rect::new_sized_unchecked(0, 0, 2, 4));
+    fb.render(&AcquireSync::Unnecessary, &ops, None, &region)
+        .unwrap();
+    assert_eq!(pixel(&fb.img, 1, 0), RED);
+    assert_eq!(pixel(&fb.img, 2, 0), [0, 0, 0, 0]);
+}
+
+#[test]
+fn blend_saturates() {
+    let mut data = WHITE;
+    let px = Pixels {
+        data: data.as_mut_ptr(),
+        width: 1,
+        height: 1,
+        stride: 4,
+        has_alpha: true,
+    };
+    unsafe {
+        px.blend(0, 0, [200, 200, 200, 128]);
+    }
+    assert_eq!(data, WHITE);
+}
+
+#[test]
+fn image_size_overflow() {
+    assert_eq!(image_size(2, 2, 8).unwrap(), 16);
+    assert!(matches!(
+        image_size(2, 2, 4),
+        Err(SoftwareError::SmallStride)
+    ));
+    assert!(matches!(
+        image_size(i32::MAX, 1, i32::MAX),
+        Err(SoftwareError::InvalidSize)
+    ));
+    assert!(matches!(
+        image_size(1 << 16, 1 << 16, 1 << 18),
+        Err(SoftwareError::InvalidSize)
+    ));
+    assert!(matches!(
+        image_size(-1, 1, 4),
+        Err(SoftwareError::InvalidSize)
+    ));
+}
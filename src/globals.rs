@@ -63,9 +63,12 @@ use {
             xdg_wm_base::XdgWmBaseGlobal,
             xdg_wm_dialog_v1::XdgWmDialogV1Global,
             zwlr_foreign_toplevel_manager_v1::ZwlrForeignToplevelManagerV1Global,
+            zwlr_gamma_control_manager_v1::ZwlrGammaControlManagerV1Global,
             zwlr_layer_shell_v1::ZwlrLayerShellV1Global,
+            zwlr_output_power_manager_v1::ZwlrOutputPowerManagerV1Global,
             zwlr_screencopy_manager_v1::ZwlrScreencopyManagerV1Global,
             zwp_idle_inhibit_manager_v1::ZwpIdleInhibitManagerV1Global,
+            zwp_keyboard_shortcuts_inhibit_manager_v1::ZwpKeyboardShortcutsInhibitManagerV1Global,
             zxdg_decoration_manager_v1::ZxdgDecorationManagerV1Global,
             zxdg_output_manager_v1::ZxdgOutputManagerV1Global,
         },
@@ -190,6 +193,8 @@ impl Globals {
         // add_singleton!(ZwpPrimarySelectionDeviceManagerV1Global);
         add_singleton!(ZwlrLayerShellV1Global);
         add_singleton!(ZwlrOutputManagerV1Global);
+        add_singleton!(ZwlrOutputPowerManagerV1Global);
+        add_singleton!(ZwlrGammaControlManagerV1Global);
         add_singleton!(ZxdgOutputManagerV1Global);
         add_singleton!(JayCompositorGlobal);
         add_singleton!(ZwlrScreencopyManagerV1Global);
@@ -206,6 +211,7 @@ impl Globals {
         add_singleton!(XdgActivationV1Global);
         add_singleton!(ExtForeignToplevelListV1Global);
         add_singleton!(ZwpIdleInhibitManagerV1Global);
+        add_singleton!(ZwpKeyboardShortcutsInhibitManagerV1Global);
         add_singleton!(ExtIdleNotifierV1Global);
         add_singleton!(XdgToplevelDragManagerV1Global);
         add_singleton!(ZwlrForeignToplevelManagerV1Global);
@@ -32,6 +32,9 @@ pub struct SizedIcons {
     pub pin_unfocused_title: StaticMap<IconState, Rc<dyn GfxTexture>>,
     pub pin_focused_title: StaticMap<IconState, Rc<dyn GfxTexture>>,
     pub pin_attention_requested: StaticMap<IconState, Rc<dyn GfxTexture>>,
+    pub close_unfocused_title: Rc<dyn GfxTexture>,
+    pub close_focused_title: Rc<dyn GfxTexture>,
+    pub close_attention_requested: Rc<dyn GfxTexture>,
 }
 
 #[derive(Debug, Error)]
@@ -97,27 +100,36 @@ pub fn create_icons(
     }
     let size = size as u32;
 
+    let create_icon = |path: &Path, color: Color| {
+        let mut paint = Paint::default();
+        paint.set_color(color);
+        let s = size as f32 / 100.0;
+        let transform = Transform::from_scale(s, s);
+        let mut pixmap = Pixmap::new(size, size).ok_or(IconsError::CreatePixmap)?;
+        pixmap.fill_path(path, &paint, FillRule::EvenOdd, transform, None);
+        upload_pixmap(pixmap, ctx)
+    };
+
     let create_pins = |color: crate::theme::Color| {
-        let create_pin = |color: Color| {
-            let mut paint = Paint::default();
-            paint.set_color(color);
-            let s = size as f32 / 100.0;
-            let transform = Transform::from_scale(s, s);
-            let mut pixmap = Pixmap::new(size, size).ok_or(IconsError::CreatePixmap)?;
-            pixmap.fill_path(&PIN_PATH, &paint, FillRule::EvenOdd, transform, None);
-            upload_pixmap(pixmap, ctx)
-        };
         let colors = calculate_accents(color);
         Ok(static_map! {
-            IconState::Passive => create_pin(colors[0])?,
-            IconState::Active => create_pin(colors[1])?,
+            IconState::Passive => create_icon(&PIN_PATH, colors[0])?,
+            IconState::Active => create_icon(&PIN_PATH, colors[1])?,
         })
     };
 
+    let create_close = |color: crate::theme::Color| {
+        let colors = calculate_accents(color);
+        create_icon(&CLOSE_PATH, colors[1])
+    };
+
     Ok(SizedIcons {
         pin_unfocused_title: create_pins(theme.colors.unfocused_title_background.get())?,
         pin_focused_title: create_pins(theme.colors.focused_title_background.get())?,
         pin_attention_requested: create_pins(theme.colors.attention_requested_background.get())?,
+        close_unfocused_title: create_close(theme.colors.unfocused_title_background.get())?,
+        close_focused_title: create_close(theme.colors.focused_title_background.get())?,
+        close_attention_requested: create_close(theme.colors.attention_requested_background.get())?,
     })
 }
 
@@ -172,11 +184,39 @@ static PIN_PATH: LazyLock<Path> = LazyLock::new(|| {
     path.finish().unwrap()
 });
 
+static CLOSE_PATH: LazyLock<Path> = LazyLock::new(|| {
+    let lo = 25.0f32;
+    let hi = 75.0f32;
+    let c = 50.0f32;
+    let w = 7.0f32;
+
+    let mut path = PathBuilder::new();
+    path.move_to(lo, lo + w);
+    path.line_to(lo + w, lo);
+    path.line_to(c, c - w);
+    path.line_to(hi - w, lo);
+    path.line_to(hi, lo + w);
+    path.line_to(c + w, c);
+    path.line_to(hi, hi - w);
+    path.line_to(hi - w, hi);
+    path.line_to(c, c + w);
+    path.line_to(lo + w, hi);
+    path.line_to(lo, hi - w);
+    path.line_to(c - w, c);
+    path.close();
+    path.finish().unwrap()
+});
+
 #[test]
 fn pin_path() {
     let _path = &*PIN_PATH;
 }
 
+#[test]
+fn close_path() {
+    let _path = &*CLOSE_PATH;
+}
+
 trait PathBuilderExt {
     fn arc_cw_to(&mut self, cx: f32, cy: f32, x: f32, y: f32);
 }
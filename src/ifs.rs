@@ -23,7 +23,9 @@ pub mod jay_idle;
 pub mod jay_input;
 pub mod jay_log_file;
 pub mod jay_output;
+pub mod jay_output_watcher;
 pub mod jay_pointer;
+pub mod jay_protocol_trace;
 pub mod jay_randr;
 pub mod jay_reexec;
 pub mod jay_render_ctx;
@@ -87,10 +89,15 @@ pub mod xdg_wm_base;
 pub mod xdg_wm_dialog_v1;
 pub mod zwlr_foreign_toplevel_handle_v1;
 pub mod zwlr_foreign_toplevel_manager_v1;
+pub mod zwlr_gamma_control_manager_v1;
+pub mod zwlr_gamma_control_v1;
 pub mod zwlr_layer_shell_v1;
+pub mod zwlr_output_power_manager_v1;
+pub mod zwlr_output_power_v1;
 pub mod zwlr_screencopy_frame_v1;
 pub mod zwlr_screencopy_manager_v1;
 pub mod zwp_idle_inhibit_manager_v1;
+pub mod zwp_keyboard_shortcuts_inhibit_manager_v1;
 pub mod zwp_linux_buffer_params_v1;
 pub mod zwp_linux_dmabuf_feedback_v1;
 pub mod zwp_linux_dmabuf_v1;
@@ -11,6 +11,7 @@ use {
 
 const STATE_ACTIVATED: u32 = 4;
 const STATE_FULLSCREEN: u32 = 8;
+const STATE_URGENT: u32 = 16;
 
 pub struct ExtForeignToplevelHandleStateV1 {
     pub id: ExtForeignToplevelHandleStateV1Id,
@@ -20,11 +21,12 @@ pub struct ExtForeignToplevelHandleStateV1 {
 }
 
 impl ExtForeignToplevelHandleStateV1 {
-    pub fn send_state(&self, active: bool, fullscreen: bool) {
+    pub fn send_state(&self, active: bool, fullscreen: bool, urgent: bool) {
         self.client.event(State {
             self_id: self.id,
             states: if active { STATE_ACTIVATED } else { 0 }
-                | if fullscreen { STATE_FULLSCREEN } else { 0 },
+                | if fullscreen { STATE_FULLSCREEN } else { 0 }
+                | if urgent { STATE_URGENT } else { 0 },
         });
     }
 }
@@ -69,9 +69,9 @@ impl ExtForeignToplevelHandleV1 {
         });
     }
 
-    pub fn send_state(&self, active: bool, fullscreen: bool) {
+    pub fn send_state(&self, active: bool, fullscreen: bool, urgent: bool) {
         if let Some(state) = self.toplevel_state.get() {
-            state.send_state(active, fullscreen);
+            state.send_state(active, fullscreen, urgent);
         }
     }
 }
@@ -13,6 +13,7 @@ use {
 
 const STATE_ACTIVATED: u32 = 4;
 const STATE_FULLSCREEN: u32 = 8;
+const STATE_URGENT: u32 = 16;
 
 pub struct ExtForeignToplevelStateV1Global {
     pub name: GlobalName,
@@ -39,7 +40,7 @@ impl ExtForeignToplevelStateV1Global {
         client.add_client_obj(&obj)?;
         client.event(Capabilities {
             self_id: id,
-            capabilities: STATE_ACTIVATED | STATE_FULLSCREEN,
+            capabilities: STATE_ACTIVATED | STATE_FULLSCREEN | STATE_URGENT,
         });
         Ok(())
     }
@@ -44,6 +44,8 @@ impl ExtImageCopyCaptureManagerV1Global {
     }
 }
 
+const OPTIONS_PAINT_CURSORS: u32 = 1;
+
 pub struct ExtImageCopyCaptureManagerV1 {
     pub(super) id: ExtImageCopyCaptureManagerV1Id,
     pub(super) client: Rc<Client>,
@@ -55,6 +57,11 @@ impl ExtImageCopyCaptureManagerV1RequestHandler for ExtImageCopyCaptureManagerV1
     type Error = ExtImageCopyCaptureManagerV1Error;
 
     fn create_session(&self, req: CreateSession, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        if req.options & !OPTIONS_PAINT_CURSORS != 0 {
+            return Err(ExtImageCopyCaptureManagerV1Error::InvalidOptions(
+                req.options,
+            ));
+        }
         let source = self.client.lookup(req.source)?;
         let obj = Rc::new_cyclic(|slf| {
             ExtImageCopyCaptureSessionV1::new(
@@ -62,6 +69,7 @@ impl ExtImageCopyCaptureManagerV1RequestHandler for ExtImageCopyCaptureManagerV1
                 &self.client,
                 self.version,
                 &source.ty,
+                req.options & OPTIONS_PAINT_CURSORS != 0,
                 slf,
             )
         });
@@ -170,5 +178,7 @@ simple_add_obj!(ExtImageCopyCaptureManagerV1);
 pub enum ExtImageCopyCaptureManagerV1Error {
     #[error(transparent)]
     ClientError(Box<ClientError>),
+    #[error("Invalid options {0:#x}")]
+    InvalidOptions(u32),
 }
 efrom!(ExtImageCopyCaptureManagerV1Error, ClientError);
@@ -48,6 +48,7 @@ pub struct ExtImageCopyCaptureSessionV1 {
     pub(super) buffer: CloneCell<Option<Rc<WlBuffer>>>,
     pub(super) pending_download: Cell<Option<PendingShmTransfer>>,
     pub(super) presented: Cell<Option<(u64, u32)>>,
+    pub(super) paint_cursors: bool,
 }
 
 impl ExtImageCopyCaptureSessionV1 {
@@ -56,6 +57,7 @@ impl ExtImageCopyCaptureSessionV1 {
         client: &Rc<Client>,
         version: Version,
         source: &ImageCaptureSource,
+        paint_cursors: bool,
         slf: &Weak<Self>,
     ) -> Self {
         ExtImageCopyCaptureSessionV1 {
@@ -76,6 +78,7 @@ impl ExtImageCopyCaptureSessionV1 {
             buffer: Default::default(),
             pending_download: Default::default(),
             presented: Default::default(),
+            paint_cursors,
         }
     }
 
@@ -233,7 +236,7 @@ impl ExtImageCopyCaptureSessionV1 {
                 resv,
                 acquire_sync,
                 release_sync,
-                render_hardware_cursors,
+                render_hardware_cursors && self.paint_cursors,
                 x_off,
                 y_off,
                 size,
@@ -3,6 +3,7 @@ use {
         client::{Client, ClientError, ClientId},
         fixed::Fixed,
         ifs::{ipc::x_data_device::XIpcDevice, wl_seat::WlSeatGlobal},
+        state::State,
         utils::{
             bitflags::BitflagsExt, cell_ext::CellExt, clonecell::CloneCell, numcell::NumCell,
             smallmap::SmallMap,
@@ -21,6 +22,7 @@ use {
     uapi::OwnedFd,
 };
 
+pub mod cached_data_source;
 pub mod data_control;
 pub mod wl_data_device;
 pub mod wl_data_device_manager;
@@ -60,6 +62,10 @@ pub trait DynDataSource: 'static {
     fn detach_seat(&self, seat: &Rc<WlSeatGlobal>);
     fn cancel_unprivileged_offers(&self);
 
+    fn is_cached(&self) -> bool {
+        false
+    }
+
     fn send_target(&self, mime_type: Option<&str>) {
         let _ = mime_type;
         log::warn!(
@@ -191,7 +197,7 @@ pub struct SourceData {
     pub id: DataSourceId,
     offers: SmallMap<DataOfferId, Rc<dyn DynDataOffer>, 1>,
     mime_types: RefCell<AHashSet<String>>,
-    pub client: Rc<Client>,
+    pub client_id: ClientId,
     state: NumCell<u32>,
     actions: Cell<Option<u32>>,
     role: Cell<Role>,
@@ -219,13 +225,17 @@ impl Default for SharedState {
 }
 
 impl SourceData {
-    pub fn new(client: &Rc<Client>) -> Self {
+    pub fn new(client: &Client) -> Self {
+        Self::new_with_id(&client.state, client.id)
+    }
+
+    pub fn new_with_id(state: &State, client_id: ClientId) -> Self {
         Self {
             seat: Default::default(),
-            id: client.state.data_source_ids.next(),
+            id: state.data_source_ids.next(),
             offers: Default::default(),
             mime_types: Default::default(),
-            client: client.clone(),
+            client_id,
             state: NumCell::new(0),
             actions: Cell::new(None),
             role: Cell::new(Role::Selection),
@@ -388,8 +398,23 @@ pub fn add_data_source_mime_type<T: IpcVtable>(src: &T::Source, mime_type: &str)
 }
 
 pub fn destroy_data_source<T: IpcVtable>(src: &T::Source) {
+    destroy_data_source_::<T>(src, false);
+}
+
+/// Destroys a data source because its client is going away.
+///
+/// Unlike an explicit destruction, this replaces the selection with its cached
+/// contents if clipboard persistence is enabled.
+pub fn teardown_data_source<T: IpcVtable>(src: &T::Source) {
+    destroy_data_source_::<T>(src, true);
+}
+
+fn destroy_data_source_<T: IpcVtable>(src: &T::Source, teardown: bool) {
     let data = src.source_data();
     if let Some(seat) = data.seat.take() {
+        if teardown && data.role.get() == Role::Selection && seat.persist_selection(data.id) {
+            return;
+        }
         T::unset(&seat, data.role.get());
     }
 }
@@ -434,14 +459,14 @@ fn break_source_loops<T: IpcVtable>(src: &T::Source) {
     let data = src.source_data();
     let mut remove = SmallVec::<[DataOfferId; 1]>::new();
     for (id, offer) in &data.offers {
-        if offer.client_id() == data.client.id {
+        if offer.client_id() == data.client_id {
             remove.push(id);
         }
     }
     while let Some(id) = remove.pop() {
         data.offers.remove(&id);
     }
-    destroy_data_source::<T>(src);
+    teardown_data_source::<T>(src);
 }
 
 fn break_offer_loops<T: IpcVtable>(offer: &T::Offer) {
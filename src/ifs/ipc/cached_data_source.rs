@@ -0,0 +1,212 @@
+use {
+    crate::{
+        async_engine::SpawnedFuture,
+        client::ClientId,
+        ifs::{
+            ipc::{
+                DataSource, DataSourceId, DynDataSource, SourceData, cancel_offers, detach_seat,
+                offer_source_to_x,
+                x_data_device::{XClipboardIpc, XIpcDevice},
+            },
+            wl_seat::WlSeatGlobal,
+        },
+        io_uring::IoUringError,
+        state::State,
+        utils::{
+            buf::Buf, copyhashmap::CopyHashMap, errorfmt::ErrorFmt, numcell::NumCell,
+            oserror::OsError,
+        },
+    },
+    std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+        time::Duration,
+    },
+    uapi::{OwnedFd, c, pipe2},
+};
+
+/// Copies the contents of a selection into the compositor while the selection is
+/// alive so that it can be served after the owning client has gone away.
+pub struct SelectionCache {
+    state: Rc<State>,
+    pub source_id: DataSourceId,
+    size: Cell<usize>,
+    pending: NumCell<usize>,
+    failed: Cell<bool>,
+    contents: RefCell<Vec<(String, Vec<u8>)>>,
+    readers: RefCell<Vec<SpawnedFuture<()>>>,
+}
+
+impl SelectionCache {
+    pub fn new(state: &Rc<State>, src: &Rc<dyn DynDataSource>) -> Rc<Self> {
+        let data = src.source_data();
+        let slf = Rc::new(Self {
+            state: state.clone(),
+            source_id: data.id,
+            size: Cell::new(0),
+            pending: NumCell::new(0),
+            failed: Cell::new(false),
+            contents: Default::default(),
+            readers: Default::default(),
+        });
+        let mime_types: Vec<_> = data.mime_types.borrow().iter().cloned().collect();
+        for mime_type in mime_types {
+            let (read, write) = match pipe2(c::O_CLOEXEC) {
+                Ok(p) => p,
+                Err(e) => {
+                    log::error!("Could not create pipe: {}", OsError::from(e));
+                    slf.failed.set(true);
+                    break;
+                }
+            };
+            src.send_send(&mime_type, Rc::new(write));
+            slf.pending.fetch_add(1);
+            let future = state
+                .eng
+                .spawn("selection cache", slf.clone().read(mime_type, read));
+            slf.readers.borrow_mut().push(future);
+        }
+        slf
+    }
+
+    async fn read(self: Rc<Self>, mime_type: String, fd: OwnedFd) {
+        let fd = Rc::new(fd);
+        let max_size = self.state.clipboard_persistence_max_size.get() as usize;
+        let mut data = vec![];
+        let mut buf = Buf::new(4096);
+        loop {
+            match self.state.ring.read(&fd, buf.clone()).await {
+                Ok(0) => break,
+                Ok(n) => {
+                    let size = self.size.get() + n;
+                    if size > max_size {
+                        log::warn!("Selection exceeds the persistence size limit");
+                        self.failed.set(true);
+                        return;
+                    }
+                    self.size.set(size);
+                    data.extend_from_slice(&buf[..n]);
+                }
+                Err(e) => {
+                    log::error!("Could not read from data source: {}", ErrorFmt(e));
+                    self.failed.set(true);
+                    return;
+                }
+            }
+        }
+        self.contents.borrow_mut().push((mime_type, data));
+        self.pending.fetch_sub(1);
+    }
+
+    pub fn cancel(&self) {
+        self.readers.take();
+    }
+
+    pub fn create_source(&self) -> Option<Rc<CachedDataSource>> {
+        if self.failed.get() || self.pending.get() > 0 {
+            return None;
+        }
+        let contents = self.contents.take();
+        // The source is owned by the compositor and must not keep the exited
+        // client alive.
+        let data = SourceData::new_with_id(&self.state, ClientId::from_raw(0));
+        data.mime_types
+            .borrow_mut()
+            .extend(contents.iter().map(|(mt, _)| mt.clone()));
+        Some(Rc::new(CachedDataSource {
+            state: self.state.clone(),
+            data,
+            contents,
+            transfer_ids: NumCell::new(0),
+            transfers: Default::default(),
+        }))
+    }
+}
+
+/// A data source owned by the compositor that serves previously cached data.
+pub struct CachedDataSource {
+    state: Rc<State>,
+    data: SourceData,
+    contents: Vec<(String, Vec<u8>)>,
+    transfer_ids: NumCell<u64>,
+    transfers: Rc<CopyHashMap<u64, SpawnedFuture<()>>>,
+}
+
+struct CachedDataTransfer {
+    id: u64,
+    data: Buf,
+    fd: Rc<OwnedFd>,
+    state: Rc<State>,
+    transfers: Rc<CopyHashMap<u64, SpawnedFuture<()>>>,
+}
+
+impl CachedDataTransfer {
+    async fn run(mut self) {
+        let timeout = self.state.now() + Duration::from_millis(5000);
+        let mut pos = 0;
+        while pos < self.data.len() {
+            let res = self
+                .state
+                .ring
+                .write(&self.fd, self.data.slice(pos..), Some(timeout));
+            match res.await {
+                Ok(n) => pos += n,
+                Err(IoUringError::OsError(OsError(c::ECANCELED))) => {
+                    log::error!("Transfer timed out");
+                    break;
+                }
+                Err(e) => {
+                    log::error!("Could not write to wayland client: {}", ErrorFmt(e));
+                    break;
+                }
+            }
+        }
+        self.transfers.remove(&self.id);
+    }
+}
+
+impl DataSource for CachedDataSource {
+    fn send_cancelled(&self, _seat: &Rc<WlSeatGlobal>) {}
+}
+
+impl DynDataSource for CachedDataSource {
+    fn source_data(&self) -> &SourceData {
+        &self.data
+    }
+
+    fn send_send(&self, mime_type: &str, fd: Rc<OwnedFd>) {
+        let Some((_, data)) = self.contents.iter().find(|(mt, _)| mt == mime_type) else {
+            return;
+        };
+        let id = self.transfer_ids.fetch_add(1);
+        let transfer = CachedDataTransfer {
+            id,
+            data: Buf::from_slice(data),
+            fd,
+            state: self.state.clone(),
+            transfers: self.transfers.clone(),
+        };
+        self.transfers.set(
+            id,
+            self.state
+                .eng
+                .spawn("cached selection transfer", transfer.run()),
+        );
+    }
+
+    fn offer_to_x(self: Rc<Self>, dd: &Rc<XIpcDevice>) {
+        offer_source_to_x::<XClipboardIpc>(self, dd);
+    }
+
+    fn detach_seat(&self, seat: &Rc<WlSeatGlobal>) {
+        detach_seat(self, seat);
+    }
+
+    fn cancel_unprivileged_offers(&self) {
+        cancel_offers(self, false);
+    }
+
+    fn is_cached(&self) -> bool {
+        true
+    }
+}
@@ -47,6 +47,7 @@ impl ExtDataControlSourceV1 {
         Self {
             id,
             data: DataControlSourceData {
+                client: client.clone(),
                 data: SourceData::new(client),
                 version,
                 location: Cell::new(IpcLocation::Clipboard),
@@ -57,7 +58,7 @@ impl ExtDataControlSourceV1 {
     }
 
     pub fn send_send(&self, mime_type: &str, fd: Rc<OwnedFd>) {
-        self.data.data.client.event(Send {
+        self.data.client.event(Send {
             self_id: self.id,
             mime_type,
             fd,
@@ -65,7 +66,7 @@ impl ExtDataControlSourceV1 {
     }
 
     pub fn send_cancelled(&self) {
-        self.data.data.client.event(Cancelled { self_id: self.id })
+        self.data.client.event(Cancelled { self_id: self.id })
     }
 }
 
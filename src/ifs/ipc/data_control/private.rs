@@ -76,6 +76,7 @@ pub trait DataControlOffer: WaylandObject {
 }
 
 pub struct DataControlSourceData {
+    pub client: Rc<Client>,
     pub data: SourceData,
     pub version: Version,
     pub location: Cell<IpcLocation>,
@@ -375,7 +376,7 @@ pub mod logic {
             IpcLocation::Clipboard => destroy_data_source::<Clipboard<S::Ipc>>(s),
             IpcLocation::PrimarySelection => destroy_data_source::<PrimarySelection<S::Ipc>>(s),
         }
-        s.data().data.client.remove_obj(s)?;
+        s.data().client.remove_obj(s)?;
         Ok(())
     }
 
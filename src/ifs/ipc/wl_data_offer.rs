@@ -125,6 +125,9 @@ impl WlDataOfferRequestHandler for WlDataOffer {
         self.data.shared.state.set(state);
         if let Some(src) = self.data.source.get() {
             src.send_target(req.mime_type);
+            if let Some(seat) = src.source_data().seat.get() {
+                seat.update_dnd_cursor();
+            }
         }
         Ok(())
     }
@@ -1,6 +1,7 @@
 use {
     crate::{
         client::{Client, ClientError},
+        cursor::KnownCursor,
         ifs::{
             ipc::{
                 DataSource, DynDataOffer, DynDataSource, OFFER_STATE_ACCEPTED, OFFER_STATE_DROPPED,
@@ -8,7 +9,7 @@ use {
                 add_data_source_mime_type, break_source_loops, cancel_offers, destroy_data_source,
                 detach_seat, offer_source_to_x,
                 wl_data_device::ClipboardIpc,
-                wl_data_device_manager::{DND_ALL, DND_NONE},
+                wl_data_device_manager::{DND_ALL, DND_ASK, DND_COPY, DND_MOVE, DND_NONE},
                 x_data_device::{XClipboardIpc, XIpcDevice},
             },
             wl_seat::WlSeatGlobal,
@@ -31,6 +32,7 @@ const INVALID_SOURCE: u32 = 1;
 
 pub struct WlDataSource {
     pub id: WlDataSourceId,
+    pub client: Rc<Client>,
     pub data: SourceData,
     pub version: Version,
     pub tracker: Tracker<Self>,
@@ -81,6 +83,7 @@ impl WlDataSource {
     pub fn new(id: WlDataSourceId, client: &Rc<Client>, version: Version) -> Self {
         Self {
             id,
+            client: client.clone(),
             tracker: Default::default(),
             data: SourceData::new(client),
             version,
@@ -128,7 +131,23 @@ impl WlDataSource {
                 // offer.client.flush();
             }
             self.send_action(action);
-            // self.data.client.flush();
+            // self.client.flush();
+            if let Some(seat) = self.data.seat.get() {
+                seat.update_dnd_cursor();
+            }
+        }
+    }
+
+    pub fn dnd_cursor(&self) -> KnownCursor {
+        let shared = self.data.shared.get();
+        if shared.state.get().not_contains(OFFER_STATE_ACCEPTED) {
+            return KnownCursor::NoDrop;
+        }
+        match shared.selected_action.get() {
+            DND_COPY => KnownCursor::Copy,
+            DND_MOVE => KnownCursor::Move,
+            DND_ASK => KnownCursor::DndAsk,
+            _ => KnownCursor::NoDrop,
         }
     }
 
@@ -161,11 +180,11 @@ impl WlDataSource {
         if let Some(drag) = self.toplevel_drag.take() {
             drag.finish_drag(seat);
         }
-        self.data.client.event(Cancelled { self_id: self.id })
+        self.client.event(Cancelled { self_id: self.id })
     }
 
     pub fn send_send(&self, mime_type: &str, fd: Rc<OwnedFd>) {
-        self.data.client.event(Send {
+        self.client.event(Send {
             self_id: self.id,
             mime_type,
             fd,
@@ -173,18 +192,18 @@ impl WlDataSource {
     }
 
     pub fn send_target(&self, mime_type: Option<&str>) {
-        self.data.client.event(Target {
+        self.client.event(Target {
             self_id: self.id,
             mime_type,
         })
     }
 
     pub fn send_dnd_finished(&self) {
-        self.data.client.event(DndFinished { self_id: self.id })
+        self.client.event(DndFinished { self_id: self.id })
     }
 
     pub fn send_action(&self, dnd_action: u32) {
-        self.data.client.event(Action {
+        self.client.event(Action {
             self_id: self.id,
             dnd_action,
         })
@@ -207,7 +226,7 @@ impl WlDataSourceRequestHandler for WlDataSource {
 
     fn destroy(&self, _req: Destroy, _slf: &Rc<Self>) -> Result<(), Self::Error> {
         destroy_data_source::<ClipboardIpc>(self);
-        self.data.client.remove_obj(self)?;
+        self.client.remove_obj(self)?;
         Ok(())
     }
 
@@ -22,6 +22,7 @@ use {
 
 pub struct ZwpPrimarySelectionSourceV1 {
     pub id: ZwpPrimarySelectionSourceV1Id,
+    pub client: Rc<Client>,
     pub data: SourceData,
     pub tracker: Tracker<Self>,
     pub version: Version,
@@ -59,6 +60,7 @@ impl ZwpPrimarySelectionSourceV1 {
     pub fn new(id: ZwpPrimarySelectionSourceV1Id, client: &Rc<Client>, version: Version) -> Self {
         Self {
             id,
+            client: client.clone(),
             data: SourceData::new(client),
             tracker: Default::default(),
             version,
@@ -66,11 +68,11 @@ impl ZwpPrimarySelectionSourceV1 {
     }
 
     pub fn send_cancelled(&self) {
-        self.data.client.event(Cancelled { self_id: self.id });
+        self.client.event(Cancelled { self_id: self.id });
     }
 
     pub fn send_send(&self, mime_type: &str, fd: Rc<OwnedFd>) {
-        self.data.client.event(Send {
+        self.client.event(Send {
             self_id: self.id,
             mime_type,
             fd,
@@ -88,7 +90,7 @@ impl ZwpPrimarySelectionSourceV1RequestHandler for ZwpPrimarySelectionSourceV1 {
 
     fn destroy(&self, _req: Destroy, _slf: &Rc<Self>) -> Result<(), Self::Error> {
         destroy_data_source::<PrimarySelectionIpc>(self);
-        self.data.client.remove_obj(self)?;
+        self.client.remove_obj(self)?;
         Ok(())
     }
 }
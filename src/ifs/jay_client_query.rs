@@ -8,8 +8,8 @@ use {
             JayClientQueryId,
             jay_client_query::{
                 AddAll, AddId, Comm, Destroy, Done, End, Exe, Execute, IsXwayland,
-                JayClientQueryRequestHandler, Pid, SandboxAppId, SandboxEngine, SandboxInstanceId,
-                Sandboxed, Start, Uid,
+                JayClientQueryRequestHandler, NumObjects, Pid, SandboxAppId, SandboxEngine,
+                SandboxInstanceId, Sandboxed, Start, Uid,
             },
         },
     },
@@ -17,6 +17,8 @@ use {
     thiserror::Error,
 };
 
+const NUM_OBJECTS_SINCE: Version = Version(26);
+
 pub struct JayClientQuery {
     pub id: JayClientQueryId,
     pub client: Rc<Client>,
@@ -95,6 +97,12 @@ impl JayClientQueryRequestHandler for JayClientQuery {
                     instance_id,
                 });
             }
+            if self.version >= NUM_OBJECTS_SINCE {
+                self.client.event(NumObjects {
+                    self_id: self.id,
+                    num_objects: client.objects.len() as u64,
+                });
+            }
             self.client.event(End { self_id: self.id });
         };
         if self.all.get() {
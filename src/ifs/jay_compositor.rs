@@ -11,7 +11,9 @@ use {
             jay_input::JayInput,
             jay_log_file::JayLogFile,
             jay_output::JayOutput,
+            jay_output_watcher::JayOutputWatcher,
             jay_pointer::JayPointer,
+            jay_protocol_trace::JayProtocolTrace,
             jay_randr::JayRandr,
             jay_reexec::JayReexec,
             jay_render_ctx::JayRenderCtx,
@@ -79,7 +81,7 @@ impl Global for JayCompositorGlobal {
     }
 
     fn version(&self) -> u32 {
-        19
+        30
     }
 
     fn required_caps(&self) -> ClientCaps {
@@ -515,6 +517,60 @@ impl JayCompositorRequestHandler for JayCompositor {
         self.client.add_client_obj(&obj)?;
         Ok(())
     }
+
+    fn watch_outputs(&self, req: WatchOutputs, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        let watcher = Rc::new(JayOutputWatcher {
+            id: req.id,
+            client: self.client.clone(),
+            tracker: Default::default(),
+        });
+        track!(self.client, watcher);
+        self.client.add_client_obj(&watcher)?;
+        self.client
+            .state
+            .output_watchers
+            .set((self.client.id, req.id), watcher.clone());
+        for output in self.client.state.root.outputs.lock().values() {
+            let global = &output.global;
+            watcher.send_connected(&global.connector.name, &global.output_id);
+        }
+        Ok(())
+    }
+
+    fn create_protocol_trace(
+        &self,
+        req: CreateProtocolTrace,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        let obj = Rc::new(JayProtocolTrace {
+            id: req.id,
+            client: self.client.clone(),
+            tracker: Default::default(),
+            version: self.version,
+            interfaces: Default::default(),
+            clients: Default::default(),
+        });
+        track!(self.client, obj);
+        self.client.add_client_obj(&obj)?;
+        Ok(())
+    }
+
+    fn set_low_power_mode(&self, req: SetLowPowerMode, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.client.state.set_low_power_mode(req.enabled != 0);
+        Ok(())
+    }
+
+    fn get_low_power_mode(
+        &self,
+        _req: GetLowPowerMode,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        self.client.event(LowPowerMode {
+            self_id: self.id,
+            enabled: self.client.state.low_power_mode.get() as _,
+        });
+        Ok(())
+    }
 }
 
 object_base! {
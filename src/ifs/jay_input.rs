@@ -1,15 +1,23 @@
 use {
     crate::{
-        backend::{self, InputDeviceAccelProfile, InputDeviceClickMethod, InputDeviceId},
+        backend::{
+            self, InputDeviceAccelProfile, InputDeviceClickMethod, InputDeviceId,
+            InputDeviceScrollMethod, InputDeviceSendEventsMode,
+        },
         client::{Client, ClientError},
         clientmem::{ClientMem, ClientMemError},
         ifs::wl_seat::WlSeatGlobal,
         kbvm::{KbvmError, KbvmMap},
         leaks::Tracker,
         libinput::consts::{
-            AccelProfile, ConfigClickMethod, LIBINPUT_CONFIG_ACCEL_PROFILE_ADAPTIVE,
-            LIBINPUT_CONFIG_ACCEL_PROFILE_FLAT, LIBINPUT_CONFIG_CLICK_METHOD_BUTTON_AREAS,
-            LIBINPUT_CONFIG_CLICK_METHOD_CLICKFINGER, LIBINPUT_CONFIG_CLICK_METHOD_NONE,
+            AccelProfile, ConfigClickMethod, ConfigScrollMethod, ConfigSendEventsMode,
+            LIBINPUT_CONFIG_ACCEL_PROFILE_ADAPTIVE, LIBINPUT_CONFIG_ACCEL_PROFILE_FLAT,
+            LIBINPUT_CONFIG_CLICK_METHOD_BUTTON_AREAS, LIBINPUT_CONFIG_CLICK_METHOD_CLICKFINGER,
+            LIBINPUT_CONFIG_CLICK_METHOD_NONE, LIBINPUT_CONFIG_SCROLL_2FG,
+            LIBINPUT_CONFIG_SCROLL_EDGE, LIBINPUT_CONFIG_SCROLL_NO_SCROLL,
+            LIBINPUT_CONFIG_SCROLL_ON_BUTTON_DOWN, LIBINPUT_CONFIG_SEND_EVENTS_DISABLED,
+            LIBINPUT_CONFIG_SEND_EVENTS_DISABLED_ON_EXTERNAL_MOUSE,
+            LIBINPUT_CONFIG_SEND_EVENTS_ENABLED,
         },
         object::{Object, Version},
         state::{DeviceHandlerData, InputDeviceData},
@@ -31,6 +39,11 @@ pub struct JayInput {
 const CALIBRATION_MATRIX_SINCE: Version = Version(4);
 const CLICK_METHOD_SINCE: Version = Version(19);
 const MIDDLE_BUTTON_EMULATION_SINCE: Version = Version(19);
+const CURSOR_ANIMATION_SINCE: Version = Version(20);
+const KEYBOARD_LAYOUT_SINCE: Version = Version(24);
+const SCROLL_METHOD_SINCE: Version = Version(25);
+const DISABLE_WHILE_TYPING_SINCE: Version = Version(25);
+const SEND_EVENTS_MODE_SINCE: Version = Version(25);
 
 impl JayInput {
     pub fn new(id: JayInputId, client: &Rc<Client>, version: Version) -> Self {
@@ -66,6 +79,29 @@ impl JayInput {
             repeat_delay: data.get_rate().1,
             hardware_cursor: data.cursor_group().hardware_cursor() as _,
         });
+        if self.version >= CURSOR_ANIMATION_SINCE
+            && let Some(user) = data.cursor_group().active()
+            && let Some(cursor) = user.get()
+            && let Some(info) = cursor.animation_info()
+        {
+            self.client.event(CursorAnimation {
+                self_id: self.id,
+                current_frame: info.current_frame as _,
+                frame_delays_ns: &info.frame_delays_ns,
+            });
+        }
+        if self.version >= KEYBOARD_LAYOUT_SINCE {
+            let active = data.keyboard_group();
+            let map = data.keymap();
+            for index in 0..map.num_groups() {
+                self.client.event(KeyboardLayout {
+                    self_id: self.id,
+                    index,
+                    name: map.group_name(index),
+                    active: (index == active) as _,
+                });
+            }
+        }
     }
 
     fn send_error(&self, error: &str) {
@@ -182,6 +218,43 @@ impl JayInput {
                 middle_button_emulation_enabled: middle_button_emulation as _,
             });
         }
+        if self.version >= SCROLL_METHOD_SINCE
+            && let Some(scroll_method) = dev.scroll_method()
+        {
+            self.client.event(ScrollMethod {
+                self_id: self.id,
+                scroll_method: match scroll_method {
+                    InputDeviceScrollMethod::NoScroll => LIBINPUT_CONFIG_SCROLL_NO_SCROLL.0,
+                    InputDeviceScrollMethod::TwoFinger => LIBINPUT_CONFIG_SCROLL_2FG.0,
+                    InputDeviceScrollMethod::Edge => LIBINPUT_CONFIG_SCROLL_EDGE.0,
+                    InputDeviceScrollMethod::OnButtonDown => {
+                        LIBINPUT_CONFIG_SCROLL_ON_BUTTON_DOWN.0
+                    }
+                },
+            });
+        }
+        if self.version >= DISABLE_WHILE_TYPING_SINCE
+            && let Some(disable_while_typing) = dev.disable_while_typing_enabled()
+        {
+            self.client.event(DisableWhileTyping {
+                self_id: self.id,
+                disable_while_typing_enabled: disable_while_typing as _,
+            });
+        }
+        if self.version >= SEND_EVENTS_MODE_SINCE
+            && let Some(send_events_mode) = dev.send_events_mode()
+        {
+            self.client.event(SendEventsMode {
+                self_id: self.id,
+                send_events_mode: match send_events_mode {
+                    InputDeviceSendEventsMode::Enabled => LIBINPUT_CONFIG_SEND_EVENTS_ENABLED.0,
+                    InputDeviceSendEventsMode::Disabled => LIBINPUT_CONFIG_SEND_EVENTS_DISABLED.0,
+                    InputDeviceSendEventsMode::DisabledOnExternalMouse => {
+                        LIBINPUT_CONFIG_SEND_EVENTS_DISABLED_ON_EXTERNAL_MOUSE.0
+                    }
+                },
+            });
+        }
     }
 
     fn device(&self, id: u32) -> Result<Rc<DeviceHandlerData>, JayInputError> {
@@ -515,6 +588,80 @@ impl JayInputRequestHandler for JayInput {
             Ok(())
         })
     }
+
+    fn set_keyboard_layout(
+        &self,
+        req: SetKeyboardLayout,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        self.or_error(|| {
+            let seat = self.seat(req.seat)?;
+            let num_groups = seat.keymap().num_groups();
+            if req.layout >= num_groups {
+                return Err(JayInputError::UnknownKeyboardLayout(req.layout, num_groups));
+            }
+            seat.set_keyboard_group(req.layout);
+            Ok(())
+        })
+    }
+
+    fn set_scroll_method(&self, req: SetScrollMethod, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.or_error(|| {
+            let dev = self.device(req.id)?;
+            let method = match ConfigScrollMethod(req.method) {
+                LIBINPUT_CONFIG_SCROLL_NO_SCROLL => InputDeviceScrollMethod::NoScroll,
+                LIBINPUT_CONFIG_SCROLL_2FG => InputDeviceScrollMethod::TwoFinger,
+                LIBINPUT_CONFIG_SCROLL_EDGE => InputDeviceScrollMethod::Edge,
+                LIBINPUT_CONFIG_SCROLL_ON_BUTTON_DOWN => InputDeviceScrollMethod::OnButtonDown,
+                _ => return Err(JayInputError::UnknownScrollMethod(req.method)),
+            };
+            dev.device.set_scroll_method(method);
+            Ok(())
+        })
+    }
+
+    fn set_disable_while_typing(
+        &self,
+        req: SetDisableWhileTyping,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        self.or_error(|| {
+            let dev = self.device(req.id)?;
+            dev.device
+                .set_disable_while_typing_enabled(req.enabled != 0);
+            Ok(())
+        })
+    }
+
+    fn set_send_events_mode(
+        &self,
+        req: SetSendEventsMode,
+        _slf: &Rc<Self>,
+    ) -> Result<(), Self::Error> {
+        self.or_error(|| {
+            let dev = self.device(req.id)?;
+            let mode = match ConfigSendEventsMode(req.mode) {
+                LIBINPUT_CONFIG_SEND_EVENTS_ENABLED => InputDeviceSendEventsMode::Enabled,
+                LIBINPUT_CONFIG_SEND_EVENTS_DISABLED => InputDeviceSendEventsMode::Disabled,
+                LIBINPUT_CONFIG_SEND_EVENTS_DISABLED_ON_EXTERNAL_MOUSE => {
+                    InputDeviceSendEventsMode::DisabledOnExternalMouse
+                }
+                _ => return Err(JayInputError::UnknownSendEventsMode(req.mode)),
+            };
+            dev.device.set_send_events_mode(mode);
+            Ok(())
+        })
+    }
+
+    fn warp_pointer(&self, req: WarpPointer, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.or_error(|| {
+            let seat = self.seat(req.seat)?;
+            if !seat.warp_pointer(req.x, req.y) {
+                return Err(JayInputError::PointerLocked);
+            }
+            Ok(())
+        })
+    }
 }
 
 object_base! {
@@ -538,6 +685,10 @@ pub enum JayInputError {
     UnknownAccelerationProfile(i32),
     #[error("There is no click method with id {0}")]
     UnknownClickMethod(i32),
+    #[error("There is no scroll method with id {0}")]
+    UnknownScrollMethod(i32),
+    #[error("There is no send-events mode with id {0}")]
+    UnknownSendEventsMode(i32),
     #[error("Repeat rate must not be negative")]
     NegativeRepeatRate,
     #[error("Repeat delay must not be negative")]
@@ -548,5 +699,9 @@ pub enum JayInputError {
     ParseKeymap(#[from] KbvmError),
     #[error("Output is not connected")]
     OutputNotConnected,
+    #[error("Keyboard layout {0} does not exist, the keymap has {1} layouts")]
+    UnknownKeyboardLayout(u32, u32),
+    #[error("The pointer is locked")]
+    PointerLocked,
 }
 efrom!(JayInputError, ClientError);
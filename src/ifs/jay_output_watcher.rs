@@ -0,0 +1,76 @@
+use {
+    crate::{
+        client::{Client, ClientError},
+        ifs::wl_output::OutputId,
+        leaks::Tracker,
+        object::{Object, Version},
+        wire::{JayOutputWatcherId, jay_output_watcher::*},
+    },
+    std::rc::Rc,
+    thiserror::Error,
+};
+
+pub struct JayOutputWatcher {
+    pub id: JayOutputWatcherId,
+    pub client: Rc<Client>,
+    pub tracker: Tracker<Self>,
+}
+
+impl JayOutputWatcher {
+    pub fn send_connected(&self, connector: &str, output_id: &OutputId) {
+        self.client.event(Connected {
+            self_id: self.id,
+            connector,
+            manufacturer: &output_id.manufacturer,
+            model: &output_id.model,
+            serial_number: &output_id.serial_number,
+        });
+    }
+
+    pub fn send_disconnected(&self, connector: &str, output_id: &OutputId) {
+        self.client.event(Disconnected {
+            self_id: self.id,
+            connector,
+            manufacturer: &output_id.manufacturer,
+            model: &output_id.model,
+            serial_number: &output_id.serial_number,
+        });
+    }
+
+    fn remove_from_state(&self) {
+        self.client
+            .state
+            .output_watchers
+            .remove(&(self.client.id, self.id));
+    }
+}
+
+impl JayOutputWatcherRequestHandler for JayOutputWatcher {
+    type Error = JayOutputWatcherError;
+
+    fn destroy(&self, _req: Destroy, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.remove_from_state();
+        self.client.remove_obj(self)?;
+        Ok(())
+    }
+}
+
+object_base! {
+    self = JayOutputWatcher;
+    version = Version(1);
+}
+
+impl Object for JayOutputWatcher {
+    fn break_loops(&self) {
+        self.remove_from_state();
+    }
+}
+
+simple_add_obj!(JayOutputWatcher);
+
+#[derive(Debug, Error)]
+pub enum JayOutputWatcherError {
+    #[error(transparent)]
+    ClientError(Box<ClientError>),
+}
+efrom!(JayOutputWatcherError, ClientError);
@@ -0,0 +1,88 @@
+use {
+    crate::{
+        client::{Client, ClientError, ClientId, protocol_trace::DEFAULT_CAPACITY},
+        leaks::Tracker,
+        object::{Object, Version},
+        wire::{JayProtocolTraceId, jay_protocol_trace::*},
+    },
+    ahash::AHashSet,
+    std::{cell::RefCell, rc::Rc},
+    thiserror::Error,
+};
+
+pub struct JayProtocolTrace {
+    pub id: JayProtocolTraceId,
+    pub client: Rc<Client>,
+    pub tracker: Tracker<Self>,
+    pub version: Version,
+    pub interfaces: RefCell<AHashSet<String>>,
+    pub clients: RefCell<AHashSet<ClientId>>,
+}
+
+impl JayProtocolTraceRequestHandler for JayProtocolTrace {
+    type Error = JayProtocolTraceError;
+
+    fn destroy(&self, _req: Destroy, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.client.remove_obj(self)?;
+        Ok(())
+    }
+
+    fn add_interface(&self, req: AddInterface<'_>, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.interfaces.borrow_mut().insert(req.name.to_string());
+        Ok(())
+    }
+
+    fn add_client(&self, req: AddClient, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.clients.borrow_mut().insert(ClientId::from_raw(req.id));
+        Ok(())
+    }
+
+    fn start(&self, req: Start, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        let capacity = match req.capacity {
+            0 => DEFAULT_CAPACITY,
+            n => n as usize,
+        };
+        self.client.state.clients.protocol_trace.start(
+            self.interfaces.take(),
+            self.clients.take(),
+            capacity,
+        );
+        Ok(())
+    }
+
+    fn stop(&self, _req: Stop, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.client.state.clients.protocol_trace.stop();
+        Ok(())
+    }
+
+    fn clear(&self, _req: Clear, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.client.state.clients.protocol_trace.clear();
+        Ok(())
+    }
+
+    fn dump(&self, _req: Dump, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        for message in self.client.state.clients.protocol_trace.messages() {
+            self.client.event(Message {
+                self_id: self.id,
+                message: &message,
+            });
+        }
+        Ok(())
+    }
+}
+
+object_base! {
+    self = JayProtocolTrace;
+    version = self.version;
+}
+
+impl Object for JayProtocolTrace {}
+
+simple_add_obj!(JayProtocolTrace);
+
+#[derive(Debug, Error)]
+pub enum JayProtocolTraceError {
+    #[error(transparent)]
+    ClientError(Box<ClientError>),
+}
+efrom!(JayProtocolTraceError, ClientError);
@@ -34,6 +34,8 @@ const FORMAT_SINCE: Version = Version(8);
 const FLIP_MARGIN_SINCE: Version = Version(10);
 const COLORIMETRY_SINCE: Version = Version(15);
 const BRIGHTNESS_SINCE: Version = Version(16);
+const VRR_RANGE_SINCE: Version = Version(23);
+const GFX_INFO_SINCE: Version = Version(28);
 
 impl JayRandr {
     pub fn new(id: JayRandrId, client: &Rc<Client>, version: Version) -> Self {
@@ -66,6 +68,20 @@ impl JayRandr {
             gfx_api: data.dev.gtx_api().to_str(),
             render_device: data.dev.is_render_device() as _,
         });
+        if self.version >= GFX_INFO_SINCE {
+            let formats = data.dev.gfx_formats();
+            let mut formats: Vec<_> = formats.values().collect();
+            formats.sort_by_key(|f| f.format.name);
+            for format in formats {
+                for &modifier in &format.read_modifiers {
+                    self.client.event(DrmDeviceFormat {
+                        self_id: self.id,
+                        format: format.format.name,
+                        modifier,
+                    });
+                }
+            }
+        }
     }
 
     fn send_connector(&self, data: &ConnectorData) {
@@ -127,6 +143,24 @@ impl JayRandr {
                     hz,
                 });
             }
+            if self.version >= VRR_RANGE_SINCE
+                && let Some(range) = node.global.vrr_range
+            {
+                self.client.event(VrrRange {
+                    self_id: self.id,
+                    min_hz: range.min_hz,
+                    max_hz: range.max_hz,
+                });
+            }
+        }
+        if self.version >= GFX_INFO_SINCE {
+            let enabled = node.hardware_cursor.is_some()
+                && self.state.cursor_user_group_hardware_cursor.is_some()
+                && !node.hardware_cursor_fallback.get();
+            self.client.event(HardwareCursor {
+                self_id: self.id,
+                enabled: enabled as _,
+            });
         }
         if self.version >= TEARING_SINCE {
             self.client.event(TearingState {
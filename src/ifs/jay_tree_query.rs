@@ -54,6 +54,7 @@ enum Root {
     WorkspaceNode(Rc<Opt<WorkspaceNode>>),
     WorkspaceName(String),
     ToplevelId(ToplevelIdentifier),
+    Windows,
 }
 
 impl JayTreeQuery {
@@ -260,6 +261,16 @@ impl JayTreeQueryRequestHandler for JayTreeQuery {
                 Some(t) => t.node_visit(&mut Visitor(self)),
                 None => self.send_not_found(),
             },
+            Root::Windows => {
+                for tl in self.client.state.list_toplevels() {
+                    let data = tl.tl_data();
+                    self.send_toplevel(data);
+                    if let Some(ws) = data.workspace.get() {
+                        self.send_output_name(&ws.output.get().global.connector.name);
+                    }
+                    self.send_end();
+                }
+            }
         }
         self.send_done();
         Ok(())
@@ -318,6 +329,11 @@ impl JayTreeQueryRequestHandler for JayTreeQuery {
         *root = Some(Root::ToplevelId(id));
         Ok(())
     }
+
+    fn set_root_windows(&self, _req: SetRootWindows, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        *self.root.borrow_mut() = Some(Root::Windows);
+        Ok(())
+    }
 }
 
 struct Visitor<'a>(&'a JayTreeQuery);
@@ -5,13 +5,13 @@ use {
         object::{Object, Version},
         wire::{OrgKdeKwinServerDecorationId, org_kde_kwin_server_decoration::*},
     },
+    jay_config::DecorationMode,
     std::{cell::Cell, rc::Rc},
     thiserror::Error,
 };
 
 #[expect(dead_code)]
 const NONE: u32 = 0;
-#[expect(dead_code)]
 const CLIENT: u32 = 1;
 const SERVER: u32 = 2;
 
@@ -54,10 +54,11 @@ impl OrgKdeKwinServerDecorationRequestHandler for OrgKdeKwinServerDecoration {
         if req.mode > SERVER {
             return Err(OrgKdeKwinServerDecorationError::InvalidMode(req.mode));
         }
-        let mode = if self.requested.replace(true) {
-            req.mode
-        } else {
-            SERVER
+        let mode = match self.client.state.decoration_mode.get() {
+            DecorationMode::SERVER_SIDE => SERVER,
+            DecorationMode::CLIENT_SIDE => CLIENT,
+            _ if self.requested.replace(true) => req.mode,
+            _ => SERVER,
         };
         self.send_mode(mode);
         Ok(())
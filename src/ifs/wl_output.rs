@@ -2,7 +2,9 @@ mod removed_output;
 
 use {
     crate::{
-        backend::{self, BackendColorSpace, BackendLuminance, BackendTransferFunction},
+        backend::{
+            self, BackendColorSpace, BackendLuminance, BackendTransferFunction, BackendVrrRange,
+        },
         client::{Client, ClientError, ClientId},
         cmm::{
             cmm_description::ColorDescription,
@@ -80,6 +82,7 @@ pub struct WlOutputGlobal {
     pub color_spaces: Vec<BackendColorSpace>,
     pub primaries: Primaries,
     pub luminance: Option<BackendLuminance>,
+    pub vrr_range: Option<BackendVrrRange>,
     pub bindings: RefCell<AHashMap<ClientId, AHashMap<WlOutputId, Rc<WlOutput>>>>,
     pub destroyed: Cell<bool>,
     pub legacy_scale: Cell<u32>,
@@ -92,6 +95,7 @@ pub struct WlOutputGlobal {
     pub linear_color_description: CloneCell<Rc<ColorDescription>>,
     pub color_description_listeners:
         CopyHashMap<(ClientId, WpColorManagementOutputV1Id), Rc<WpColorManagementOutputV1>>,
+    pub zoom: Cell<f64>,
 }
 
 #[derive(Default)]
@@ -123,6 +127,8 @@ pub struct PersistentOutputState {
     pub vrr_cursor_hz: Cell<Option<f64>>,
     pub tearing_mode: Cell<&'static TearingMode>,
     pub brightness: Cell<Option<f64>>,
+    pub cursor_size: Cell<Option<u32>>,
+    pub max_fps: Cell<Option<f64>>,
 }
 
 impl Default for PersistentOutputState {
@@ -135,6 +141,8 @@ impl Default for PersistentOutputState {
             vrr_cursor_hz: Default::default(),
             tearing_mode: Cell::new(&TearingMode::Never),
             brightness: Default::default(),
+            cursor_size: Default::default(),
+            max_fps: Default::default(),
         }
     }
 }
@@ -183,6 +191,7 @@ impl WlOutputGlobal {
         color_spaces: Vec<BackendColorSpace>,
         primaries: Primaries,
         luminance: Option<BackendLuminance>,
+        vrr_range: Option<BackendVrrRange>,
     ) -> Self {
         let (x, y) = persistent_state.pos.get();
         let scale = persistent_state.scale.get();
@@ -209,6 +218,7 @@ impl WlOutputGlobal {
             color_spaces,
             primaries,
             luminance,
+            vrr_range,
             bindings: Default::default(),
             destroyed: Cell::new(false),
             legacy_scale: Cell::new(scale.round_up()),
@@ -220,6 +230,7 @@ impl WlOutputGlobal {
             color_description: CloneCell::new(state.color_manager.srgb_srgb().clone()),
             linear_color_description: CloneCell::new(state.color_manager.srgb_linear().clone()),
             color_description_listeners: Default::default(),
+            zoom: Cell::new(1.0),
         };
         global.update_damage_matrix();
         global.update_color_description();
@@ -326,7 +337,19 @@ impl WlOutputGlobal {
             .set(Rect::new_sized_unchecked(0, 0, mode.width, mode.height));
     }
 
+    pub fn zoomed(&self) -> bool {
+        self.zoom.get() > 1.0
+    }
+
     pub fn add_damage_area(&self, area: &Rect) {
+        if self.zoomed() {
+            // The zoomed view follows the cursor, so every change can move the whole
+            // output contents.
+            let damage = &mut *self.connector.damage.borrow_mut();
+            damage.clear();
+            damage.push(self.connector.damage_intersect.get());
+            return;
+        }
         let pos = self.pos.get();
         let rect = area.move_(-pos.x1(), -pos.y1());
         let mut rect = self.damage_matrix.get().apply(0, 0, rect);
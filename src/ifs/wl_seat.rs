@@ -33,7 +33,8 @@ use {
         ifs::{
             ext_idle_notification_v1::ExtIdleNotificationV1,
             ipc::{
-                self, DynDataSource, IpcError, IpcLocation,
+                self, DataSourceId, DynDataSource, IpcError, IpcLocation,
+                cached_data_source::SelectionCache,
                 data_control::{DataControlDeviceId, DynDataControlDevice},
                 offer_source_to_regular_client,
                 wl_data_device::{ClipboardIpc, WlDataDevice},
@@ -105,7 +106,13 @@ use {
         wire_ei::EiSeatId,
     },
     ahash::AHashMap,
-    jay_config::keyboard::syms::{KeySym, SYM_Escape},
+    jay_config::keyboard::{
+        mods::{CAPS, NUM, SHIFT},
+        syms::{
+            KeySym, SYM_Escape, SYM_Hyper_R, SYM_ISO_Last_Group_Lock, SYM_ISO_Level5_Lock,
+            SYM_ISO_Level5_Shift, SYM_ISO_Lock, SYM_Mode_switch, SYM_Num_Lock, SYM_Shift_L,
+        },
+    },
     kbvm::Keycode,
     smallvec::SmallVec,
     std::{
@@ -114,6 +121,7 @@ use {
         mem,
         ops::{Deref, DerefMut},
         rc::{Rc, Weak},
+        time::Duration,
     },
     thiserror::Error,
 };
@@ -136,6 +144,10 @@ pub const SEAT_NAME_SINCE: Version = Version(2);
 
 pub const PX_PER_SCROLL: f64 = 15.0;
 
+/// The distance in logical pixels that the pointer has to move before a cursor that was
+/// hidden while typing is shown again.
+const HIDE_CURSOR_WHILE_TYPING_THRESHOLD: f64 = 8.0;
+
 #[derive(Clone)]
 pub struct Dnd {
     pub seat: Rc<WlSeatGlobal>,
@@ -199,6 +211,7 @@ pub struct WlSeatGlobal {
     tree_changed_needs_layout: Cell<bool>,
     selection: CloneCell<Option<Rc<dyn DynDataSource>>>,
     selection_serial: Cell<u64>,
+    selection_cache: CloneCell<Option<Rc<SelectionCache>>>,
     primary_selection: CloneCell<Option<Rc<dyn DynDataSource>>>,
     primary_selection_serial: Cell<u64>,
     pointer_owner: PointerOwnerHolder,
@@ -219,6 +232,15 @@ pub struct WlSeatGlobal {
     input_method_grab: CloneCell<Option<Rc<ZwpInputMethodKeyboardGrabV2>>>,
     forward: Cell<bool>,
     focus_follows_mouse: Cell<bool>,
+    focus_follows_mouse_delay: Cell<u64>,
+    focus_follows_mouse_task: Cell<Option<SpawnedFuture<()>>>,
+    per_window_keyboard_layout: Cell<bool>,
+    cursor_follows_focus: Cell<bool>,
+    focus_by_pointer: Cell<bool>,
+    hide_cursor_while_typing: Cell<bool>,
+    typing_cursor_motion: Cell<Option<f64>>,
+    shortcut_repeat_requested: Cell<bool>,
+    shortcut_repeat: Cell<Option<(u32, SpawnedFuture<()>)>>,
     swipe_bindings: PerClientBindings<ZwpPointerGestureSwipeV1>,
     pinch_bindings: PerClientBindings<ZwpPointerGesturePinchV1>,
     hold_bindings: PerClientBindings<ZwpPointerGestureHoldV1>,
@@ -286,6 +308,7 @@ impl WlSeatGlobal {
             tree_changed_needs_layout: Default::default(),
             selection: Default::default(),
             selection_serial: Cell::new(0),
+            selection_cache: Default::default(),
             primary_selection: Default::default(),
             primary_selection_serial: Cell::new(0),
             pointer_owner: Default::default(),
@@ -307,6 +330,15 @@ impl WlSeatGlobal {
             input_method_grab: Default::default(),
             forward: Cell::new(false),
             focus_follows_mouse: Cell::new(true),
+            focus_follows_mouse_delay: Cell::new(0),
+            focus_follows_mouse_task: Cell::new(None),
+            per_window_keyboard_layout: Cell::new(false),
+            cursor_follows_focus: Cell::new(false),
+            focus_by_pointer: Cell::new(false),
+            hide_cursor_while_typing: Cell::new(false),
+            typing_cursor_motion: Cell::new(None),
+            shortcut_repeat_requested: Cell::new(false),
+            shortcut_repeat: Cell::new(None),
             swipe_bindings: Default::default(),
             pinch_bindings: Default::default(),
             hold_bindings: Default::default(),
@@ -439,6 +471,10 @@ impl WlSeatGlobal {
         self.cursor_user_group.latest_output()
     }
 
+    pub fn client_has_swipe_bindings(&self, client: ClientId) -> bool {
+        self.swipe_bindings.borrow().contains_key(&client)
+    }
+
     pub fn get_keyboard_node(&self) -> Rc<dyn Node> {
         self.keyboard_node.get()
     }
@@ -455,6 +491,32 @@ impl WlSeatGlobal {
         toplevel_set_workspace(&self.state, tl, ws);
     }
 
+    pub fn move_focused_to_output(&self, output: &Rc<OutputNode>) {
+        if output.is_dummy {
+            return;
+        }
+        let tl = match self.keyboard_node.get().node_toplevel() {
+            Some(tl) => tl,
+            _ => return,
+        };
+        let ws = output.ensure_workspace();
+        toplevel_set_workspace(&self.state, tl, &ws);
+    }
+
+    pub fn move_focused_to_adjacent_output(&self, direction: Direction) {
+        let tl = match self.keyboard_node.get().node_toplevel() {
+            Some(tl) => tl,
+            _ => return,
+        };
+        let output = match tl.node_output() {
+            Some(output) => output,
+            _ => self.get_output(),
+        };
+        if let Some(output) = self.state.output_in_direction(&output, direction) {
+            self.move_focused_to_output(&output);
+        }
+    }
+
     pub fn mark_last_active(self: &Rc<Self>) {
         let link = &mut *self.queue_link.borrow_mut();
         if let Some(link) = link {
@@ -1068,6 +1130,10 @@ impl WlSeatGlobal {
         self.pointer_owner.cancel_dnd(self);
     }
 
+    pub fn update_dnd_cursor(self: &Rc<Self>) {
+        self.pointer_owner.update_dnd_cursor(self);
+    }
+
     pub fn unset_selection(self: &Rc<Self>) {
         let _ = self.set_wl_data_source_selection(None, None);
     }
@@ -1096,13 +1162,54 @@ impl WlSeatGlobal {
             &self.selection,
             selection,
             IpcLocation::Clipboard,
-        )
+        )?;
+        self.update_selection_cache();
+        Ok(())
     }
 
     pub fn get_selection(&self) -> Option<Rc<dyn DynDataSource>> {
         self.selection.get()
     }
 
+    fn update_selection_cache(&self) {
+        let selection = self.selection.get();
+        if let Some(cache) = self.selection_cache.get() {
+            if let Some(selection) = &selection
+                && selection.source_data().id == cache.source_id
+            {
+                return;
+            }
+            self.selection_cache.take();
+            cache.cancel();
+        }
+        if !self.state.clipboard_persistence.get() {
+            return;
+        }
+        if let Some(selection) = &selection
+            && !selection.is_cached()
+        {
+            let cache = SelectionCache::new(&self.state, selection);
+            self.selection_cache.set(Some(cache));
+        }
+    }
+
+    /// Replaces the selection with a copy owned by the compositor if `source` is the
+    /// current selection and its contents have been cached.
+    pub fn persist_selection(self: &Rc<Self>, source: DataSourceId) -> bool {
+        let Some(cache) = self.selection_cache.get() else {
+            return false;
+        };
+        if cache.source_id != source {
+            return false;
+        }
+        self.selection_cache.take();
+        cache.cancel();
+        let Some(src) = cache.create_source() else {
+            return false;
+        };
+        self.set_selection(Some(src)).is_ok()
+    }
+
     pub fn may_modify_selection(&self, client: &Rc<Client>, serial: u64) -> bool {
         if serial < self.selection_serial.get() {
             return false;
@@ -1182,6 +1289,9 @@ impl WlSeatGlobal {
         self.data_control_devices.clear();
         self.cursor_user_group.detach();
         self.selection.set(None);
+        if let Some(cache) = self.selection_cache.take() {
+            cache.cancel();
+        }
         self.primary_selection.set(None);
         self.pointer_owner.clear();
         self.kb_owner.clear();
@@ -1189,6 +1299,8 @@ impl WlSeatGlobal {
         *self.dropped_dnd.borrow_mut() = None;
         self.queue_link.take();
         self.tree_changed_handler.set(None);
+        self.focus_follows_mouse_task.take();
+        self.shortcut_repeat.take();
         self.constraint.take();
         self.text_inputs.borrow_mut().clear();
         self.text_input.take();
@@ -1289,6 +1401,62 @@ impl WlSeatGlobal {
 
     pub fn set_focus_follows_mouse(&self, focus_follows_mouse: bool) {
         self.focus_follows_mouse.set(focus_follows_mouse);
+        if !focus_follows_mouse {
+            self.focus_follows_mouse_task.take();
+        }
+    }
+
+    pub fn set_per_window_keyboard_layout(&self, enabled: bool) {
+        self.per_window_keyboard_layout.set(enabled);
+    }
+
+    pub fn set_cursor_follows_focus(&self, enabled: bool) {
+        self.cursor_follows_focus.set(enabled);
+    }
+
+    pub fn set_hide_cursor_while_typing(&self, enabled: bool) {
+        self.hide_cursor_while_typing.set(enabled);
+        if !enabled {
+            self.show_typing_cursor();
+        }
+    }
+
+    fn hide_cursor_for_typing(&self, mods: u32) {
+        if !self.hide_cursor_while_typing.get() {
+            return;
+        }
+        if mods & !(SHIFT.0 | CAPS.0 | NUM.0) != 0 {
+            return;
+        }
+        self.typing_cursor_motion.set(Some(0.0));
+        self.cursor_user_group.set_hidden(true);
+    }
+
+    fn typing_cursor_moved(&self, dx: Fixed, dy: Fixed) {
+        let Some(distance) = self.typing_cursor_motion.get() else {
+            return;
+        };
+        let distance = distance + dx.to_f64().abs() + dy.to_f64().abs();
+        if distance >= HIDE_CURSOR_WHILE_TYPING_THRESHOLD {
+            self.show_typing_cursor();
+        } else {
+            self.typing_cursor_motion.set(Some(distance));
+        }
+    }
+
+    fn show_typing_cursor(&self) {
+        if self.typing_cursor_motion.take().is_some() {
+            self.cursor_user_group.set_hidden(false);
+        }
+    }
+
+    pub fn repeat_shortcut(&self) {
+        self.shortcut_repeat_requested.set(true);
+    }
+
+    pub fn set_focus_follows_mouse_delay(&self, delay: Duration) {
+        self.focus_follows_mouse_delay.set(delay.as_millis() as u64);
+        self.focus_follows_mouse_task.take();
     }
 
     pub fn set_window_management_enabled(self: &Rc<Self>, enabled: bool) {
@@ -1603,6 +1771,15 @@ pub fn collect_kb_foci(node: Rc<dyn Node>) -> SmallVec<[Rc<WlSeatGlobal>; 3]> {
     res
 }
 
+/// Returns whether the keysym belongs to a modifier key, like `xkb_keysym_is_modifier`.
+fn is_modifier_keysym(sym: u32) -> bool {
+    (SYM_Shift_L.0..=SYM_Hyper_R.0).contains(&sym)
+        || (SYM_ISO_Lock.0..=SYM_ISO_Last_Group_Lock.0).contains(&sym)
+        || (SYM_ISO_Level5_Shift.0..=SYM_ISO_Level5_Lock.0).contains(&sym)
+        || sym == SYM_Mode_switch.0
+        || sym == SYM_Num_Lock.0
+}
+
 impl DeviceHandlerData {
     pub fn set_seat(&self, seat: Option<Rc<WlSeatGlobal>>) {
         if let Some(new) = &seat {
@@ -18,6 +18,7 @@ use {
             },
             wl_seat::{
                 CHANGE_CURSOR_MOVED, CHANGE_TREE, Dnd, MarkMode, SeatId, WlSeat, WlSeatGlobal,
+                is_modifier_keysym,
                 tablet::{TabletPad, TabletPadId, TabletTool, TabletToolId},
                 text_input::TextDisconnectReason,
                 wl_keyboard::WlKeyboard,
@@ -41,6 +42,7 @@ use {
         tree::{Direction, Node, ToplevelNode},
         utils::{
             bitflags::BitflagsExt,
+            errorfmt::ErrorFmt,
             hash_map_ext::HashMapExt,
             linkedlist::{LinkedNode, NodeRef},
             smallmap::{SmallMap, SmallMapMut},
@@ -584,6 +586,8 @@ impl WlSeatGlobal {
         self.for_each_ei_seat(|ei_seat| {
             ei_seat.handle_motion_abs(time_usec, x, y);
         });
+        let (x_old, y_old) = self.pointer_cursor.position();
+        self.typing_cursor_moved(x - x_old, y - y_old);
         let (x, y) = self.set_pointer_cursor_position(x, y);
         if let Some(c) = self.constraint.get()
             && (c.ty == ConstraintType::Lock || !c.contains(x.round_down(), y.round_down()))
@@ -596,6 +600,30 @@ impl WlSeatGlobal {
         self.cursor_moved(time_usec, defer);
     }
 
+    /// Moves the pointer to the given position in the global compositor space.
+    ///
+    /// Unlike `motion_event_abs`, this is not treated as input from a device. Returns
+    /// false and does nothing if the pointer is locked.
+    pub fn warp_pointer(self: &Rc<Self>, x: Fixed, y: Fixed) -> bool {
+        if let Some(c) = self.constraint.get()
+            && c.ty == ConstraintType::Lock
+        {
+            return false;
+        }
+        let (x, y) = self.set_pointer_cursor_position(x, y);
+        if let Some(c) = self.constraint.get() {
+            let surface_pos = c.surface.buffer_abs_pos.get();
+            let (x_rel, y_rel) = (x - surface_pos.x1(), y - surface_pos.y1());
+            let contained = surface_pos.contains(x.round_down(), y.round_down())
+                && c.contains(x_rel.round_down(), y_rel.round_down());
+            if !contained {
+                c.deactivate(false);
+            }
+        }
+        self.cursor_moved(self.state.now_usec(), false);
+        true
+    }
+
     pub fn motion_event(
         self: &Rc<Self>,
         time_usec: u64,
@@ -607,6 +635,7 @@ impl WlSeatGlobal {
         self.for_each_ei_seat(|ei_seat| {
             ei_seat.handle_motion(time_usec, dx, dy);
         });
+        self.typing_cursor_moved(dx, dy);
         self.pointer_owner.relative_motion(
             self,
             time_usec,
@@ -658,7 +687,37 @@ impl WlSeatGlobal {
         self.state.for_each_seat_tester(|t| {
             t.send_button(self.id, time_usec, button, state);
         });
-        self.pointer_owner.button(self, time_usec, button, state);
+        self.show_typing_cursor();
+        self.with_focus_by_pointer(|| {
+            self.pointer_owner.button(self, time_usec, button, state);
+        });
+    }
+
+    fn with_focus_by_pointer(&self, f: impl FnOnce()) {
+        let prev = self.focus_by_pointer.replace(true);
+        f();
+        self.focus_by_pointer.set(prev);
+    }
+
+    /// Moves the pointer to the center of the newly focused toplevel if the focus was
+    /// not changed by the pointer.
+    pub(super) fn cursor_follow_focus(self: &Rc<Self>, node: &Rc<dyn Node>) {
+        if !self.cursor_follows_focus.get() || self.focus_by_pointer.get() {
+            return;
+        }
+        let Some(tl) = node.clone().node_toplevel() else {
+            return;
+        };
+        if !tl.node_visible() {
+            return;
+        }
+        let rect = tl.node_absolute_position();
+        let (x, y) = self.pointer_cursor.position_int();
+        if rect.contains(x, y) {
+            return;
+        }
+        let (x, y) = rect.center();
+        self.warp_pointer(Fixed::from_int(x), Fixed::from_int(y));
     }
 
     pub fn axis_source(&self, axis_source: AxisSource) {
@@ -875,6 +934,12 @@ impl WlSeatGlobal {
                 Event::KeyUp(kc) => (KeyState::Released, kc),
                 _ => continue,
             };
+            if let Some((repeat_kc, future)) = self.shortcut_repeat.take()
+                && key_state == KeyState::Released
+                && repeat_kc != kc.to_evdev()
+            {
+                self.shortcut_repeat.set(Some((repeat_kc, future)));
+            }
             let update_pressed_keys = |kbvm_state: &mut KbvmState| {
                 let pk = &mut kbvm_state.kb_state.pressed_keys;
                 match key_state {
@@ -900,12 +965,14 @@ impl WlSeatGlobal {
                 continue;
             }
             shortcuts.clear();
+            let mut is_modifier = false;
             {
                 let mut mods = kbvm_state.kb_state.mods.mods.0 & !(CAPS.0 | NUM.0);
                 if key_state == KeyState::Released {
                     mods |= RELEASE.0;
                 }
                 let scs = &*self.shortcuts.borrow();
+                let inhibited = self.shortcuts_inhibited();
                 let keysyms = kbvm_state.map.lookup_table.lookup(
                     kbvm_state.kb_state.mods.group,
                     ModifierMask::default(),
@@ -914,10 +981,12 @@ impl WlSeatGlobal {
                 let mut revert_pointer_to_default = false;
                 for props in keysyms {
                     let sym = props.keysym().0;
+                    is_modifier |= is_modifier_keysym(sym);
                     if sym == self.revert_key.get().0 && mods == 0 {
                         revert_pointer_to_default = true;
                     }
                     if !self.state.lock.locked.get()
+                        && !inhibited
                         && let Some(key_mods) = scs.get(&sym)
                     {
                         for (key_mods, mask) in key_mods {
@@ -944,9 +1013,13 @@ impl WlSeatGlobal {
                 self.forward.set(key_state == KeyState::Released);
                 if let Some(config) = self.state.config.get() {
                     drop(kbvm_state);
+                    self.shortcut_repeat_requested.set(false);
                     for shortcut in &shortcuts {
                         config.invoke_shortcut(self.id(), shortcut);
                     }
+                    if self.shortcut_repeat_requested.take() && key_state == KeyState::Pressed {
+                        self.start_shortcut_repeat(kc.to_evdev(), &shortcuts);
+                    }
                     kbvm_state = kbvm_state_rc.borrow_mut();
                     if kbvm_state.kb_state.id != self.latest_kb_state_id.get() {
                         update_pressed_keys(&mut kbvm_state);
@@ -959,6 +1032,9 @@ impl WlSeatGlobal {
                     continue;
                 }
             }
+            if key_state == KeyState::Pressed && !is_modifier {
+                self.hide_cursor_for_typing(kbvm_state.kb_state.mods.mods.0);
+            }
             self.send_components(&mut components_changed, &kbvm_state);
             match self.input_method_grab.get() {
                 Some(g) => g.on_key(time_usec, kc.to_evdev(), key_state, &kbvm_state.kb_state),
@@ -978,6 +1054,55 @@ impl WlSeatGlobal {
         self.send_components(&mut components_changed, &kbvm_state);
     }
 
+    fn start_shortcut_repeat(self: &Rc<Self>, kc: u32, shortcuts: &[InvokedShortcut]) {
+        let (rate, delay) = self.repeat_rate.get();
+        if rate <= 0 {
+            return;
+        }
+        let future = self.state.eng.spawn(
+            "shortcut repeat",
+            self.clone()
+                .repeat_shortcuts(shortcuts.to_vec(), delay.max(0) as u64, rate as u64),
+        );
+        self.shortcut_repeat.set(Some((kc, future)));
+    }
+
+    async fn repeat_shortcuts(
+        self: Rc<Self>,
+        shortcuts: Vec<InvokedShortcut>,
+        delay: u64,
+        rate: u64,
+    ) {
+        let interval = (1000 / rate).max(1);
+        let mut timeout = delay;
+        loop {
+            if let Err(e) = self.state.wheel.timeout(timeout).await {
+                log::error!("Could not wait for the repeat delay: {}", ErrorFmt(e));
+                return;
+            }
+            if self.state.lock.locked.get() || self.shortcuts_inhibited() {
+                return;
+            }
+            let Some(config) = self.state.config.get() else {
+                return;
+            };
+            for shortcut in &shortcuts {
+                config.invoke_shortcut(self.id(), shortcut);
+            }
+            timeout = interval;
+        }
+    }
+
+    fn shortcuts_inhibited(&self) -> bool {
+        let Some(surface) = self.keyboard_node.get().node_into_surface() else {
+            return false;
+        };
+        surface
+            .shortcuts_inhibitors
+            .get(&self.id())
+            .is_some_and(|i| i.active.get())
+    }
+
     pub fn create_mark_interactive(&self) {
         self.mark_mode.set(Some(MarkMode::Mark));
     }
@@ -1049,6 +1174,36 @@ impl WlSeatGlobal {
         }
     }
 
+    pub fn keyboard_group(&self) -> u32 {
+        self.seat_kb_state.get().borrow().kb_state.mods.group.0
+    }
+
+    pub fn set_keyboard_group(&self, group: u32) {
+        let kbvm_state = self.seat_kb_state.get();
+        {
+            let mods = &mut kbvm_state.borrow_mut().kb_state.mods;
+            mods.group_locked.0 = group;
+            mods.update_effective();
+        }
+        let kbvm_state = kbvm_state.borrow();
+        self.latest_kb_state_id.set(kbvm_state.kb_state.id);
+        self.latest_kb_state.set(self.seat_kb_state.get());
+        self.send_components(&mut true, &kbvm_state);
+    }
+
+    pub fn cycle_keyboard_group(&self, forward: bool) {
+        let num_groups = self.keymap().num_groups();
+        if num_groups <= 1 {
+            return;
+        }
+        let group = self.keyboard_group() % num_groups;
+        let group = match forward {
+            true => (group + 1) % num_groups,
+            false => (group + num_groups - 1) % num_groups,
+        };
+        self.set_keyboard_group(group);
+    }
+
     pub(super) fn for_each_ei_seat(&self, mut f: impl FnMut(&Rc<EiSeat>)) {
         if self.ei_seats.is_not_empty() {
             for ei_seat in self.ei_seats.lock().values() {
@@ -1293,6 +1448,10 @@ impl WlSeatGlobal {
             KeyState::Released => (wl_pointer::RELEASED, false),
             KeyState::Pressed => {
                 surface.client.focus_stealing_serial.set(Some(serial));
+                surface
+                    .client
+                    .last_interaction_msec
+                    .set(Some(self.state.now_msec()));
                 (wl_pointer::PRESSED, true)
             }
         };
@@ -1392,7 +1551,32 @@ impl WlSeatGlobal {
             && self.changes.get().contains(CHANGE_CURSOR_MOVED)
             && self.focus_follows_mouse.get()
         {
-            self.focus_toplevel(n);
+            let delay = self.focus_follows_mouse_delay.get();
+            if delay == 0 {
+                self.focus_follows_mouse_task.take();
+                self.with_focus_by_pointer(|| self.focus_toplevel(n));
+            } else {
+                let future = self.state.eng.spawn(
+                    "focus follows mouse",
+                    self.clone().focus_toplevel_after(n, delay),
+                );
+                self.focus_follows_mouse_task.set(Some(future));
+            }
+        }
+    }
+
+    async fn focus_toplevel_after(self: Rc<Self>, n: Rc<dyn ToplevelNode>, delay_ms: u64) {
+        if let Err(e) = self.state.wheel.timeout(delay_ms).await {
+            log::error!("Could not wait for the focus delay: {}", ErrorFmt(e));
+            return;
+        }
+        let still_hovered = self
+            .pointer_stack
+            .borrow()
+            .iter()
+            .any(|node| node.node_id() == n.node_id());
+        if still_hovered && n.tl_accepts_keyboard_focus() {
+            self.with_focus_by_pointer(|| self.focus_toplevel(n));
         }
     }
 
@@ -1441,6 +1625,10 @@ impl WlSeatGlobal {
             }
         }
 
+        if let Some(inhibitor) = surface.shortcuts_inhibitors.get(&self.id()) {
+            inhibitor.deactivate();
+        }
+
         let serial = surface.client.next_serial();
         self.surface_kb_event(Version::ALL, surface, |k| k.send_leave(serial, surface.id))
     }
@@ -1473,6 +1661,10 @@ impl WlSeatGlobal {
                 ti.send_done();
             }
         }
+
+        if let Some(inhibitor) = surface.shortcuts_inhibitors.get(&self.id()) {
+            inhibitor.activate();
+        }
     }
 }
 
@@ -1488,6 +1680,12 @@ impl WlSeatGlobal {
     ) {
         let serial = surface.client.next_serial();
         let time = (time_usec / 1000) as _;
+        if state == KeyState::Pressed {
+            surface
+                .client
+                .last_interaction_msec
+                .set(Some(self.state.now_msec()));
+        }
         self.surface_kb_event(Version::ALL, surface, |k| {
             k.on_key(serial, time, key, state, surface.id, kb_state);
         });
@@ -1516,6 +1714,10 @@ impl WlSeatGlobal {
     ) {
         let serial = surface.client.next_serial();
         surface.client.focus_stealing_serial.set(Some(serial));
+        surface
+            .client
+            .last_interaction_msec
+            .set(Some(self.state.now_msec()));
         let time = (time_usec / 1000) as _;
         self.surface_touch_event(Version::ALL, surface, |t| {
             t.send_down(serial, time, surface.id, id, x, y)
@@ -1,5 +1,6 @@
 use {
     crate::{fixed::Fixed, ifs::wl_seat::WlSeatGlobal, tree::Node, utils::clonecell::CloneCell},
+    jay_config::input::SwipeEvent,
     std::rc::Rc,
 };
 
@@ -143,7 +144,17 @@ impl GestureOwner for NoGesture {
     }
 
     fn swipe_begin(&self, seat: &Rc<WlSeatGlobal>, time_usec: u64, finger_count: u32) {
-        let Some(node) = seat.pointer_node() else {
+        let node = seat.pointer_node();
+        let consumed = match node.clone().and_then(|n| n.node_into_surface()) {
+            Some(surface) => seat.client_has_swipe_bindings(surface.client.id),
+            None => false,
+        };
+        if !consumed && let Some(config) = seat.state.config.get() {
+            config.swipe_event(seat.id, SwipeEvent::Begin { finger_count });
+            seat.gesture_owner.owner.set(Rc::new(ConfigSwipeGesture));
+            return;
+        }
+        let Some(node) = node else {
             return;
         };
         node.node_seat_state().gesture_begin(seat);
@@ -190,6 +201,31 @@ impl GestureOwner for SwipeGesture {
     }
 }
 
+struct ConfigSwipeGesture;
+
+impl GestureOwner for ConfigSwipeGesture {
+    fn revert_to_default(&self, seat: &Rc<WlSeatGlobal>) {
+        self.swipe_end(seat, seat.state.now_usec(), true);
+    }
+
+    fn swipe_update(&self, seat: &Rc<WlSeatGlobal>, _time_usec: u64, dx: Fixed, dy: Fixed) {
+        if let Some(config) = seat.state.config.get() {
+            let event = SwipeEvent::Update {
+                dx: dx.to_f64(),
+                dy: dy.to_f64(),
+            };
+            config.swipe_event(seat.id, event);
+        }
+    }
+
+    fn swipe_end(&self, seat: &Rc<WlSeatGlobal>, _time_usec: u64, cancelled: bool) {
+        if let Some(config) = seat.state.config.get() {
+            config.swipe_event(seat.id, SwipeEvent::End { cancelled });
+        }
+        seat.gesture_owner.set_default_owner();
+    }
+}
+
 struct PinchGesture {
     node: Rc<dyn Node>,
 }
@@ -96,6 +96,19 @@ impl KbOwner for DefaultKbOwner {
         seat.keyboard_node_serial.set(serial);
         seat.keyboard_node.set(node.clone());
         seat.tablet_on_keyboard_node_change();
+        if seat.per_window_keyboard_layout.get() {
+            if let Some(tl) = old.node_toplevel() {
+                tl.tl_data().keyboard_group.set(Some(seat.keyboard_group()));
+            }
+            if let Some(tl) = node.node_toplevel()
+                && let Some(group) = tl.tl_data().keyboard_group.get()
+                && group < seat.keymap().num_groups()
+                && group != seat.keyboard_group()
+            {
+                seat.set_keyboard_group(group);
+            }
+        }
+        seat.cursor_follow_focus(&node);
     }
 }
 
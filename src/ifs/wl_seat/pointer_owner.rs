@@ -166,6 +166,10 @@ impl PointerOwnerHolder {
         self.owner.get().remove_dnd_icon()
     }
 
+    pub fn update_dnd_cursor(&self, seat: &Rc<WlSeatGlobal>) {
+        self.owner.get().update_dnd_cursor(seat)
+    }
+
     pub fn clear(&self) {
         self.owner.set(self.default.clone());
     }
@@ -262,6 +266,9 @@ trait PointerOwner {
     fn remove_dnd_icon(&self) {
         // nothing
     }
+    fn update_dnd_cursor(&self, seat: &Rc<WlSeatGlobal>) {
+        let _ = seat;
+    }
     fn enable_window_management(&self, seat: &Rc<WlSeatGlobal>) {
         let _ = seat;
     }
@@ -297,6 +304,7 @@ struct DndPointerOwner {
     icon: CloneCell<Option<Rc<DndIcon>>>,
     pos_x: Cell<Fixed>,
     pos_y: Cell<Fixed>,
+    cursor: Cell<Option<KnownCursor>>,
 }
 
 #[derive(Copy, Clone)]
@@ -570,6 +578,20 @@ impl PointerOwner for DndPointerOwner {
         }
         self.pos_x.set(x);
         self.pos_y.set(y);
+        self.update_dnd_cursor(seat);
+    }
+
+    fn update_dnd_cursor(&self, seat: &Rc<WlSeatGlobal>) {
+        let Some(src) = &self.dnd.src else {
+            return;
+        };
+        if src.toplevel_drag.is_some() {
+            return;
+        }
+        let cursor = src.dnd_cursor();
+        if self.cursor.replace(Some(cursor)) != Some(cursor) {
+            seat.pointer_cursor.set_known(cursor);
+        }
     }
 
     fn cancel_dnd(&self, seat: &Rc<WlSeatGlobal>) {
@@ -761,6 +783,7 @@ impl SimplePointerOwnerUsecase for DefaultPointerUsecase {
             icon: CloneCell::new(icon),
             pos_x: Cell::new(Fixed::from_int(0)),
             pos_y: Cell::new(Fixed::from_int(0)),
+            cursor: Cell::new(None),
         });
         self.prepare_new_usecase(grab, seat);
         // {
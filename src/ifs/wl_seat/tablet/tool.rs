@@ -212,6 +212,9 @@ impl TabletTool {
         });
         if state == ToolButtonState::Pressed {
             n.client.focus_stealing_serial.set(Some(serial.get()));
+            n.client
+                .last_interaction_msec
+                .set(Some(self.tablet.seat.state.now_msec()));
             if let Some(node) = n.get_focus_node() {
                 self.tablet.seat.focus_node_with_serial(node, serial.get());
             }
@@ -262,6 +265,9 @@ impl TabletTool {
             && changes.down == Some(true)
         {
             n.client.focus_stealing_serial.set(Some(serial.get()));
+            n.client
+                .last_interaction_msec
+                .set(Some(self.tablet.seat.state.now_msec()));
             if let Some(node) = n.get_focus_node() {
                 self.tablet.seat.focus_node_with_serial(node, serial.get());
             }
@@ -31,16 +31,20 @@ impl WlShmPool {
         len: usize,
         version: Version,
     ) -> Result<Self, WlShmPoolError> {
+        check_shm_limit(client, len)?;
         Ok(Self {
             id,
             client: client.clone(),
-            mem: CloneCell::new(Rc::new(ClientMem::new(
-                &fd,
-                len,
-                false,
-                Some(client),
-                Some(&client.state.cpu_worker),
-            )?)),
+            mem: CloneCell::new(Rc::new(
+                ClientMem::new(
+                    &fd,
+                    len,
+                    false,
+                    Some(client),
+                    Some(&client.state.cpu_worker),
+                )?
+                .with_accounting(&client.shm_size),
+            )),
             fd,
             tracker: Default::default(),
             version,
@@ -48,6 +52,18 @@ impl WlShmPool {
     }
 }
 
+fn check_shm_limit(client: &Client, additional: usize) -> Result<(), WlShmPoolError> {
+    if client.is_xwayland {
+        return Ok(());
+    }
+    if let Some(limit) = client.state.client_shm_limit.get()
+        && client.shm_size.get().saturating_add(additional as u64) > limit
+    {
+        return Err(WlShmPoolError::ShmLimitExceeded(limit));
+    }
+    Ok(())
+}
+
 impl WlShmPoolRequestHandler for WlShmPool {
     type Error = WlShmPoolError;
 
@@ -84,16 +100,30 @@ impl WlShmPoolRequestHandler for WlShmPool {
         if req.size < 0 {
             return Err(WlShmPoolError::NegativeSize);
         }
-        if (req.size as usize) < self.mem.get().len() {
+        let mem = self.mem.get();
+        let old_size = mem.len();
+        let new_size = req.size as usize;
+        if new_size < old_size {
             return Err(WlShmPoolError::CannotShrink);
         }
-        self.mem.set(Rc::new(ClientMem::new(
-            &self.fd,
-            req.size as usize,
-            false,
-            Some(&self.client),
-            Some(&self.client.state.cpu_worker),
-        )?));
+        // Buffers created from the pool keep the old mapping alive. Otherwise it is
+        // released below and must not count against the limit.
+        let additional = match Rc::strong_count(&mem) {
+            2 => new_size - old_size,
+            _ => new_size,
+        };
+        drop(mem);
+        check_shm_limit(&self.client, additional)?;
+        self.mem.set(Rc::new(
+            ClientMem::new(
+                &self.fd,
+                new_size,
+                false,
+                Some(&self.client),
+                Some(&self.client.state.cpu_worker),
+            )?
+            .with_accounting(&self.client.shm_size),
+        ));
         Ok(())
     }
 }
@@ -123,6 +153,8 @@ pub enum WlShmPoolError {
     NegativeParameters,
     #[error(transparent)]
     WlBufferError(Box<WlBufferError>),
+    #[error("The client exceeded the limit of {0} bytes of shared memory")]
+    ShmLimitExceeded(u64),
 }
 efrom!(WlShmPoolError, ClientError);
 efrom!(WlShmPoolError, ClientMemError);
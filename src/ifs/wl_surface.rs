@@ -18,6 +18,7 @@ pub mod xwayland_shell_v1;
 pub mod zwlr_layer_surface_v1;
 pub mod zwp_idle_inhibitor_v1;
 pub mod zwp_input_popup_surface_v2;
+pub mod zwp_keyboard_shortcuts_inhibitor_v1;
 
 use {
     crate::{
@@ -75,9 +76,10 @@ use {
         rect::{DamageQueue, Rect, Region},
         renderer::Renderer,
         tree::{
-            BeforeLatchListener, BeforeLatchResult, ContainerNode, FindTreeResult, FoundNode,
-            LatchListener, Node, NodeId, NodeLayerLink, NodeLocation, NodeVisitor, NodeVisitorBase,
-            OutputNode, PlaceholderNode, PresentationListener, ToplevelNode, VblankListener,
+            BeforeLatchListener, BeforeLatchResult, ContainerNode, Direction, FindTreeResult,
+            FoundNode, LatchListener, Node, NodeId, NodeLayerLink, NodeLocation, NodeVisitor,
+            NodeVisitorBase, OutputNode, PlaceholderNode, PresentationListener, ToplevelNode,
+            VblankListener,
         },
         utils::{
             cell_ext::CellExt, clonecell::CloneCell, copyhashmap::CopyHashMap,
@@ -109,6 +111,7 @@ use {
     thiserror::Error,
     wp_color_management_surface_v1::WpColorManagementSurfaceV1,
     zwp_idle_inhibitor_v1::ZwpIdleInhibitorV1,
+    zwp_keyboard_shortcuts_inhibitor_v1::ZwpKeyboardShortcutsInhibitorV1,
 };
 
 #[expect(dead_code)]
@@ -317,6 +320,7 @@ pub struct WlSurface {
     dnd_icons: SmallMap<SeatId, Rc<DndIcon>, 1>,
     pub tracker: Tracker<Self>,
     idle_inhibitors: SmallMap<ZwpIdleInhibitorV1Id, Rc<ZwpIdleInhibitorV1>, 1>,
+    pub shortcuts_inhibitors: SmallMap<SeatId, Rc<ZwpKeyboardShortcutsInhibitorV1>, 1>,
     viewporter: CloneCell<Option<Rc<WpViewport>>>,
     output: CloneCell<Rc<OutputNode>>,
     location: Cell<NodeLocation>,
@@ -669,6 +673,7 @@ impl WlSurface {
             dnd_icons: Default::default(),
             tracker: Default::default(),
             idle_inhibitors: Default::default(),
+            shortcuts_inhibitors: Default::default(),
             viewporter: Default::default(),
             location: Cell::new(NodeLocation::Output(dummy_output.id)),
             output: CloneCell::new(dummy_output),
@@ -1030,6 +1035,7 @@ impl WlSurfaceRequestHandler for WlSurface {
         self.toplevel.set(None);
         self.client.remove_obj(self)?;
         self.idle_inhibitors.clear();
+        self.shortcuts_inhibitors.clear();
         self.constraints.take();
         self.destroyed.set(true);
         Ok(())
@@ -1665,6 +1671,21 @@ impl WlSurface {
         self.pending.borrow_mut().content_type = Some(content_type);
     }
 
+    pub fn activate(&self) {
+        let Some(tl) = self.toplevel.get() else {
+            return;
+        };
+        if !tl.node_visible() {
+            tl.clone().node_make_visible();
+            if !tl.node_visible() {
+                return;
+            }
+        }
+        if let Some(seat) = self.client.state.seat_queue.last() {
+            tl.node_do_focus(&seat, Direction::Unspecified);
+        }
+    }
+
     pub fn request_activation(&self) {
         if let Some(tl) = self.toplevel.get() {
             tl.tl_data().request_attention(&*tl);
@@ -1677,6 +1698,21 @@ impl WlSurface {
         }
     }
 
+    /// Returns the scanout feedback of the output if this surface is the scanout
+    /// surface of a fullscreen window.
+    pub fn scanout_feedback(&self) -> Option<Rc<DrmFeedback>> {
+        let output = self.get_output();
+        for ws in output.workspaces.iter() {
+            if let Some(node) = ws.fullscreen.get()
+                && let Some(surface) = node.tl_scanout_surface()
+                && surface.id == self.id
+            {
+                return output.global.connector.connector.drm_feedback();
+            }
+        }
+        None
+    }
+
     fn consume_pending_child(
         &self,
         child: SubsurfaceId,
@@ -1750,6 +1786,7 @@ impl Object for WlSurface {
         self.fractional_scale.take();
         self.tearing_control.take();
         self.constraints.clear();
+        self.shortcuts_inhibitors.clear();
         self.drm_feedback.clear();
         self.commit_timeline.clear(ClearReason::BreakLoops);
         self.alpha_modifier.take();
@@ -103,6 +103,18 @@ impl XdgPopupParent for Popup {
         self.parent.surface.output.get()
     }
 
+    fn constraint_area(&self) -> Rect {
+        // Keep popups out of exclusive zones unless the parent itself extends into
+        // them, e.g. because it is fullscreen.
+        let output = self.output();
+        let work_area = output.non_exclusive_rect.get();
+        if work_area.contains_rect(&self.position()) {
+            work_area
+        } else {
+            output.global.pos.get()
+        }
+    }
+
     fn has_workspace_link(&self) -> bool {
         self.workspace_link.borrow().is_some()
     }
@@ -204,6 +216,14 @@ pub trait XdgSurfaceExt: Debug {
         // nothing
     }
 
+    fn configure_acked(&self) {
+        // nothing
+    }
+
+    fn check_commit_size(&self) {
+        // nothing
+    }
+
     fn focus_node(&self) -> Option<Rc<dyn Node>> {
         None
     }
@@ -474,6 +494,9 @@ impl XdgSurfaceRequestHandler for XdgSurface {
     fn ack_configure(&self, req: AckConfigure, _slf: &Rc<Self>) -> Result<(), Self::Error> {
         if self.requested_serial.get() == req.serial {
             self.acked_serial.set(Some(req.serial));
+            if let Some(ext) = self.ext.get() {
+                ext.configure_acked();
+            }
         }
         Ok(())
     }
@@ -584,6 +607,7 @@ impl SurfaceExt for XdgSurface {
 
     fn after_apply_commit(self: Rc<Self>) {
         if let Some(ext) = self.ext.get() {
+            ext.check_commit_size();
             ext.post_commit();
         }
     }
@@ -42,6 +42,9 @@ pub trait XdgPopupParent {
     fn position(&self) -> Rect;
     fn remove_popup(&self);
     fn output(&self) -> Rc<OutputNode>;
+    fn constraint_area(&self) -> Rect {
+        self.output().global.pos.get()
+    }
     fn has_workspace_link(&self) -> bool;
     fn post_commit(&self);
     fn visible(&self) -> bool;
@@ -126,7 +129,7 @@ impl XdgPopup {
         let mut rel_pos = positioner.get_position(false, false);
         let mut abs_pos = rel_pos.move_(parent_abs.x1(), parent_abs.y1());
         {
-            let output_pos = parent.output().global.pos.get();
+            let output_pos = parent.constraint_area();
             let mut overflow = output_pos.get_overflow(&abs_pos);
             if !overflow.is_contained() {
                 let mut flip_x = positioner.ca.contains(CA_FLIP_X) && overflow.x_overflow();
@@ -83,13 +83,13 @@ const CAP_FULLSCREEN: u32 = 3;
 #[expect(dead_code)]
 const CAP_MINIMIZE: u32 = 4;
 
+pub const CONFIGURE_BOUNDS_SINCE: Version = Version(4);
 pub const WM_CAPABILITIES_SINCE: Version = Version(5);
 pub const SUSPENDED_SINCE: Version = Version(6);
 pub const CONSTRAINTS_SINCE: Version = Version(7);
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub enum Decoration {
-    #[expect(dead_code)]
     Client,
     Server,
 }
@@ -109,16 +109,16 @@ pub struct XdgToplevel {
     states: RefCell<AHashSet<u32>>,
     pub decoration: Cell<Decoration>,
     bugs: Cell<&'static Bugs>,
-    min_width: Cell<Option<i32>>,
-    min_height: Cell<Option<i32>>,
-    max_width: Cell<Option<i32>>,
-    max_height: Cell<Option<i32>>,
     pub tracker: Tracker<Self>,
     toplevel_data: ToplevelData,
     pub drag: CloneCell<Option<Rc<XdgToplevelDragV1>>>,
     is_mapped: Cell<bool>,
     dialog: CloneCell<Option<Rc<XdgDialogV1>>>,
     extents_set: Cell<bool>,
+    configured_max_size: Cell<Option<(i32, i32)>>,
+    acked_max_size: Cell<Option<(i32, i32)>>,
+    size_warned: Cell<bool>,
+    sent_bounds: Cell<Option<(i32, i32)>>,
     pub data: Rc<XdgToplevelToplevelData>,
 }
 
@@ -168,16 +168,16 @@ impl XdgToplevel {
             states: RefCell::new(states),
             decoration: Cell::new(Decoration::Server),
             bugs: Cell::new(&bugs::NONE),
-            min_width: Cell::new(None),
-            min_height: Cell::new(None),
-            max_width: Cell::new(None),
-            max_height: Cell::new(None),
             tracker: Default::default(),
             toplevel_data,
             drag: Default::default(),
             is_mapped: Cell::new(false),
             dialog: Default::default(),
             extents_set: Cell::new(false),
+            configured_max_size: Default::default(),
+            sent_bounds: Default::default(),
+            acked_max_size: Default::default(),
+            size_warned: Cell::new(false),
             data,
         }
     }
@@ -204,17 +204,23 @@ impl XdgToplevel {
             height = height.max(1);
         }
         let bugs = self.bugs.get();
+        // Tiled windows are clamped to the size limits as well. If the tile is larger
+        // than the maximum size, the rest of the tile stays empty. If it is smaller
+        // than the minimum size, the window is clipped to the tile. In the fullscreen
+        // state, the window should cover the whole output.
+        let data = &self.toplevel_data;
+        let respect_min_max_size = bugs.respect_min_max_size || !data.is_fullscreen.get();
         macro_rules! apply {
             ($field:expr, $min:ident, $max:ident) => {
                 if $field != 0 {
                     if let Some(min) = bugs.$min {
                         $field = $field.max(min);
                     }
-                    if bugs.respect_min_max_size {
-                        if let Some(min) = self.$min.get() {
+                    if respect_min_max_size {
+                        if let Some(min) = data.$min.get() {
                             $field = $field.max(min);
                         }
-                        if let Some(max) = self.$max.get() {
+                        if let Some(max) = data.$max.get() {
                             $field = $field.min(max);
                         }
                     }
@@ -233,6 +239,22 @@ impl XdgToplevel {
 
     fn send_configure(&self, width: i32, height: i32) {
         let states: Vec<_> = self.states.borrow().iter().copied().collect();
+        // In the fullscreen state, the configured size is a maximum for the window
+        // geometry. In all other states that we send, the client is free to choose a
+        // different size.
+        let max_size = states.contains(&STATE_FULLSCREEN) && width > 0 && height > 0;
+        self.configured_max_size
+            .set(max_size.then_some((width, height)));
+        if self.xdg.base.version >= CONFIGURE_BOUNDS_SINCE {
+            let bounds = self.bounds();
+            if self.sent_bounds.replace(Some(bounds)) != Some(bounds) {
+                self.xdg.surface.client.event(ConfigureBounds {
+                    self_id: self.id,
+                    width: bounds.0,
+                    height: bounds.1,
+                });
+            }
+        }
         self.xdg.surface.client.event(Configure {
             self_id: self.id,
             width,
@@ -241,6 +263,11 @@ impl XdgToplevel {
         })
     }
 
+    fn bounds(&self) -> (i32, i32) {
+        let rect = self.xdg.surface.output.get().workspace_rect.get();
+        (rect.width(), rect.height())
+    }
+
     pub fn send_wm_capabilities(&self) {
         self.xdg.surface.client.event(WmCapabilities {
             self_id: self.id,
@@ -279,12 +306,28 @@ impl XdgToplevelRequestHandler for XdgToplevel {
         Ok(())
     }
 
-    fn set_parent(&self, req: SetParent, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+    fn set_parent(&self, req: SetParent, slf: &Rc<Self>) -> Result<(), Self::Error> {
         let mut parent = None;
         if req.parent.is_some() {
-            parent = Some(self.xdg.surface.client.lookup(req.parent)?);
+            let p = self.xdg.surface.client.lookup(req.parent)?;
+            let mut ancestor = Some(p.clone());
+            while let Some(a) = ancestor {
+                if a.id == self.id {
+                    return Err(XdgToplevelError::InvalidParent);
+                }
+                ancestor = a.parent.get();
+            }
+            parent = Some(p);
+        }
+        if let Some(old) = self.parent.set(parent.clone()) {
+            old.children.borrow_mut().remove(&self.id);
+        }
+        if let Some(parent) = parent {
+            parent.children.borrow_mut().insert(self.id, slf.clone());
+            if let Some(float) = self.toplevel_data.float.get() {
+                float.restack();
+            }
         }
-        self.parent.set(parent);
         Ok(())
     }
 
@@ -316,12 +359,12 @@ impl XdgToplevelRequestHandler for XdgToplevel {
         if req.height < 0 || req.width < 0 {
             return Err(XdgToplevelError::NonNegative);
         }
-        self.max_width.set(if req.width == 0 {
+        self.toplevel_data.max_width.set(if req.width == 0 {
             None
         } else {
             Some(req.width)
         });
-        self.max_height.set(if req.height == 0 {
+        self.toplevel_data.max_height.set(if req.height == 0 {
             None
         } else {
             Some(req.height)
@@ -333,12 +376,12 @@ impl XdgToplevelRequestHandler for XdgToplevel {
         if req.height < 0 || req.width < 0 {
             return Err(XdgToplevelError::NonNegative);
         }
-        self.min_width.set(if req.width == 0 {
+        self.toplevel_data.min_width.set(if req.width == 0 {
             None
         } else {
             Some(req.width)
         });
-        self.min_height.set(if req.height == 0 {
+        self.toplevel_data.min_height.set(if req.height == 0 {
             None
         } else {
             Some(req.height)
@@ -502,8 +545,12 @@ impl XdgToplevel {
             {
                 let new_parent = self.parent.get();
                 let mut children = self.children.borrow_mut();
+                let mut parent_children = new_parent.as_ref().map(|p| p.children.borrow_mut());
                 for child in children.drain_values() {
                     child.parent.set(new_parent.clone());
+                    if let Some(parent_children) = &mut parent_children {
+                        parent_children.insert(child.id, child);
+                    }
                 }
             }
             self.state.tree_changed();
@@ -741,8 +788,22 @@ impl ToplevelNodeBase for XdgToplevel {
         Some(self.xdg.surface.clone())
     }
 
+    fn tl_work_area_changed(&self) {
+        let Some(sent) = self.sent_bounds.get() else {
+            return;
+        };
+        if sent != self.bounds() {
+            self.send_current_configure();
+        }
+    }
+
     fn tl_restack_popups(&self) {
         self.xdg.restack_popups();
+        for child in self.children.borrow().values() {
+            if let Some(float) = child.toplevel_data.float.get() {
+                float.restack();
+            }
+        }
     }
 
     fn tl_admits_children(&self) -> bool {
@@ -789,6 +850,30 @@ impl XdgSurfaceExt for XdgToplevel {
             .damage(self.node_absolute_position());
     }
 
+    fn configure_acked(&self) {
+        self.acked_max_size.set(self.configured_max_size.get());
+        self.size_warned.set(false);
+    }
+
+    fn check_commit_size(&self) {
+        // Many toolkits briefly commit a larger size after acking a fullscreen
+        // configure. The window is clipped to its tile anyway, so only log this.
+        let extents = self.xdg.extents.get();
+        if let Some((max_width, max_height)) = self.acked_max_size.get()
+            && (extents.width() > max_width || extents.height() > max_height)
+            && !self.size_warned.replace(true)
+        {
+            log::warn!(
+                "Client {} committed a window of size {}x{} that exceeds the size {}x{} of the acked fullscreen configure",
+                self.xdg.surface.client.id,
+                extents.width(),
+                extents.height(),
+                max_width,
+                max_height,
+            );
+        }
+    }
+
     fn make_visible(self: Rc<Self>) {
         self.node_make_visible();
     }
@@ -804,5 +889,7 @@ pub enum XdgToplevelError {
     ClientError(Box<ClientError>),
     #[error("width/height must be non-negative")]
     NonNegative,
+    #[error("The parent is the toplevel itself or one of its descendants")]
+    InvalidParent,
 }
 efrom!(XdgToplevelError, ClientError);
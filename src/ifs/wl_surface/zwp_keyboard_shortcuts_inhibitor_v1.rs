@@ -0,0 +1,88 @@
+use {
+    crate::{
+        client::{Client, ClientError},
+        ifs::{wl_seat::WlSeatGlobal, wl_surface::WlSurface},
+        leaks::Tracker,
+        object::{Object, Version},
+        tree::Node,
+        wire::{ZwpKeyboardShortcutsInhibitorV1Id, zwp_keyboard_shortcuts_inhibitor_v1::*},
+    },
+    std::{cell::Cell, rc::Rc},
+    thiserror::Error,
+};
+
+pub struct ZwpKeyboardShortcutsInhibitorV1 {
+    pub id: ZwpKeyboardShortcutsInhibitorV1Id,
+    pub client: Rc<Client>,
+    pub surface: Rc<WlSurface>,
+    pub seat: Rc<WlSeatGlobal>,
+    pub active: Cell<bool>,
+    pub tracker: Tracker<Self>,
+    pub version: Version,
+}
+
+impl ZwpKeyboardShortcutsInhibitorV1 {
+    pub fn install(self: &Rc<Self>) -> Result<(), ZwpKeyboardShortcutsInhibitorV1Error> {
+        let seat = self.seat.id();
+        if self.surface.shortcuts_inhibitors.contains(&seat) {
+            return Err(ZwpKeyboardShortcutsInhibitorV1Error::AlreadyInhibited);
+        }
+        self.surface.shortcuts_inhibitors.insert(seat, self.clone());
+        if self.seat.get_keyboard_node().node_id() == self.surface.node_id() {
+            self.activate();
+        }
+        Ok(())
+    }
+
+    pub fn activate(&self) {
+        if self.active.get() {
+            return;
+        }
+        let Some(tl) = self.surface.get_toplevel() else {
+            return;
+        };
+        let Some(config) = self.client.state.config.get() else {
+            return;
+        };
+        if !config.may_inhibit_shortcuts(tl.tl_data()) {
+            return;
+        }
+        self.active.set(true);
+        self.client.event(Active { self_id: self.id });
+    }
+
+    pub fn deactivate(&self) {
+        if self.active.replace(false) {
+            self.client.event(Inactive { self_id: self.id });
+        }
+    }
+}
+
+impl ZwpKeyboardShortcutsInhibitorV1RequestHandler for ZwpKeyboardShortcutsInhibitorV1 {
+    type Error = ZwpKeyboardShortcutsInhibitorV1Error;
+
+    fn destroy(&self, _req: Destroy, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.client.remove_obj(self)?;
+        self.surface.shortcuts_inhibitors.remove(&self.seat.id());
+        self.active.set(false);
+        Ok(())
+    }
+}
+
+object_base! {
+    self = ZwpKeyboardShortcutsInhibitorV1;
+    version = self.version;
+}
+
+impl Object for ZwpKeyboardShortcutsInhibitorV1 {}
+
+simple_add_obj!(ZwpKeyboardShortcutsInhibitorV1);
+
+#[derive(Debug, Error)]
+pub enum ZwpKeyboardShortcutsInhibitorV1Error {
+    #[error(transparent)]
+    ClientError(Box<ClientError>),
+    #[error("The surface already has a shortcuts inhibitor for the seat")]
+    AlreadyInhibited,
+}
+efrom!(ZwpKeyboardShortcutsInhibitorV1Error, ClientError);
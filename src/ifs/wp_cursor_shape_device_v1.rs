@@ -111,6 +111,10 @@ impl WpCursorShapeDeviceV1RequestHandler for WpCursorShapeDeviceV1 {
             ALL_RESIZE if self.version >= V2 => KnownCursor::AllResize,
             _ => return Err(WpCursorShapeDeviceV1Error::UnknownShape(req.shape)),
         };
+        if self.client.map_serial(req.serial).is_none() {
+            log::warn!("Client tried to set_shape with an invalid serial");
+            return Ok(());
+        }
         let tablet_tool;
         let (node_client_id, user) = match &self.cursor_user {
             CursorShapeCursorUser::Seat(s) => match s.pointer_node() {
@@ -80,11 +80,18 @@ impl WpSecurityContextV1RequestHandler for WpSecurityContextV1 {
     fn commit(&self, _req: Commit, _slf: &Rc<Self>) -> Result<(), Self::Error> {
         self.check_committed()?;
         self.committed.set(true);
-        let caps = CAPS_DEFAULT_SANDBOXED & self.client.bounding_caps;
+        let app_id = self.app_id.take();
+        let mut caps = CAPS_DEFAULT_SANDBOXED;
+        if let Some(app_id) = &app_id
+            && let Some(granted) = self.client.state.sandbox_capabilities.get(app_id)
+        {
+            caps |= granted;
+        }
+        let caps = caps & self.client.bounding_caps;
         self.client.state.security_context_acceptors.spawn(
             &self.client.state,
             self.sandbox_engine.take(),
-            self.app_id.take(),
+            app_id,
             self.instance_id.take(),
             &self.listen_fd,
             &self.close_fd,
@@ -1,6 +1,6 @@
 use {
     crate::{
-        client::{Client, ClientError},
+        client::{Client, ClientError, ClientId},
         leaks::Tracker,
         object::{Object, Version},
         utils::activation_token::{ActivationToken, activation_token},
@@ -12,6 +12,12 @@ use {
 
 const MAX_TOKENS_PER_CLIENT: usize = 8;
 
+#[derive(Copy, Clone)]
+pub struct ActivationTokenData {
+    pub client: ClientId,
+    pub last_interaction_msec: Option<u64>,
+}
+
 pub struct XdgActivationTokenV1 {
     pub id: XdgActivationTokenV1Id,
     pub client: Rc<Client>,
@@ -53,7 +59,11 @@ impl XdgActivationTokenV1RequestHandler for XdgActivationTokenV1 {
             return Err(XdgActivationTokenV1Error::AlreadyUsed);
         }
         let token = activation_token();
-        self.client.state.activation_tokens.set(token, ());
+        let data = ActivationTokenData {
+            client: self.client.id,
+            last_interaction_msec: self.client.last_interaction_msec.get(),
+        };
+        self.client.state.activation_tokens.set(token, data);
         let mut tokens = self.client.activation_tokens.borrow_mut();
         if tokens.len() >= MAX_TOKENS_PER_CLIENT
             && let Some(oldest) = tokens.pop_front()
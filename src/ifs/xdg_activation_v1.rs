@@ -2,16 +2,21 @@ use {
     crate::{
         client::{Client, ClientError},
         globals::{Global, GlobalName},
-        ifs::xdg_activation_token_v1::XdgActivationTokenV1,
+        ifs::xdg_activation_token_v1::{ActivationTokenData, XdgActivationTokenV1},
         leaks::Tracker,
         object::{Object, Version},
         utils::{activation_token::ActivationToken, errorfmt::ErrorFmt, opaque::OpaqueError},
         wire::{XdgActivationV1Id, xdg_activation_v1::*},
     },
+    jay_config::FocusStealingPolicy,
     std::rc::Rc,
     thiserror::Error,
 };
 
+/// How long after the last user input to the application that created the token an
+/// activation request may move the focus under the smart policy.
+const SMART_INTERACTION_MSEC: u64 = 5000;
+
 pub struct XdgActivationV1Global {
     pub name: GlobalName,
 }
@@ -92,18 +97,37 @@ impl XdgActivationV1RequestHandler for XdgActivationV1 {
             }
         };
         let surface = self.client.lookup(req.surface)?;
-        if self.client.state.activation_tokens.remove(&token).is_none() {
+        let Some(data) = self.client.state.activation_tokens.remove(&token) else {
             log::warn!(
                 "Client requested activation with unknown token {}",
                 req.token
             );
             return Ok(());
+        };
+        if self.may_steal_focus(&data) {
+            surface.activate();
+        } else {
+            surface.request_activation();
         }
-        surface.request_activation();
         Ok(())
     }
 }
 
+impl XdgActivationV1 {
+    fn may_steal_focus(&self, data: &ActivationTokenData) -> bool {
+        let state = &self.client.state;
+        match state.focus_stealing_policy.get() {
+            FocusStealingPolicy::ALWAYS => true,
+            FocusStealingPolicy::SAME_APP => data.client == self.client.id,
+            FocusStealingPolicy::SMART => match data.last_interaction_msec {
+                Some(msec) => state.now_msec().saturating_sub(msec) <= SMART_INTERACTION_MSEC,
+                None => false,
+            },
+            _ => false,
+        }
+    }
+}
+
 object_base! {
     self = XdgActivationV1;
     version = self.version;
@@ -32,7 +32,7 @@ impl XdgToplevelDragV1 {
     pub fn new(id: XdgToplevelDragV1Id, source: &Rc<WlDataSource>, version: Version) -> Self {
         Self {
             id,
-            client: source.data.client.clone(),
+            client: source.client.clone(),
             source: source.clone(),
             tracker: Default::default(),
             toplevel: Default::default(),
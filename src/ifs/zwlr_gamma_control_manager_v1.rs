@@ -0,0 +1,110 @@
+use {
+    crate::{
+        client::{CAP_HEAD_MANAGER, Client, ClientCaps, ClientError},
+        globals::{Global, GlobalName},
+        ifs::zwlr_gamma_control_v1::ZwlrGammaControlV1,
+        leaks::Tracker,
+        object::{Object, Version},
+        wire::{ZwlrGammaControlManagerV1Id, zwlr_gamma_control_manager_v1::*},
+    },
+    std::{cell::Cell, rc::Rc},
+    thiserror::Error,
+};
+
+pub struct ZwlrGammaControlManagerV1Global {
+    pub name: GlobalName,
+}
+
+impl ZwlrGammaControlManagerV1Global {
+    pub fn new(name: GlobalName) -> Self {
+        Self { name }
+    }
+
+    fn bind_(
+        self: Rc<Self>,
+        id: ZwlrGammaControlManagerV1Id,
+        client: &Rc<Client>,
+        version: Version,
+    ) -> Result<(), ZwlrGammaControlManagerV1Error> {
+        let obj = Rc::new(ZwlrGammaControlManagerV1 {
+            id,
+            client: client.clone(),
+            tracker: Default::default(),
+            version,
+        });
+        track!(client, obj);
+        client.add_client_obj(&obj)?;
+        Ok(())
+    }
+}
+
+global_base!(
+    ZwlrGammaControlManagerV1Global,
+    ZwlrGammaControlManagerV1,
+    ZwlrGammaControlManagerV1Error
+);
+
+impl Global for ZwlrGammaControlManagerV1Global {
+    fn singleton(&self) -> bool {
+        true
+    }
+
+    fn version(&self) -> u32 {
+        1
+    }
+
+    fn required_caps(&self) -> ClientCaps {
+        CAP_HEAD_MANAGER
+    }
+}
+
+simple_add_global!(ZwlrGammaControlManagerV1Global);
+
+pub struct ZwlrGammaControlManagerV1 {
+    pub id: ZwlrGammaControlManagerV1Id,
+    pub client: Rc<Client>,
+    pub tracker: Tracker<Self>,
+    pub version: Version,
+}
+
+impl ZwlrGammaControlManagerV1RequestHandler for ZwlrGammaControlManagerV1 {
+    type Error = ZwlrGammaControlManagerV1Error;
+
+    fn get_gamma_control(&self, req: GetGammaControl, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        let output = self.client.lookup(req.output)?;
+        let obj = Rc::new(ZwlrGammaControlV1 {
+            id: req.id,
+            client: self.client.clone(),
+            tracker: Default::default(),
+            version: self.version,
+            connector: output.global.connector.clone(),
+            size: Cell::new(0),
+            active: Cell::new(false),
+        });
+        track!(self.client, obj);
+        self.client.add_client_obj(&obj)?;
+        obj.install();
+        Ok(())
+    }
+
+    fn destroy(&self, _req: Destroy, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.client.remove_obj(self)?;
+        Ok(())
+    }
+}
+
+object_base! {
+    self = ZwlrGammaControlManagerV1;
+    version = self.version;
+}
+
+impl Object for ZwlrGammaControlManagerV1 {}
+
+simple_add_obj!(ZwlrGammaControlManagerV1);
+
+#[derive(Debug, Error)]
+pub enum ZwlrGammaControlManagerV1Error {
+    #[error(transparent)]
+    ClientError(Box<ClientError>),
+}
+efrom!(ZwlrGammaControlManagerV1Error, ClientError);
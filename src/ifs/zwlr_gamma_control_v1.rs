@@ -0,0 +1,144 @@
+use {
+    crate::{
+        backend::BackendGammaLut,
+        client::{Client, ClientError},
+        clientmem::{ClientMem, ClientMemError},
+        leaks::Tracker,
+        object::{Object, Version},
+        state::ConnectorData,
+        utils::errorfmt::ErrorFmt,
+        wire::{ZwlrGammaControlV1Id, zwlr_gamma_control_v1::*},
+    },
+    std::{cell::Cell, rc::Rc},
+    thiserror::Error,
+};
+
+pub struct ZwlrGammaControlV1 {
+    pub id: ZwlrGammaControlV1Id,
+    pub client: Rc<Client>,
+    pub tracker: Tracker<Self>,
+    pub version: Version,
+    pub connector: Rc<ConnectorData>,
+    pub size: Cell<u32>,
+    pub active: Cell<bool>,
+}
+
+impl ZwlrGammaControlV1 {
+    pub fn install(self: &Rc<Self>) {
+        let size = match self.connector.connector.gamma_lut_size() {
+            Some(size) if size > 0 => size,
+            _ => {
+                self.send_failed();
+                return;
+            }
+        };
+        if !self.connector.connected.get() || self.connector.gamma_control.is_some() {
+            self.send_failed();
+            return;
+        }
+        self.connector.gamma_control.set(Some(self.clone()));
+        self.size.set(size);
+        self.active.set(true);
+        self.client.event(GammaSize {
+            self_id: self.id,
+            size,
+        });
+    }
+
+    pub fn send_failed(&self) {
+        self.active.set(false);
+        self.client.event(Failed { self_id: self.id });
+    }
+
+    fn fail(&self) {
+        self.send_failed();
+        self.detach();
+    }
+
+    fn detach(&self) {
+        if let Some(gc) = self.connector.gamma_control.get()
+            && gc.id == self.id
+            && gc.client.id == self.client.id
+        {
+            self.connector.gamma_control.take();
+            if let Err(e) = self.connector.set_gamma_lut(None) {
+                log::error!("Could not reset the gamma LUT: {}", ErrorFmt(e));
+            }
+        }
+    }
+
+    fn read_lut(&self, req: &SetGamma) -> Result<BackendGammaLut, ZwlrGammaControlV1Error> {
+        let size = self.size.get() as usize;
+        let mem = ClientMem::new_private(&req.fd, 3 * size * 2, true, Some(&self.client), None)
+            .map(Rc::new)
+            .map_err(ZwlrGammaControlV1Error::MapGamma)?;
+        let mut bytes = vec![];
+        mem.offset(0)
+            .read(&mut bytes)
+            .map_err(ZwlrGammaControlV1Error::ReadGamma)?;
+        let channel = |idx: usize| {
+            bytes[idx * size * 2..(idx + 1) * size * 2]
+                .chunks_exact(2)
+                .map(|c| u16::from_ne_bytes([c[0], c[1]]))
+                .collect()
+        };
+        Ok(BackendGammaLut {
+            red: channel(0),
+            green: channel(1),
+            blue: channel(2),
+        })
+    }
+}
+
+impl ZwlrGammaControlV1RequestHandler for ZwlrGammaControlV1 {
+    type Error = ZwlrGammaControlV1Error;
+
+    fn set_gamma(&self, req: SetGamma, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        if !self.active.get() {
+            return Ok(());
+        }
+        let lut = match self.read_lut(&req) {
+            Ok(lut) => lut,
+            Err(e) => {
+                log::warn!("Could not read gamma ramps: {}", ErrorFmt(e));
+                self.fail();
+                return Ok(());
+            }
+        };
+        if let Err(e) = self.connector.set_gamma_lut(Some(Rc::new(lut))) {
+            log::error!("Could not apply the gamma LUT: {}", ErrorFmt(e));
+            self.fail();
+        }
+        Ok(())
+    }
+
+    fn destroy(&self, _req: Destroy, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.detach();
+        self.client.remove_obj(self)?;
+        Ok(())
+    }
+}
+
+object_base! {
+    self = ZwlrGammaControlV1;
+    version = self.version;
+}
+
+impl Object for ZwlrGammaControlV1 {
+    fn break_loops(&self) {
+        self.detach();
+    }
+}
+
+simple_add_obj!(ZwlrGammaControlV1);
+
+#[derive(Debug, Error)]
+pub enum ZwlrGammaControlV1Error {
+    #[error(transparent)]
+    ClientError(Box<ClientError>),
+    #[error("Could not map the gamma ramps")]
+    MapGamma(#[source] ClientMemError),
+    #[error("Could not read the gamma ramps")]
+    ReadGamma(#[source] ClientMemError),
+}
+efrom!(ZwlrGammaControlV1Error, ClientError);
@@ -0,0 +1,109 @@
+use {
+    crate::{
+        client::{Client, ClientError},
+        leaks::Tracker,
+        object::{Object, Version},
+        state::ConnectorData,
+        utils::errorfmt::ErrorFmt,
+        wire::{ZwlrOutputPowerV1Id, zwlr_output_power_v1::*},
+    },
+    std::rc::Rc,
+    thiserror::Error,
+};
+
+const MODE_OFF: u32 = 0;
+const MODE_ON: u32 = 1;
+
+pub struct ZwlrOutputPowerV1 {
+    pub id: ZwlrOutputPowerV1Id,
+    pub client: Rc<Client>,
+    pub tracker: Tracker<Self>,
+    pub version: Version,
+    pub connector: Rc<ConnectorData>,
+}
+
+impl ZwlrOutputPowerV1 {
+    pub fn install(self: &Rc<Self>) {
+        if !self.connector.connected.get() {
+            self.send_failed();
+            return;
+        }
+        self.connector
+            .output_power
+            .set((self.client.id, self.id), self.clone());
+        self.send_mode(self.connector.state.get().active);
+    }
+
+    pub fn send_mode(&self, active: bool) {
+        let mode = match active {
+            true => MODE_ON,
+            false => MODE_OFF,
+        };
+        self.client.event(Mode {
+            self_id: self.id,
+            mode,
+        });
+    }
+
+    pub fn send_failed(&self) {
+        self.client.event(Failed { self_id: self.id });
+    }
+
+    fn detach(&self) {
+        self.connector
+            .output_power
+            .remove(&(self.client.id, self.id));
+    }
+}
+
+impl ZwlrOutputPowerV1RequestHandler for ZwlrOutputPowerV1 {
+    type Error = ZwlrOutputPowerV1Error;
+
+    fn set_mode(&self, req: SetMode, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        let active = match req.mode {
+            MODE_OFF => false,
+            MODE_ON => true,
+            _ => return Err(ZwlrOutputPowerV1Error::InvalidMode(req.mode)),
+        };
+        if !self.connector.connected.get() {
+            return Ok(());
+        }
+        let res = self
+            .connector
+            .modify_state(&self.client.state, |s| s.active = active);
+        if let Err(e) = res {
+            log::error!("Could not change the power mode: {}", ErrorFmt(e));
+            self.send_failed();
+            self.detach();
+        }
+        Ok(())
+    }
+
+    fn destroy(&self, _req: Destroy, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.detach();
+        self.client.remove_obj(self)?;
+        Ok(())
+    }
+}
+
+object_base! {
+    self = ZwlrOutputPowerV1;
+    version = self.version;
+}
+
+impl Object for ZwlrOutputPowerV1 {
+    fn break_loops(&self) {
+        self.detach();
+    }
+}
+
+simple_add_obj!(ZwlrOutputPowerV1);
+
+#[derive(Debug, Error)]
+pub enum ZwlrOutputPowerV1Error {
+    #[error(transparent)]
+    ClientError(Box<ClientError>),
+    #[error("Unknown power mode {0}")]
+    InvalidMode(u32),
+}
+efrom!(ZwlrOutputPowerV1Error, ClientError);
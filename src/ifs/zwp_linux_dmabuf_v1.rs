@@ -115,7 +115,10 @@ impl ZwpLinuxDmabufV1 {
             .state
             .drm_feedback_consumers
             .set((self.client.id, id), fb.clone());
-        if let Some(feedback) = self.client.state.drm_feedback.get() {
+        let feedback = surface
+            .and_then(|s| s.scanout_feedback())
+            .or_else(|| self.client.state.drm_feedback.get());
+        if let Some(feedback) = feedback {
             fb.send_feedback(&feedback);
         }
         Ok(fb)
@@ -6,7 +6,8 @@ use {
         object::{Object, Version},
         wire::{ZxdgToplevelDecorationV1Id, zxdg_toplevel_decoration_v1::*},
     },
-    std::rc::Rc,
+    jay_config::DecorationMode,
+    std::{cell::Cell, rc::Rc},
     thiserror::Error,
 };
 
@@ -19,6 +20,7 @@ pub struct ZxdgToplevelDecorationV1 {
     pub toplevel: Rc<XdgToplevel>,
     pub tracker: Tracker<Self>,
     pub version: Version,
+    requested: Cell<Option<Decoration>>,
 }
 
 impl ZxdgToplevelDecorationV1 {
@@ -34,6 +36,7 @@ impl ZxdgToplevelDecorationV1 {
             toplevel: toplevel.clone(),
             tracker: Default::default(),
             version,
+            requested: Default::default(),
         }
     }
 
@@ -45,7 +48,13 @@ impl ZxdgToplevelDecorationV1 {
     }
 
     pub fn do_send_configure(&self) {
-        let mode = match self.toplevel.decoration.get() {
+        let decoration = match self.client.state.decoration_mode.get() {
+            DecorationMode::SERVER_SIDE => Decoration::Server,
+            DecorationMode::CLIENT_SIDE => Decoration::Client,
+            _ => self.requested.get().unwrap_or(Decoration::Server),
+        };
+        self.toplevel.decoration.set(decoration);
+        let mode = match decoration {
             Decoration::Client => CLIENT_SIDE,
             Decoration::Server => SERVER_SIDE,
         };
@@ -62,12 +71,19 @@ impl ZxdgToplevelDecorationV1RequestHandler for ZxdgToplevelDecorationV1 {
         Ok(())
     }
 
-    fn set_mode(&self, _req: SetMode, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+    fn set_mode(&self, req: SetMode, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        let requested = match req.mode {
+            CLIENT_SIDE => Decoration::Client,
+            SERVER_SIDE => Decoration::Server,
+            _ => return Err(ZxdgToplevelDecorationV1Error::InvalidMode(req.mode)),
+        };
+        self.requested.set(Some(requested));
         self.do_send_configure();
         Ok(())
     }
 
     fn unset_mode(&self, _req: UnsetMode, _slf: &Rc<Self>) -> Result<(), Self::Error> {
+        self.requested.set(None);
         self.do_send_configure();
         Ok(())
     }
@@ -86,5 +102,7 @@ simple_add_obj!(ZxdgToplevelDecorationV1);
 pub enum ZxdgToplevelDecorationV1Error {
     #[error(transparent)]
     ClientError(Box<ClientError>),
+    #[error("Mode {0} does not exist")]
+    InvalidMode(u32),
 }
 efrom!(ZxdgToplevelDecorationV1Error, ClientError);
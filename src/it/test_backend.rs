@@ -3,10 +3,10 @@ use {
         allocator::{Allocator, AllocatorError},
         async_engine::SpawnedFuture,
         backend::{
-            AxisSource, Backend, BackendConnectorState, BackendEvent, Connector, ConnectorEvent,
-            ConnectorId, ConnectorKernelId, DrmDeviceId, InputDevice, InputDeviceAccelProfile,
-            InputDeviceCapability, InputDeviceClickMethod, InputDeviceId, InputEvent, KeyState,
-            Mode, MonitorInfo, ScrollAxis, TransformMatrix,
+            AxisSource, Backend, BackendConnectorState, BackendEvent, BackendGammaLut, Connector,
+            ConnectorEvent, ConnectorId, ConnectorKernelId, DrmDeviceId, InputDevice,
+            InputDeviceAccelProfile, InputDeviceCapability, InputDeviceClickMethod, InputDeviceId,
+            InputEvent, KeyState, Mode, MonitorInfo, ScrollAxis, TransformMatrix,
             transaction::{
                 BackendAppliedConnectorTransaction, BackendConnectorTransaction,
                 BackendConnectorTransactionError, BackendConnectorTransactionType,
@@ -84,6 +84,8 @@ impl TestBackend {
             events: Default::default(),
             feedback: Default::default(),
             idle: Default::default(),
+            gamma_lut_size: Some(4),
+            gamma_lut: Default::default(),
         });
         let default_mouse = Rc::new(TestBackendMouse {
             common: TestInputDeviceCommon {
@@ -141,6 +143,7 @@ impl TestBackend {
             color_spaces: vec![],
             primaries: Primaries::SRGB,
             luminance: None,
+            vrr_range: None,
             state: BackendConnectorState {
                 serial: state.backend_connector_state_serials.next(),
                 enabled: true,
@@ -318,6 +321,8 @@ pub struct TestConnector {
     pub events: OnChange<ConnectorEvent>,
     pub feedback: CloneCell<Option<Rc<DrmFeedback>>>,
     pub idle: TEEH<bool>,
+    pub gamma_lut_size: Option<u32>,
+    pub gamma_lut: CloneCell<Option<Rc<BackendGammaLut>>>,
 }
 
 impl Connector for TestConnector {
@@ -354,6 +359,14 @@ impl Connector for TestConnector {
         self.feedback.get()
     }
 
+    fn gamma_lut_size(&self) -> Option<u32> {
+        self.gamma_lut_size
+    }
+
+    fn set_gamma_lut(&self, lut: Option<Rc<BackendGammaLut>>) {
+        self.gamma_lut.set(lut);
+    }
+
     fn transaction_type(&self) -> Box<dyn BackendConnectorTransactionTypeDyn> {
         Box::new(TestBackendTransactionType)
     }
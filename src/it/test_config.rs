@@ -10,13 +10,14 @@ use {
     isnt::std_1::primitive::IsntConstPtrExt,
     jay_config::{
         _private::{
-            ConfigEntry, VERSION, bincode_ops,
+            ConfigEntry, VERSION, WindowCriterionIpc, WindowCriterionStringField, bincode_ops,
             ipc::{ClientMessage, Response, ServerMessage},
         },
-        Axis, Direction,
+        Axis, DecorationMode, Direction,
         input::{InputDevice, Seat},
         keyboard::{Keymap, ModifiedKeySym},
         video::{Connector, Transform},
+        window::WindowMatcher,
     },
     std::{cell::Cell, ops::Deref, ptr, rc::Rc, time::Duration},
 };
@@ -280,6 +281,29 @@ impl TestConfig {
         })
     }
 
+    pub fn create_title_matcher(&self, title: &str) -> Result<WindowMatcher, TestError> {
+        let reply = self.send_with_reply(ClientMessage::CreateWindowMatcher {
+            criterion: WindowCriterionIpc::String {
+                string: title.to_string(),
+                field: WindowCriterionStringField::Title,
+                regex: false,
+            },
+        })?;
+        get_response!(reply, CreateWindowMatcher { matcher });
+        Ok(matcher)
+    }
+
+    pub fn set_window_matcher_may_inhibit_shortcuts(
+        &self,
+        matcher: WindowMatcher,
+        may_inhibit: bool,
+    ) -> TestResult {
+        self.send(ClientMessage::SetWindowMatcherMayInhibitShortcuts {
+            matcher,
+            may_inhibit,
+        })
+    }
+
     fn clear(&self) {
         unsafe {
             if let Some(srv) = self.srv.take() {
@@ -295,6 +319,14 @@ impl TestConfig {
         })
     }
 
+    pub fn set_client_shm_limit(&self, limit: Option<u64>) -> TestResult {
+        self.send(ClientMessage::SetClientShmLimit { limit })
+    }
+
+    pub fn set_decoration_mode(&self, mode: DecorationMode) -> TestResult {
+        self.send(ClientMessage::SetDecorationMode { mode })
+    }
+
     pub fn set_output_transform(&self, output: &OutputNode, transform: Transform) -> TestResult {
         self.send(ClientMessage::ConnectorSetTransform {
             connector: Connector(output.global.connector.connector.id().raw() as _),
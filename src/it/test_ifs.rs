@@ -20,12 +20,19 @@ pub mod test_dmabuf;
 pub mod test_dmabuf_feedback;
 pub mod test_ext_foreign_toplevel_handle;
 pub mod test_ext_foreign_toplevel_list;
+pub mod test_gamma_control;
+pub mod test_gamma_control_manager;
 pub mod test_input_method;
 pub mod test_input_method_keyboard_grab;
 pub mod test_input_method_manager;
 pub mod test_input_popup_surface;
 pub mod test_jay_compositor;
 pub mod test_keyboard;
+pub mod test_keyboard_shortcuts_inhibit_manager;
+pub mod test_keyboard_shortcuts_inhibitor;
+pub mod test_output;
+pub mod test_output_power;
+pub mod test_output_power_manager;
 pub mod test_pointer;
 pub mod test_region;
 pub mod test_registry;
@@ -53,5 +60,7 @@ pub mod test_wl_fixes;
 pub mod test_xdg_activation;
 pub mod test_xdg_activation_token;
 pub mod test_xdg_base;
+pub mod test_xdg_decoration_manager;
 pub mod test_xdg_surface;
 pub mod test_xdg_toplevel;
+pub mod test_xdg_toplevel_decoration;
@@ -0,0 +1,72 @@
+use {
+    crate::{
+        it::{
+            test_error::TestError, test_mem::TestMem, test_object::TestObject,
+            test_transport::TestTransport, testrun::ParseFull,
+        },
+        utils::buffd::MsgParser,
+        wire::{ZwlrGammaControlV1Id, zwlr_gamma_control_v1::*},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestGammaControl {
+    pub id: ZwlrGammaControlV1Id,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+    pub size: Cell<Option<u32>>,
+    pub failed: Cell<bool>,
+}
+
+impl TestGammaControl {
+    pub fn destroy(&self) -> Result<(), TestError> {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    pub fn set_gamma(&self, red: &[u16], green: &[u16], blue: &[u16]) -> Result<(), TestError> {
+        let ramps: Vec<u8> = [red, green, blue]
+            .into_iter()
+            .flatten()
+            .flat_map(|v| v.to_ne_bytes())
+            .collect();
+        let mem = TestMem::new(ramps.len())?;
+        for (dst, src) in mem.iter().zip(ramps) {
+            dst.set(src);
+        }
+        self.tran.send(SetGamma {
+            self_id: self.id,
+            fd: mem.fd.clone(),
+        })?;
+        Ok(())
+    }
+
+    fn handle_gamma_size(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let ev = GammaSize::parse_full(parser)?;
+        self.size.set(Some(ev.size));
+        Ok(())
+    }
+
+    fn handle_failed(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let _ev = Failed::parse_full(parser)?;
+        self.failed.set(true);
+        Ok(())
+    }
+}
+
+test_object! {
+    TestGammaControl, ZwlrGammaControlV1;
+
+    GAMMA_SIZE => handle_gamma_size,
+    FAILED => handle_failed,
+}
+
+impl TestObject for TestGammaControl {}
+
+impl Drop for TestGammaControl {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
@@ -0,0 +1,64 @@
+use {
+    crate::{
+        it::{
+            test_error::{TestError, TestResult},
+            test_ifs::{test_gamma_control::TestGammaControl, test_output::TestOutput},
+            test_object::TestObject,
+            test_transport::TestTransport,
+        },
+        wire::{ZwlrGammaControlManagerV1Id, zwlr_gamma_control_manager_v1::*},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestGammaControlManager {
+    pub id: ZwlrGammaControlManagerV1Id,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+}
+
+impl TestGammaControlManager {
+    pub fn new(tran: &Rc<TestTransport>) -> Self {
+        Self {
+            id: tran.id(),
+            tran: tran.clone(),
+            destroyed: Cell::new(false),
+        }
+    }
+
+    pub fn destroy(&self) -> Result<(), TestError> {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    pub fn get_gamma_control(&self, output: &TestOutput) -> TestResult<Rc<TestGammaControl>> {
+        let obj = Rc::new(TestGammaControl {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+            size: Default::default(),
+            failed: Cell::new(false),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(GetGammaControl {
+            self_id: self.id,
+            id: obj.id,
+            output: output.id,
+        })?;
+        Ok(obj)
+    }
+}
+
+impl Drop for TestGammaControlManager {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestGammaControlManager, ZwlrGammaControlManagerV1;
+}
+
+impl TestObject for TestGammaControlManager {}
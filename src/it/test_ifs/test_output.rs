@@ -0,0 +1,77 @@
+use {
+    crate::{
+        it::{
+            test_error::TestError, test_object::TestObject, test_transport::TestTransport,
+            testrun::ParseFull,
+        },
+        utils::{buffd::MsgParser, clonecell::CloneCell, once::Once},
+        wire::{WlOutputId, wl_output::*},
+    },
+    std::rc::Rc,
+};
+
+pub struct TestOutput {
+    pub id: WlOutputId,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Once,
+    pub name: CloneCell<Option<Rc<String>>>,
+}
+
+impl TestOutput {
+    pub fn destroy(&self) -> Result<(), TestError> {
+        if self.destroyed.set() {
+            self.tran.send(Release { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    fn handle_geometry(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let _ev = Geometry::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_mode(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let _ev = Mode::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_done(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let _ev = Done::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_scale(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let _ev = Scale::parse_full(parser)?;
+        Ok(())
+    }
+
+    fn handle_name(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let ev = Name::parse_full(parser)?;
+        self.name.set(Some(Rc::new(ev.name.to_string())));
+        Ok(())
+    }
+
+    fn handle_description(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let _ev = Description::parse_full(parser)?;
+        Ok(())
+    }
+}
+
+test_object! {
+    TestOutput, WlOutput;
+
+    GEOMETRY => handle_geometry,
+    MODE => handle_mode,
+    DONE => handle_done,
+    SCALE => handle_scale,
+    NAME => handle_name,
+    DESCRIPTION => handle_description,
+}
+
+impl TestObject for TestOutput {}
+
+impl Drop for TestOutput {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
@@ -0,0 +1,63 @@
+use {
+    crate::{
+        it::{
+            test_error::TestError, test_object::TestObject, test_transport::TestTransport,
+            testrun::ParseFull,
+        },
+        utils::buffd::MsgParser,
+        wire::{ZwlrOutputPowerV1Id, zwlr_output_power_v1::*},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestOutputPower {
+    pub id: ZwlrOutputPowerV1Id,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+    pub mode: Cell<Option<u32>>,
+    pub failed: Cell<bool>,
+}
+
+impl TestOutputPower {
+    pub fn destroy(&self) -> Result<(), TestError> {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    pub fn set_mode(&self, mode: u32) -> Result<(), TestError> {
+        self.tran.send(SetMode {
+            self_id: self.id,
+            mode,
+        })?;
+        Ok(())
+    }
+
+    fn handle_mode(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let ev = Mode::parse_full(parser)?;
+        self.mode.set(Some(ev.mode));
+        Ok(())
+    }
+
+    fn handle_failed(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let _ev = Failed::parse_full(parser)?;
+        self.failed.set(true);
+        Ok(())
+    }
+}
+
+test_object! {
+    TestOutputPower, ZwlrOutputPowerV1;
+
+    MODE => handle_mode,
+    FAILED => handle_failed,
+}
+
+impl TestObject for TestOutputPower {}
+
+impl Drop for TestOutputPower {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
@@ -0,0 +1,64 @@
+use {
+    crate::{
+        it::{
+            test_error::{TestError, TestResult},
+            test_ifs::{test_output::TestOutput, test_output_power::TestOutputPower},
+            test_object::TestObject,
+            test_transport::TestTransport,
+        },
+        wire::{ZwlrOutputPowerManagerV1Id, zwlr_output_power_manager_v1::*},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestOutputPowerManager {
+    pub id: ZwlrOutputPowerManagerV1Id,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+}
+
+impl TestOutputPowerManager {
+    pub fn new(tran: &Rc<TestTransport>) -> Self {
+        Self {
+            id: tran.id(),
+            tran: tran.clone(),
+            destroyed: Cell::new(false),
+        }
+    }
+
+    pub fn destroy(&self) -> Result<(), TestError> {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    pub fn get_output_power(&self, output: &TestOutput) -> TestResult<Rc<TestOutputPower>> {
+        let obj = Rc::new(TestOutputPower {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+            mode: Default::default(),
+            failed: Cell::new(false),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(GetOutputPower {
+            self_id: self.id,
+            id: obj.id,
+            output: output.id,
+        })?;
+        Ok(obj)
+    }
+}
+
+impl Drop for TestOutputPowerManager {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestOutputPowerManager, ZwlrOutputPowerManagerV1;
+}
+
+impl TestObject for TestOutputPowerManager {}
@@ -11,8 +11,11 @@ use {
                 test_data_control_manager::TestDataControlManager,
                 test_data_device_manager::TestDataDeviceManager, test_dmabuf::TestDmabuf,
                 test_ext_foreign_toplevel_list::TestExtForeignToplevelList,
+                test_gamma_control_manager::TestGammaControlManager,
                 test_input_method_manager::TestInputMethodManager,
-                test_jay_compositor::TestJayCompositor, test_shm::TestShm,
+                test_jay_compositor::TestJayCompositor,
+                test_keyboard_shortcuts_inhibit_manager::TestKeyboardShortcutsInhibitManager,
+                test_output_power_manager::TestOutputPowerManager, test_shm::TestShm,
                 test_single_pixel_buffer_manager::TestSinglePixelBufferManager,
                 test_subcompositor::TestSubcompositor, test_syncobj_manager::TestSyncobjManager,
                 test_text_input_manager::TestTextInputManager,
@@ -21,6 +24,7 @@ use {
                 test_virtual_keyboard_manager::TestVirtualKeyboardManager,
                 test_wl_fixes::TestWlFixes, test_xdg_activation::TestXdgActivation,
                 test_xdg_base::TestXdgWmBase,
+                test_xdg_decoration_manager::TestXdgDecorationManager,
             },
             test_object::TestObject,
             test_transport::TestTransport,
@@ -60,6 +64,10 @@ pub struct TestRegistrySingletons {
     pub zwp_input_method_manager_v2: u32,
     pub zwp_text_input_manager_v3: u32,
     pub wl_fixes: u32,
+    pub zwp_keyboard_shortcuts_inhibit_manager_v1: u32,
+    pub zwlr_output_power_manager_v1: u32,
+    pub zwlr_gamma_control_manager_v1: u32,
+    pub zxdg_decoration_manager_v1: u32,
 }
 
 pub struct TestRegistry {
@@ -88,6 +96,10 @@ pub struct TestRegistry {
     pub input_method_manager: CloneCell<Option<Rc<TestInputMethodManager>>>,
     pub text_input_manager: CloneCell<Option<Rc<TestTextInputManager>>>,
     pub wl_fixes: CloneCell<Option<Rc<TestWlFixes>>>,
+    pub shortcuts_inhibit_manager: CloneCell<Option<Rc<TestKeyboardShortcutsInhibitManager>>>,
+    pub output_power_manager: CloneCell<Option<Rc<TestOutputPowerManager>>>,
+    pub gamma_control_manager: CloneCell<Option<Rc<TestGammaControlManager>>>,
+    pub decoration_manager: CloneCell<Option<Rc<TestXdgDecorationManager>>>,
     pub seats: CopyHashMap<GlobalName, Rc<WlSeatGlobal>>,
 }
 
@@ -160,6 +172,10 @@ impl TestRegistry {
             zwp_input_method_manager_v2,
             zwp_text_input_manager_v3,
             wl_fixes,
+            zwp_keyboard_shortcuts_inhibit_manager_v1,
+            zwlr_output_power_manager_v1,
+            zwlr_gamma_control_manager_v1,
+            zxdg_decoration_manager_v1,
         };
         self.singletons.set(Some(singletons.clone()));
         Ok(singletons)
@@ -276,6 +292,34 @@ impl TestRegistry {
         TestTextInputManager
     );
     create_singleton!(get_wl_fixes, wl_fixes, wl_fixes, 1, TestWlFixes);
+    create_singleton!(
+        get_shortcuts_inhibit_manager,
+        shortcuts_inhibit_manager,
+        zwp_keyboard_shortcuts_inhibit_manager_v1,
+        1,
+        TestKeyboardShortcutsInhibitManager
+    );
+    create_singleton!(
+        get_output_power_manager,
+        output_power_manager,
+        zwlr_output_power_manager_v1,
+        1,
+        TestOutputPowerManager
+    );
+    create_singleton!(
+        get_gamma_control_manager,
+        gamma_control_manager,
+        zwlr_gamma_control_manager_v1,
+        1,
+        TestGammaControlManager
+    );
+    create_singleton!(
+        get_decoration_manager,
+        decoration_manager,
+        zxdg_decoration_manager_v1,
+        1,
+        TestXdgDecorationManager
+    );
 
     pub fn bind<O: TestObject>(
         &self,
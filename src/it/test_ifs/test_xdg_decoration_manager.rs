@@ -0,0 +1,69 @@
+use {
+    crate::{
+        it::{
+            test_error::{TestError, TestResult},
+            test_ifs::{
+                test_xdg_toplevel::TestXdgToplevel,
+                test_xdg_toplevel_decoration::TestXdgToplevelDecoration,
+            },
+            test_object::TestObject,
+            test_transport::TestTransport,
+        },
+        wire::{ZxdgDecorationManagerV1Id, zxdg_decoration_manager_v1::*},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub struct TestXdgDecorationManager {
+    pub id: ZxdgDecorationManagerV1Id,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+}
+
+impl TestXdgDecorationManager {
+    pub fn new(tran: &Rc<TestTransport>) -> Self {
+        Self {
+            id: tran.id(),
+            tran: tran.clone(),
+            destroyed: Cell::new(false),
+        }
+    }
+
+    pub fn destroy(&self) -> Result<(), TestError> {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    pub fn get_toplevel_decoration(
+        &self,
+        toplevel: &TestXdgToplevel,
+    ) -> TestResult<Rc<TestXdgToplevelDecoration>> {
+        let obj = Rc::new(TestXdgToplevelDecoration {
+            id: self.tran.id(),
+            tran: self.tran.clone(),
+            destroyed: Cell::new(false),
+            mode: Default::default(),
+        });
+        self.tran.add_obj(obj.clone())?;
+        self.tran.send(GetToplevelDecoration {
+            self_id: self.id,
+            id: obj.id,
+            toplevel: toplevel.core.id,
+        })?;
+        Ok(obj)
+    }
+}
+
+impl Drop for TestXdgDecorationManager {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
+
+test_object! {
+    TestXdgDecorationManager, ZxdgDecorationManagerV1;
+}
+
+impl TestObject for TestXdgDecorationManager {}
@@ -0,0 +1,63 @@
+use {
+    crate::{
+        it::{
+            test_error::TestError, test_object::TestObject, test_transport::TestTransport,
+            testrun::ParseFull,
+        },
+        utils::buffd::MsgParser,
+        wire::{ZxdgToplevelDecorationV1Id, zxdg_toplevel_decoration_v1::*},
+    },
+    std::{cell::Cell, rc::Rc},
+};
+
+pub const CLIENT_SIDE: u32 = 1;
+pub const SERVER_SIDE: u32 = 2;
+
+pub struct TestXdgToplevelDecoration {
+    pub id: ZxdgToplevelDecorationV1Id,
+    pub tran: Rc<TestTransport>,
+    pub destroyed: Cell<bool>,
+    pub mode: Cell<Option<u32>>,
+}
+
+impl TestXdgToplevelDecoration {
+    pub fn destroy(&self) -> Result<(), TestError> {
+        if !self.destroyed.replace(true) {
+            self.tran.send(Destroy { self_id: self.id })?;
+        }
+        Ok(())
+    }
+
+    pub fn set_mode(&self, mode: u32) -> Result<(), TestError> {
+        self.tran.send(SetMode {
+            self_id: self.id,
+            mode,
+        })?;
+        Ok(())
+    }
+
+    pub fn unset_mode(&self) -> Result<(), TestError> {
+        self.tran.send(UnsetMode { self_id: self.id })?;
+        Ok(())
+    }
+
+    fn handle_configure(&self, parser: MsgParser<'_, '_>) -> Result<(), TestError> {
+        let ev = Configure::parse_full(parser)?;
+        self.mode.set(Some(ev.mode));
+        Ok(())
+    }
+}
+
+test_object! {
+    TestXdgToplevelDecoration, ZxdgToplevelDecorationV1;
+
+    CONFIGURE => handle_configure,
+}
+
+impl TestObject for TestXdgToplevelDecoration {}
+
+impl Drop for TestXdgToplevelDecoration {
+    fn drop(&mut self) {
+        let _ = self.destroy();
+    }
+}
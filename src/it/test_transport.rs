@@ -74,6 +74,10 @@ impl TestTransport {
             input_method_manager: Default::default(),
             text_input_manager: Default::default(),
             wl_fixes: Default::default(),
+            shortcuts_inhibit_manager: Default::default(),
+            output_power_manager: Default::default(),
+            gamma_control_manager: Default::default(),
+            decoration_manager: Default::default(),
             seats: Default::default(),
         });
         self.send(wl_display::GetRegistry {
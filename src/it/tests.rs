@@ -76,6 +76,13 @@ mod t0041_input_method;
 mod t0042_toplevel_select;
 mod t0043_destroy_registry;
 mod t0044_stacked_focus;
+mod t0045_clipboard_persistence;
+mod t0046_oversized_fullscreen;
+mod t0047_shm_accounting;
+mod t0048_keyboard_shortcuts_inhibit;
+mod t0049_output_power;
+mod t0050_gamma_control;
+mod t0051_xdg_decoration;
 
 pub trait TestCase: Sync {
     fn name(&self) -> &'static str;
@@ -139,5 +146,12 @@ pub fn tests() -> Vec<&'static dyn TestCase> {
         t0042_toplevel_select,
         t0043_destroy_registry,
         t0044_stacked_focus,
+        t0045_clipboard_persistence,
+        t0046_oversized_fullscreen,
+        t0047_shm_accounting,
+        t0048_keyboard_shortcuts_inhibit,
+        t0049_output_power,
+        t0050_gamma_control,
+        t0051_xdg_decoration,
     }
 }
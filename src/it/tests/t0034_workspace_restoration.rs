@@ -35,6 +35,8 @@ async fn test(run: Rc<TestRun>) -> TestResult {
         events: Default::default(),
         feedback: Default::default(),
         idle: Default::default(),
+        gamma_lut_size: None,
+        gamma_lut: Default::default(),
     });
     let new_monitor_info = MonitorInfo {
         modes: vec![],
@@ -53,6 +55,7 @@ async fn test(run: Rc<TestRun>) -> TestResult {
         color_spaces: vec![],
         primaries: Primaries::SRGB,
         luminance: None,
+        vrr_range: None,
         state: BackendConnectorState {
             serial: run.state.backend_connector_state_serials.next(),
             enabled: true,
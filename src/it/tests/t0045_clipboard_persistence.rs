@@ -0,0 +1,76 @@
+use {
+    crate::it::{
+        test_error::{TestErrorExt, TestResult},
+        testrun::TestRun,
+    },
+    std::{
+        io::{Read, Write},
+        rc::Rc,
+    },
+};
+
+testcase!();
+
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let _ds = run.create_default_setup().await?;
+    run.state.clipboard_persistence.set(true);
+
+    let client1 = run.create_client().await?;
+    let seat1 = client1.get_default_seat().await?;
+    let dev1 = client1.data_device_manager.get_data_device(&seat1.seat)?;
+    let entered = seat1.kb.enter.expect()?;
+    let win1 = client1.create_window().await?;
+    win1.map2().await?;
+    let serial = entered.next()?.serial;
+
+    let client2 = run.create_client().await?;
+    let seat2 = client2.get_default_seat().await?;
+    let data_control2 = client2.registry.get_data_control_manager().await?;
+    let dev2 = data_control2.get_data_device(&seat2.seat)?;
+    let selection = dev2.selection.expect()?;
+
+    // Explicitly destroying the source clears the selection.
+    {
+        let source = client1.data_device_manager.create_data_source()?;
+        source.offer("text")?;
+        let sends = source.sends.expect()?;
+        dev1.set_selection(&source, serial)?;
+        client1.sync().await;
+        let (mime, sfd) = sends.next().with_context(|| "sends (1)")?;
+        tassert_eq!(mime, "text");
+        sfd.borrow().write_all(b"abcd")?;
+        drop(sfd);
+        client1.sync().await;
+        source.destroy()?;
+        client1.sync().await;
+        client2.sync().await;
+        tassert!(selection.last().with_context(|| "selection (1)")?.is_none());
+    }
+
+    // Disconnecting the client persists the selection.
+    let source = client1.data_device_manager.create_data_source()?;
+    source.offer("text")?;
+    let sends = source.sends.expect()?;
+    dev1.set_selection(&source, serial)?;
+    client1.sync().await;
+    let (mime, sfd) = sends.next().with_context(|| "sends (2)")?;
+    tassert_eq!(mime, "text");
+    sfd.borrow().write_all(b"efgh")?;
+    drop(sfd);
+    client1.sync().await;
+
+    run.state.clients.kill(client1.server.id);
+    client2.sync().await;
+
+    let Some(sel) = selection.last().with_context(|| "selection (2)")? else {
+        bail!("selection was not persisted");
+    };
+    tassert!(sel.offers.borrow().contains("text"));
+    let rfd = sel.receive("text")?;
+    client2.sync().await;
+    let mut buf = vec![];
+    rfd.borrow().read_to_end(&mut buf)?;
+    tassert_eq!(buf, b"efgh");
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use {
+    crate::{
+        it::{test_error::TestResult, testrun::TestRun},
+        tree::ToplevelNodeBase,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that a window exceeding the size of an acked fullscreen configure is not
+/// disconnected
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let window = client.create_window().await?;
+    window.map2().await?;
+
+    run.cfg.set_fullscreen(ds.seat.id(), true)?;
+    client.sync().await;
+    tassert!(window.tl.server.tl_data().is_fullscreen.get());
+
+    let width = window.tl.core.width.get();
+    let height = window.tl.core.height.get();
+    tassert!(width > 0 && height > 0);
+    window.xdg.ack_configure(window.xdg.last_serial.get())?;
+    window.surface.map(width + 100, height + 100).await?;
+    client.sync().await;
+
+    tassert!(run.state.clients.get(client.server.id).is_ok());
+    tassert!(window.tl.server.tl_data().is_fullscreen.get());
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use {
+    crate::{
+        format::ARGB8888,
+        it::{test_error::TestResult, testrun::TestRun},
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that shm memory stays accounted to the client while buffers reference it and
+/// that growing a pool does not count the old mapping against the limit
+async fn test(run: Rc<TestRun>) -> TestResult {
+    run.backend.install_default()?;
+
+    let client = run.create_client().await?;
+    client.sync().await;
+    tassert_eq!(client.server.shm_size.get(), 0);
+
+    let pool = client.shm.create_pool(64)?;
+    let buffer = pool.create_buffer(0, 4, 4, 16, ARGB8888)?;
+    client.sync().await;
+    tassert_eq!(client.server.shm_size.get(), 64);
+
+    pool.destroy()?;
+    client.sync().await;
+    tassert_eq!(client.server.shm_size.get(), 64);
+
+    buffer.buffer.destroy()?;
+    client.sync().await;
+    tassert_eq!(client.server.shm_size.get(), 0);
+
+    run.cfg.set_client_shm_limit(Some(128))?;
+    let pool = client.shm.create_pool(64)?;
+    pool.resize(100)?;
+    client.sync().await;
+    tassert_eq!(client.server.shm_size.get(), 100);
+
+    Ok(())
+}
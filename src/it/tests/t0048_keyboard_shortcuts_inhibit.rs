@@ -0,0 +1,72 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    jay_config::keyboard::syms::SYM_F13,
+    std::rc::Rc,
+};
+
+testcase!();
+
+const KEYMAP: &str = r#"
+xkb_keymap {
+    xkb_keycodes {
+          <1> = 9; # ESC
+    };
+    xkb_types {
+    };
+    xkb_compatibility {
+    };
+    xkb_symbols {
+        key <1> { [ F13 ] };
+    };
+};
+"#;
+
+/// Test that shortcuts are only inhibited by focused windows that are allowed to do so
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+    ds.mouse.rel(1.0, 1.0);
+
+    let keymap = run.cfg.parse_keymap(KEYMAP)?;
+    run.cfg.set_keymap(ds.seat.id(), keymap)?;
+    run.cfg.add_shortcut(ds.seat.id(), SYM_F13)?;
+    let shortcut = (ds.seat.id(), SYM_F13.into());
+
+    let client = run.create_client().await?;
+    let seat = client.get_default_seat().await?;
+    let manager = client.registry.get_shortcuts_inhibit_manager().await?;
+
+    let window = client.create_window().await?;
+    window.tl.core.set_title("vm")?;
+    window.map2().await?;
+
+    let inhibitor = manager.inhibit_shortcuts(&window.surface, &seat.seat)?;
+    client.sync().await;
+    tassert!(!inhibitor.active.get());
+
+    ds.kb.press(1);
+    client.sync().await;
+    tassert!(run.cfg.invoked_shortcuts.remove(&shortcut).is_some());
+
+    let matcher = run.cfg.create_title_matcher("vm")?;
+    run.cfg
+        .set_window_matcher_may_inhibit_shortcuts(matcher, true)?;
+    inhibitor.destroy()?;
+    let inhibitor = manager.inhibit_shortcuts(&window.surface, &seat.seat)?;
+    client.sync().await;
+    tassert!(inhibitor.active.get());
+
+    ds.kb.press(1);
+    client.sync().await;
+    tassert!(run.cfg.invoked_shortcuts.is_empty());
+
+    let window2 = client.create_window().await?;
+    window2.map2().await?;
+    client.sync().await;
+    tassert!(!inhibitor.active.get());
+
+    ds.kb.press(1);
+    client.sync().await;
+    tassert!(run.cfg.invoked_shortcuts.remove(&shortcut).is_some());
+
+    Ok(())
+}
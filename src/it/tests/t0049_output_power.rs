@@ -0,0 +1,47 @@
+use {
+    crate::it::{
+        test_error::{TestErrorExt, TestResult},
+        testrun::TestRun,
+    },
+    std::rc::Rc,
+};
+
+testcase!();
+
+const MODE_OFF: u32 = 0;
+const MODE_ON: u32 = 1;
+
+/// Test that output power objects change the active state of the connector
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let output = client.get_output(&ds.output).await?;
+    let manager = client.registry.get_output_power_manager().await?;
+    let power1 = manager.get_output_power(&output)?;
+    let power2 = manager.get_output_power(&output)?;
+    client.sync().await;
+    tassert_eq!(power1.mode.get(), Some(MODE_ON));
+    tassert_eq!(power2.mode.get(), Some(MODE_ON));
+
+    let idle = ds.connector.idle.expect()?;
+
+    power1.set_mode(MODE_OFF)?;
+    client.sync().await;
+    tassert_eq!(idle.next().with_context(|| "off")?, true);
+    tassert!(idle.next().is_err());
+    tassert_eq!(power1.mode.get(), Some(MODE_OFF));
+    tassert_eq!(power2.mode.get(), Some(MODE_OFF));
+
+    power2.set_mode(MODE_ON)?;
+    client.sync().await;
+    tassert_eq!(idle.next().with_context(|| "on")?, false);
+    tassert!(idle.next().is_err());
+    tassert_eq!(power1.mode.get(), Some(MODE_ON));
+    tassert_eq!(power2.mode.get(), Some(MODE_ON));
+
+    tassert!(!power1.failed.get());
+    tassert!(!power2.failed.get());
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use {
+    crate::it::{test_error::TestResult, testrun::TestRun},
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that gamma controls are exclusive and that their LUT is applied and reset
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let output = client.get_output(&ds.output).await?;
+    let manager = client.registry.get_gamma_control_manager().await?;
+
+    let gc1 = manager.get_gamma_control(&output)?;
+    client.sync().await;
+    tassert_eq!(gc1.size.get(), Some(4));
+    tassert!(!gc1.failed.get());
+
+    let gc2 = manager.get_gamma_control(&output)?;
+    client.sync().await;
+    tassert_eq!(gc2.size.get(), None);
+    tassert!(gc2.failed.get());
+
+    let red = [0, 1, 2, 3];
+    let green = [4, 5, 6, 7];
+    let blue = [8, 9, 10, 0xffff];
+    gc1.set_gamma(&red, &green, &blue)?;
+    client.sync().await;
+    tassert!(!gc1.failed.get());
+    let Some(lut) = ds.connector.gamma_lut.get() else {
+        bail!("gamma LUT was not applied");
+    };
+    tassert_eq!(&lut.red[..], &red[..]);
+    tassert_eq!(&lut.green[..], &green[..]);
+    tassert_eq!(&lut.blue[..], &blue[..]);
+
+    gc1.destroy()?;
+    client.sync().await;
+    tassert!(ds.connector.gamma_lut.get().is_none());
+
+    let gc3 = manager.get_gamma_control(&output)?;
+    client.sync().await;
+    tassert_eq!(gc3.size.get(), Some(4));
+    tassert!(!gc3.failed.get());
+
+    Ok(())
+}
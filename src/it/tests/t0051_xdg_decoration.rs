@@ -0,0 +1,46 @@
+use {
+    crate::{
+        ifs::wl_surface::xdg_surface::xdg_toplevel::Decoration,
+        it::{
+            test_error::TestResult,
+            test_ifs::test_xdg_toplevel_decoration::{CLIENT_SIDE, SERVER_SIDE},
+            testrun::TestRun,
+        },
+    },
+    jay_config::DecorationMode,
+    std::rc::Rc,
+};
+
+testcase!();
+
+/// Test that windows use server-side decorations unless the configuration allows
+/// client-side decorations
+async fn test(run: Rc<TestRun>) -> TestResult {
+    let _ds = run.create_default_setup().await?;
+
+    let client = run.create_client().await?;
+    let manager = client.registry.get_decoration_manager().await?;
+    let win = client.create_window().await?;
+    let deco = manager.get_toplevel_decoration(&win.tl)?;
+    client.sync().await;
+    tassert_eq!(deco.mode.get(), Some(SERVER_SIDE));
+
+    deco.set_mode(CLIENT_SIDE)?;
+    client.sync().await;
+    tassert_eq!(deco.mode.get(), Some(SERVER_SIDE));
+    tassert_eq!(win.tl.server.decoration.get(), Decoration::Server);
+
+    run.cfg
+        .set_decoration_mode(DecorationMode::CLIENT_PREFERENCE)?;
+    deco.set_mode(CLIENT_SIDE)?;
+    client.sync().await;
+    tassert_eq!(deco.mode.get(), Some(CLIENT_SIDE));
+    tassert_eq!(win.tl.server.decoration.get(), Decoration::Client);
+
+    deco.unset_mode()?;
+    client.sync().await;
+    tassert_eq!(deco.mode.get(), Some(SERVER_SIDE));
+    tassert_eq!(win.tl.server.decoration.get(), Decoration::Server);
+
+    Ok(())
+}
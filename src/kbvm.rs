@@ -55,6 +55,7 @@ pub struct KbvmMap {
     pub lookup_table: LookupTable,
     pub map: KeymapFd,
     pub xwayland_map: KeymapFd,
+    pub group_names: Vec<String>,
 }
 
 pub struct KbvmState {
@@ -95,10 +96,43 @@ impl KbvmContext {
             map: create_keymap_memfd(&map, false).map_err(KbvmError::KeymapMemfd)?,
             xwayland_map: create_keymap_memfd(&map, true).map_err(KbvmError::KeymapMemfd)?,
             lookup_table: builder.build_lookup_table(),
+            group_names: group_names(&map.format().to_string()),
         }))
     }
 }
 
+/// Extracts the `name[GroupN]` entries of the symbols section.
+fn group_names(keymap: &str) -> Vec<String> {
+    let mut names = vec![];
+    for line in keymap.lines() {
+        let Some(rest) = line.trim_start().strip_prefix("name[") else {
+            continue;
+        };
+        let Some((group, rest)) = rest.split_once(']') else {
+            continue;
+        };
+        let Some(idx) = group
+            .get(5..)
+            .filter(|_| group[..5].eq_ignore_ascii_case("group"))
+            .and_then(|idx| idx.parse::<usize>().ok())
+            .filter(|idx| *idx > 0)
+        else {
+            continue;
+        };
+        let Some((_, name)) = rest.split_once('"') else {
+            continue;
+        };
+        let Some((name, _)) = name.split_once('"') else {
+            continue;
+        };
+        if names.len() < idx {
+            names.resize(idx, String::new());
+        }
+        names[idx - 1] = name.to_string();
+    }
+    names
+}
+
 fn create_keymap_memfd(map: &Keymap, xwayland: bool) -> Result<KeymapFd, OsError> {
     let mut format = map.format();
     if xwayland {
@@ -120,6 +154,17 @@ fn create_keymap_memfd(map: &Keymap, xwayland: bool) -> Result<KeymapFd, OsError
 }
 
 impl KbvmMap {
+    pub fn num_groups(&self) -> u32 {
+        self.group_names.len().max(1) as u32
+    }
+
+    pub fn group_name(&self, group: u32) -> &str {
+        self.group_names
+            .get(group as usize)
+            .map(|n| &**n)
+            .unwrap_or_default()
+    }
+
     pub fn state(self: &Rc<Self>, id: KeyboardStateId) -> KbvmState {
         KbvmState {
             map: self.clone(),
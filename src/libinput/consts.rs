@@ -203,3 +203,27 @@ cenum! {
     LIBINPUT_CONFIG_MIDDLE_EMULATION_DISABLED = 0,
     LIBINPUT_CONFIG_MIDDLE_EMULATION_ENABLED = 1,
 }
+
+cenum! {
+    ConfigScrollMethod, LIBINPUT_CONFIG_SCROLL_METHOD;
+
+    LIBINPUT_CONFIG_SCROLL_NO_SCROLL = 0,
+    LIBINPUT_CONFIG_SCROLL_2FG = 1 << 0,
+    LIBINPUT_CONFIG_SCROLL_EDGE = 1 << 1,
+    LIBINPUT_CONFIG_SCROLL_ON_BUTTON_DOWN = 1 << 2,
+}
+
+cenum! {
+    ConfigDwtState, LIBINPUT_CONFIG_DWT_STATE;
+
+    LIBINPUT_CONFIG_DWT_DISABLED = 0,
+    LIBINPUT_CONFIG_DWT_ENABLED = 1,
+}
+
+cenum! {
+    ConfigSendEventsMode, LIBINPUT_CONFIG_SEND_EVENTS_MODE;
+
+    LIBINPUT_CONFIG_SEND_EVENTS_ENABLED = 0,
+    LIBINPUT_CONFIG_SEND_EVENTS_DISABLED = 1 << 0,
+    LIBINPUT_CONFIG_SEND_EVENTS_DISABLED_ON_EXTERNAL_MOUSE = 1 << 1,
+}
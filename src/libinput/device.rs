@@ -2,10 +2,11 @@ use {
     crate::libinput::{
         LibInput,
         consts::{
-            AccelProfile, ConfigClickMethod, ConfigDragLockState, ConfigDragState,
-            ConfigMiddleEmulationState, ConfigTapState, DeviceCapability,
-            LIBINPUT_CONFIG_DRAG_DISABLED, LIBINPUT_CONFIG_DRAG_ENABLED,
+            AccelProfile, ConfigClickMethod, ConfigDragLockState, ConfigDragState, ConfigDwtState,
+            ConfigMiddleEmulationState, ConfigScrollMethod, ConfigSendEventsMode, ConfigTapState,
+            DeviceCapability, LIBINPUT_CONFIG_DRAG_DISABLED, LIBINPUT_CONFIG_DRAG_ENABLED,
             LIBINPUT_CONFIG_DRAG_LOCK_DISABLED, LIBINPUT_CONFIG_DRAG_LOCK_ENABLED,
+            LIBINPUT_CONFIG_DWT_DISABLED, LIBINPUT_CONFIG_DWT_ENABLED,
             LIBINPUT_CONFIG_MIDDLE_EMULATION_DISABLED, LIBINPUT_CONFIG_MIDDLE_EMULATION_ENABLED,
             LIBINPUT_CONFIG_TAP_DISABLED, LIBINPUT_CONFIG_TAP_ENABLED,
         },
@@ -17,15 +18,21 @@ use {
             libinput_device_config_calibration_has_matrix,
             libinput_device_config_calibration_set_matrix, libinput_device_config_click_get_method,
             libinput_device_config_click_get_methods, libinput_device_config_click_set_method,
-            libinput_device_config_left_handed_get,
+            libinput_device_config_dwt_get_enabled, libinput_device_config_dwt_is_available,
+            libinput_device_config_dwt_set_enabled, libinput_device_config_left_handed_get,
             libinput_device_config_left_handed_is_available,
             libinput_device_config_left_handed_set,
             libinput_device_config_middle_emulation_get_enabled,
             libinput_device_config_middle_emulation_is_available,
             libinput_device_config_middle_emulation_set_enabled,
+            libinput_device_config_scroll_get_method, libinput_device_config_scroll_get_methods,
             libinput_device_config_scroll_get_natural_scroll_enabled,
             libinput_device_config_scroll_has_natural_scroll,
+            libinput_device_config_scroll_set_method,
             libinput_device_config_scroll_set_natural_scroll_enabled,
+            libinput_device_config_send_events_get_mode,
+            libinput_device_config_send_events_get_modes,
+            libinput_device_config_send_events_set_mode,
             libinput_device_config_tap_get_drag_enabled,
             libinput_device_config_tap_get_drag_lock_enabled,
             libinput_device_config_tap_get_enabled, libinput_device_config_tap_get_finger_count,
@@ -256,6 +263,56 @@ impl<'a> LibInputDevice<'a> {
         unsafe { libinput_device_config_middle_emulation_is_available(self.dev) != 0 }
     }
 
+    pub fn has_scroll_methods(&self) -> bool {
+        unsafe { libinput_device_config_scroll_get_methods(self.dev) != 0 }
+    }
+
+    pub fn scroll_method(&self) -> ConfigScrollMethod {
+        unsafe { ConfigScrollMethod(libinput_device_config_scroll_get_method(self.dev)) }
+    }
+
+    pub fn set_scroll_method(&self, method: ConfigScrollMethod) {
+        unsafe {
+            libinput_device_config_scroll_set_method(self.dev, method.raw() as _);
+        }
+    }
+
+    pub fn set_disable_while_typing_enabled(&self, enabled: bool) {
+        let enabled = match enabled {
+            true => LIBINPUT_CONFIG_DWT_ENABLED,
+            false => LIBINPUT_CONFIG_DWT_DISABLED,
+        };
+        unsafe {
+            libinput_device_config_dwt_set_enabled(self.dev, enabled.raw() as _);
+        }
+    }
+
+    pub fn disable_while_typing_enabled(&self) -> bool {
+        let enabled = unsafe { ConfigDwtState(libinput_device_config_dwt_get_enabled(self.dev)) };
+        match enabled {
+            LIBINPUT_CONFIG_DWT_ENABLED => true,
+            _ => false,
+        }
+    }
+
+    pub fn disable_while_typing_available(&self) -> bool {
+        unsafe { libinput_device_config_dwt_is_available(self.dev) != 0 }
+    }
+
+    pub fn has_send_events_modes(&self) -> bool {
+        unsafe { libinput_device_config_send_events_get_modes(self.dev) != 0 }
+    }
+
+    pub fn send_events_mode(&self) -> ConfigSendEventsMode {
+        unsafe { ConfigSendEventsMode(libinput_device_config_send_events_get_mode(self.dev) as _) }
+    }
+
+    pub fn set_send_events_mode(&self, mode: ConfigSendEventsMode) {
+        unsafe {
+            libinput_device_config_send_events_set_mode(self.dev, mode.raw() as _);
+        }
+    }
+
     pub fn device_group(&self) -> LibInputDeviceGroup<'_> {
         LibInputDeviceGroup {
             group: unsafe { libinput_device_get_device_group(self.dev) },
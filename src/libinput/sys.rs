@@ -134,6 +134,31 @@ unsafe extern "C" {
         device: *mut libinput_device,
     ) -> c::c_int;
 
+    pub fn libinput_device_config_scroll_get_methods(device: *mut libinput_device) -> u32;
+    pub fn libinput_device_config_scroll_get_method(
+        device: *mut libinput_device,
+    ) -> libinput_config_scroll_method;
+    pub fn libinput_device_config_scroll_set_method(
+        device: *mut libinput_device,
+        method: libinput_config_scroll_method,
+    ) -> libinput_config_status;
+
+    pub fn libinput_device_config_dwt_is_available(device: *mut libinput_device) -> c::c_int;
+    pub fn libinput_device_config_dwt_set_enabled(
+        device: *mut libinput_device,
+        enable: libinput_config_dwt_state,
+    ) -> libinput_config_status;
+    pub fn libinput_device_config_dwt_get_enabled(
+        device: *mut libinput_device,
+    ) -> libinput_config_dwt_state;
+
+    pub fn libinput_device_config_send_events_get_modes(device: *mut libinput_device) -> u32;
+    pub fn libinput_device_config_send_events_get_mode(device: *mut libinput_device) -> u32;
+    pub fn libinput_device_config_send_events_set_mode(
+        device: *mut libinput_device,
+        mode: u32,
+    ) -> libinput_config_status;
+
     pub fn libinput_event_destroy(event: *mut libinput_event);
     pub fn libinput_event_get_type(event: *mut libinput_event) -> libinput_event_type;
     pub fn libinput_event_get_device(event: *mut libinput_event) -> *mut libinput_device;
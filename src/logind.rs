@@ -6,7 +6,7 @@ use {
             org,
             org::freedesktop::login1::{
                 seat::SwitchToReply,
-                session::{PauseDevice, ResumeDevice, TakeDeviceReply},
+                session::{Lock, PauseDevice, ResumeDevice, TakeDeviceReply},
             },
         },
     },
@@ -138,6 +138,18 @@ impl Session {
             )
     }
 
+    pub fn on_lock<F>(&self, f: F) -> Result<SignalHandler, DbusError>
+    where
+        F: Fn(Lock) + 'static,
+    {
+        self.socket
+            .handle_signal::<org::freedesktop::login1::session::Lock, _>(
+                Some(LOGIND_NAME),
+                Some(&self.session_path),
+                f,
+            )
+    }
+
     pub fn device_paused(&self, major: u32, minor: u32) {
         self.socket.call_noreply(
             LOGIND_NAME,
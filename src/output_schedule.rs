@@ -1,7 +1,7 @@
 use {
     crate::{
         async_engine::AsyncEngine,
-        backend::HardwareCursor,
+        backend::{BackendVrrRange, HardwareCursor},
         ifs::wl_output::PersistentOutputState,
         io_uring::{IoUring, IoUringError},
         state::ConnectorData,
@@ -42,6 +42,7 @@ pub struct OutputSchedule {
     eng: Rc<AsyncEngine>,
 
     vrr_enabled: Cell<bool>,
+    vrr_range: Option<BackendVrrRange>,
 
     hardware_cursor_change: Cell<Change>,
     software_cursor_change: Cell<Change>,
@@ -55,6 +56,7 @@ impl OutputSchedule {
         eng: &Rc<AsyncEngine>,
         connector: &Rc<ConnectorData>,
         persistent: &Rc<PersistentOutputState>,
+        vrr_range: Option<BackendVrrRange>,
     ) -> Self {
         let slf = Self {
             changed: Default::default(),
@@ -63,6 +65,7 @@ impl OutputSchedule {
             ring: ring.clone(),
             eng: eng.clone(),
             vrr_enabled: Default::default(),
+            vrr_range,
             hardware_cursor_change: Cell::new(Change::None),
             software_cursor_change: Cell::new(Change::None),
             hardware_cursor: Default::default(),
@@ -119,7 +122,7 @@ impl OutputSchedule {
     }
 
     pub fn set_cursor_hz(&self, hz: f64) {
-        let (hz, delta) = match map_cursor_hz(hz) {
+        let (hz, mut delta) = match map_cursor_hz(hz) {
             None => {
                 log::warn!("Ignoring cursor frequency {hz}");
                 return;
@@ -127,6 +130,22 @@ impl OutputSchedule {
             Some(v) => v,
         };
         self.persistent.vrr_cursor_hz.set(hz);
+        if let Some(range) = self.vrr_range
+            && let Some(hz) = hz
+            && hz > 0.0
+        {
+            let clamped = hz.clamp(range.min_hz as f64, range.max_hz as f64);
+            if clamped != hz {
+                log::info!(
+                    "Clamping cursor frequency {hz} to the VRR range {}-{} Hz",
+                    range.min_hz,
+                    range.max_hz,
+                );
+                if let Some((_, d)) = map_cursor_hz(clamped) {
+                    delta = d;
+                }
+            }
+        }
         self.cursor_delta_nsec.set(delta);
         self.trigger();
     }
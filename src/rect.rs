@@ -220,13 +220,12 @@ where
         dx * dx + dy * dy
     }
 
-    #[expect(dead_code)]
     pub fn contains_rect<U>(&self, rect: &Rect<U>) -> bool
     where
         U: Tag,
     {
         self.raw.x1 <= rect.raw.x1
-            && self.raw.y1 <= rect.raw.x1
+            && self.raw.y1 <= rect.raw.y1
             && rect.raw.x2 <= self.raw.x2
             && rect.raw.y2 <= self.raw.y2
     }
@@ -687,3 +687,12 @@ fn intersect3() {
         ],
     );
 }
+
+#[test]
+fn contains_rect1() {
+    let r1 = Rect::new(0, 10, 100, 110).unwrap();
+    assert!(r1.contains_rect(&Rect::new(0, 10, 100, 110).unwrap()));
+    assert!(r1.contains_rect(&Rect::new(20, 20, 30, 30).unwrap()));
+    assert!(!r1.contains_rect(&Rect::new(20, 5, 30, 30).unwrap()));
+    assert!(!r1.contains_rect(&Rect::new(20, 20, 30, 111).unwrap()));
+}
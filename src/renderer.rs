@@ -1,6 +1,6 @@
 use {
     crate::{
-        gfx_api::{AcquireSync, GfxApiOpt, ReleaseSync, SampleRect},
+        gfx_api::{AcquireSync, GfxApiOpt, ReleaseSync, SampleRect, scale_ops},
         icons::{IconState, SizedIcons},
         ifs::wl_surface::{
             SurfaceBuffer, WlSurface,
@@ -8,14 +8,14 @@ use {
             xdg_surface::{XdgSurface, xdg_toplevel::XdgToplevel},
             zwlr_layer_surface_v1::ZwlrLayerSurfaceV1,
         },
-        rect::Rect,
+        rect::{Rect, Region},
         renderer::renderer_base::RendererBase,
         scale::Scale,
         state::State,
         theme::Color,
         tree::{
             ContainerNode, DisplayNode, FloatNode, OutputNode, PlaceholderNode, ToplevelData,
-            ToplevelNodeBase, WorkspaceNode,
+            ToplevelNodeBase, WorkspaceNode, window_animation_scale,
         },
     },
     std::{ops::Deref, rc::Rc, slice},
@@ -29,6 +29,7 @@ pub struct Renderer<'a> {
     pub logical_extents: Rect,
     pub pixel_extents: Rect,
     pub icons: Option<Rc<SizedIcons>>,
+    pub toplevel_alpha: Option<f32>,
 }
 
 impl Renderer<'_> {
@@ -169,7 +170,7 @@ impl Renderer<'_> {
                 y += th + 1;
             }
             if let Some(ws) = output.workspace.get() {
-                self.render_workspace(&ws, x, y);
+                self.render_output_workspace(output, &ws, x, y);
             }
         }
         macro_rules! render_stacked {
@@ -188,6 +189,7 @@ impl Renderer<'_> {
         }
         render_stacked!(self.state.root.stacked);
         if fullscreen.is_none() {
+            self.render_closing_windows(output, x, y);
             render_layer!(output.layers[2]);
         }
         render_layer!(output.layers[3]);
@@ -202,9 +204,65 @@ impl Renderer<'_> {
         }
     }
 
+    fn render_closing_windows(&mut self, output: &OutputNode, x: i32, y: i32) {
+        let now = self.state.now();
+        for window in output.closing_windows.borrow().iter() {
+            if window.scale != self.base.scale
+                || window.transform != self.base.transform
+                || window.fb_size != (self.base.fb_width, self.base.fb_height)
+            {
+                continue;
+            }
+            let t = window.progress(now);
+            let start = self.base.ops.len();
+            self.base.ops.push(GfxApiOpt::Sync);
+            for op in &window.ops {
+                let mut op = op.clone();
+                op.multiply_alpha((1.0 - t) as f32);
+                self.base.ops.push(op);
+            }
+            let (width, height) = window.rect.size();
+            let cx = window.rect.x1() as f32 + width as f32 / 2.0;
+            let cy = window.rect.y1() as f32 + height as f32 / 2.0;
+            let from = self.base.fb_point(cx, cy);
+            let to = self.base.fb_point(cx + x as f32, cy + y as f32);
+            let factor = window_animation_scale(1.0 - t);
+            scale_ops(&mut self.base.ops[start..], factor, from, to);
+        }
+    }
+
+    fn render_output_workspace(
+        &mut self,
+        output: &OutputNode,
+        workspace: &WorkspaceNode,
+        x: i32,
+        y: i32,
+    ) {
+        if let Some(animation) = output.workspace_animation.get()
+            && !animation.done.get()
+            && !self.state.low_power_mode.get()
+        {
+            let width = output.workspace_rect.get().width();
+            let dx = (width as f64 * animation.progress(self.state.now())).round() as i32;
+            let (old_x, new_x) = match animation.forward {
+                true => (x - dx, x + width - dx),
+                false => (x + dx, x - width + dx),
+            };
+            if let Some(old) = animation.old.get()
+                && !old.visible.get()
+            {
+                self.render_workspace(&old, old_x, y);
+            }
+            self.render_workspace(workspace, new_x, y);
+            return;
+        }
+        self.render_workspace(workspace, x, y);
+    }
+
     pub fn render_workspace(&mut self, workspace: &WorkspaceNode, x: i32, y: i32) {
         if let Some(node) = workspace.container.get() {
-            self.render_container(&node, x, y)
+            let gap = workspace.outer_gap();
+            self.render_container(&node, x + gap, y + gap)
         }
     }
 
@@ -321,15 +379,58 @@ impl Renderer<'_> {
     }
 
     pub fn render_xwindow(&mut self, tl: &Xwindow, x: i32, y: i32, bounds: Option<&Rect>) {
+        let prev = self.set_toplevel_alpha(tl.tl_data());
+        let start = self.base.ops.len();
         self.render_surface(&tl.x.surface, x, y, bounds);
+        self.apply_open_animation_scale(tl.tl_data(), start, x, y);
+        self.toplevel_alpha = prev;
         self.render_tl_aux(tl.tl_data(), bounds, true);
     }
 
     pub fn render_xdg_toplevel(&mut self, tl: &XdgToplevel, x: i32, y: i32, bounds: Option<&Rect>) {
+        let prev = self.set_toplevel_alpha(tl.tl_data());
+        let start = self.base.ops.len();
         self.render_xdg_surface(&tl.xdg, x, y, bounds);
+        self.apply_open_animation_scale(tl.tl_data(), start, x, y);
+        self.toplevel_alpha = prev;
         self.render_tl_aux(tl.tl_data(), bounds, true);
     }
 
+    fn set_toplevel_alpha(&mut self, tl_data: &ToplevelData) -> Option<f32> {
+        let mut alpha = None;
+        if !tl_data.self_or_ancestor_is_fullscreen.get() {
+            let mut a = tl_data.opacity.get();
+            if !tl_data.active() {
+                a *= self.state.inactive_window_opacity.get();
+            }
+            if !self.state.low_power_mode.get()
+                && let Some(t) = tl_data.open_animation_progress(self.state.now())
+            {
+                a *= t as f32;
+            }
+            if a < 1.0 {
+                alpha = Some(a);
+            }
+        }
+        std::mem::replace(&mut self.toplevel_alpha, alpha)
+    }
+
+    fn apply_open_animation_scale(&mut self, tl_data: &ToplevelData, start: usize, x: i32, y: i32) {
+        if tl_data.self_or_ancestor_is_fullscreen.get() || self.state.low_power_mode.get() {
+            return;
+        }
+        let Some(t) = tl_data.open_animation_progress(self.state.now()) else {
+            return;
+        };
+        let (width, height) = tl_data.pos.get().size();
+        let center = self.base.fb_point(
+            x as f32 + width as f32 / 2.0,
+            y as f32 + height as f32 / 2.0,
+        );
+        let factor = window_animation_scale(t);
+        scale_ops(&mut self.base.ops[start..], factor, center, center);
+    }
+
     pub fn render_xdg_surface(
         &mut self,
         xdg: &XdgSurface,
@@ -453,7 +554,10 @@ impl Renderer<'_> {
         tsize: (i32, i32),
         bounds: Option<&Rect>,
     ) {
-        let alpha = surface.alpha();
+        let alpha = match (surface.alpha(), self.toplevel_alpha) {
+            (Some(a), Some(b)) => Some(a * b),
+            (a, b) => a.or(b),
+        };
         let cd = surface.color_description();
         if let Some(tex) = buffer.buffer.get_texture(surface) {
             let mut opaque = surface.opaque();
@@ -525,6 +629,7 @@ impl Renderer<'_> {
         ];
         let srgb_srgb = self.state.color_manager.srgb_srgb();
         let srgb = &srgb_srgb.linear;
+        self.render_float_shadow(pos.at_point(x, y));
         self.base.fill_boxes(&borders, &bc, srgb);
         let title = [Rect::new_sized(x + bw, y + bw, pos.width() - 2 * bw, th).unwrap()];
         self.base.fill_boxes(&title, &tc, srgb);
@@ -567,6 +672,34 @@ impl Renderer<'_> {
             }
             x1 += th;
         }
+        if self.state.show_close_icon.get()
+            && rect.width() >= th
+            && let Some(icons) = &self.icons
+        {
+            let icon = if floating.active.get() {
+                &icons.close_focused_title
+            } else if floating.attention_requested.get() {
+                &icons.close_attention_requested
+            } else {
+                &icons.close_unfocused_title
+            };
+            let (x, y) = self.base.scale_point(rect.x2() - th, y1);
+            self.base.render_texture(
+                icon,
+                None,
+                x,
+                y,
+                None,
+                None,
+                self.base.scale,
+                Some(&bounds),
+                None,
+                AcquireSync::None,
+                ReleaseSync::None,
+                false,
+                srgb_srgb,
+            );
+        }
         if let Some(title) = floating.title_textures.borrow().get(&self.base.scale)
             && let Some(texture) = title.texture()
         {
@@ -598,6 +731,38 @@ impl Renderer<'_> {
         child.node_render(self, body.x1(), body.y1(), Some(&scissor_body));
     }
 
+    fn render_float_shadow(&mut self, window: Rect) {
+        let theme = &self.state.theme;
+        let size = theme.sizes.shadow_size.get();
+        if size <= 0 || self.state.low_power_mode.get() {
+            return;
+        }
+        let color = theme.colors.shadow.get();
+        let shadow = window.move_(
+            theme.sizes.shadow_offset_x.get(),
+            theme.sizes.shadow_offset_y.get(),
+        );
+        let window = Region::new(window);
+        let srgb_srgb = self.state.color_manager.srgb_srgb();
+        let srgb = &srgb_srgb.linear;
+        // The shadow is drawn as concentric rings whose alpha decreases linearly with the
+        // distance from the window. The part below the window itself is never drawn.
+        let mut inner = Region::empty();
+        for i in 0..=size {
+            let outer = Region::new(Rect::new_unchecked(
+                shadow.x1() - i,
+                shadow.y1() - i,
+                shadow.x2() + i,
+                shadow.y2() + i,
+            ));
+            let ring = outer.subtract(&inner).subtract(&window);
+            let alpha = 1.0 - i as f32 / (size + 1) as f32;
+            self.base
+                .fill_boxes3(ring.rects(), &color, Some(alpha), srgb, 0, 0, false);
+            inner = outer;
+        }
+    }
+
     pub fn render_layer_surface(&mut self, surface: &ZwlrLayerSurfaceV1, x: i32, y: i32) {
         let (dx, dy) = surface.surface.extents.get().position();
         self.render_surface(&surface.surface, x - dx, y - dy, None);
@@ -44,6 +44,15 @@ impl RendererBase<'_> {
         (x, y)
     }
 
+    /// Converts a logical point to framebuffer coordinates.
+    pub fn fb_point(&self, x: f32, y: f32) -> [f32; 2] {
+        let (x, y) = self.scale_point_f(x, y);
+        [
+            2.0 * x / self.fb_width - 1.0,
+            2.0 * y / self.fb_height - 1.0,
+        ]
+    }
+
     pub fn scale_rect(&self, mut rect: Rect) -> Rect {
         if self.scaled {
             let [x1, y1, x2, y2] =
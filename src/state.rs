@@ -4,13 +4,13 @@ use {
         async_engine::{AsyncEngine, SpawnedFuture},
         backend::{
             Backend, BackendConnectorState, BackendConnectorStateSerials, BackendDrmDevice,
-            BackendEvent, Connector, ConnectorId, ConnectorIds, DrmDeviceId, DrmDeviceIds,
-            HardwareCursorUpdate, InputDevice, InputDeviceGroupIds, InputDeviceId, InputDeviceIds,
-            MonitorInfo, transaction::BackendConnectorTransactionError,
+            BackendEvent, BackendGammaLut, Connector, ConnectorId, ConnectorIds, DrmDeviceId,
+            DrmDeviceIds, HardwareCursorUpdate, InputDevice, InputDeviceGroupIds, InputDeviceId,
+            InputDeviceIds, MonitorInfo, transaction::BackendConnectorTransactionError,
         },
         backends::dummy::DummyBackend,
         cli::RunArgs,
-        client::{Client, ClientId, Clients, NUM_CACHED_SERIAL_RANGES, SerialRange},
+        client::{Client, ClientCaps, ClientId, Clients, NUM_CACHED_SERIAL_RANGES, SerialRange},
         clientmem::ClientMemOffset,
         cmm::{cmm_description::ColorDescription, cmm_manager::ColorManager},
         compositor::LIBEI_SOCKET,
@@ -48,6 +48,7 @@ use {
                 DataOfferIds, DataSourceIds, data_control::DataControlDeviceIds,
                 x_data_device::XIpcDeviceIds,
             },
+            jay_output_watcher::JayOutputWatcher,
             jay_render_ctx::JayRenderCtx,
             jay_screencast::JayScreencast,
             jay_seat_events::JaySeatEvents,
@@ -75,7 +76,10 @@ use {
             wp_drm_lease_connector_v1::WpDrmLeaseConnectorV1,
             wp_drm_lease_device_v1::WpDrmLeaseDeviceV1Global,
             wp_linux_drm_syncobj_manager_v1::WpLinuxDrmSyncobjManagerV1Global,
+            xdg_activation_token_v1::ActivationTokenData,
             zwlr_foreign_toplevel_manager_v1::ZwlrForeignToplevelManagerV1,
+            zwlr_gamma_control_v1::ZwlrGammaControlV1,
+            zwlr_output_power_v1::ZwlrOutputPowerV1,
             zwlr_screencopy_frame_v1::ZwlrScreencopyFrameV1,
             zwp_linux_dmabuf_feedback_v1::ZwpLinuxDmabufFeedbackV1,
             zwp_linux_dmabuf_v1::ZwpLinuxDmabufV1Global,
@@ -93,17 +97,19 @@ use {
         theme::{Color, Theme},
         time::Time,
         tree::{
-            ContainerNode, ContainerSplit, Direction, DisplayNode, FindTreeUsecase, FloatNode,
-            FoundNode, LatchListener, Node, NodeIds, NodeVisitorBase, OutputNode, PlaceholderNode,
-            TearingMode, ToplevelData, ToplevelNode, ToplevelNodeBase, VrrMode, WorkspaceNode,
-            generic_node_visitor,
+            ContainerNode, ContainerSplit, ContainingNode, Direction, DisplayNode, FindTreeUsecase,
+            FloatNode, FoundNode, LatchListener, Node, NodeIds, NodeVisitorBase, OutputNode,
+            PlaceholderNode, Scratchpad, TearingMode, ToplevelData, ToplevelNode, ToplevelNodeBase,
+            ToplevelType, VrrMode, WorkspaceLayout, WorkspaceNode, WsMoveConfig,
+            generic_node_visitor, move_ws_to_output,
         },
         utils::{
             activation_token::ActivationToken, asyncevent::AsyncEvent, bindings::Bindings,
             clonecell::CloneCell, copyhashmap::CopyHashMap, errorfmt::ErrorFmt,
             event_listener::EventSource, fdcloser::FdCloser, hash_map_ext::HashMapExt,
-            linkedlist::LinkedList, numcell::NumCell, queue::AsyncQueue, refcounted::RefCounted,
-            run_toplevel::RunToplevel, toplevel_identifier::ToplevelIdentifier,
+            linkedlist::LinkedList, numcell::NumCell, pid_info::get_ppid, queue::AsyncQueue,
+            refcounted::RefCounted, run_toplevel::RunToplevel,
+            toplevel_identifier::ToplevelIdentifier,
         },
         video::{
             dmabuf::DmaBufIds,
@@ -116,15 +122,15 @@ use {
         wheel::Wheel,
         wire::{
             ExtForeignToplevelListV1Id, ExtIdleNotificationV1Id, JayHeadManagerSessionV1Id,
-            JayRenderCtxId, JaySeatEventsId, JayWorkspaceWatcherId, ZwlrForeignToplevelManagerV1Id,
-            ZwpLinuxDmabufFeedbackV1Id,
+            JayOutputWatcherId, JayRenderCtxId, JaySeatEventsId, JayWorkspaceWatcherId,
+            ZwlrForeignToplevelManagerV1Id, ZwlrOutputPowerV1Id, ZwpLinuxDmabufFeedbackV1Id,
         },
         xwayland::{self, XWaylandEvent},
     },
     ahash::{AHashMap, AHashSet},
     bstr::ByteSlice,
     jay_config::{
-        PciId,
+        DecorationMode, FocusStealingPolicy, PciId,
         video::{GfxApi, Transform},
         window::TileState,
     },
@@ -154,6 +160,7 @@ pub struct State {
     pub render_ctx_version: NumCell<u32>,
     pub render_ctx_ever_initialized: Cell<bool>,
     pub cursors: CloneCell<Option<Rc<ServerCursors>>>,
+    pub themed_cursors: CopyHashMap<Rc<String>, Rc<ServerCursors>>,
     pub wheel: Rc<Wheel>,
     pub clients: Clients,
     pub globals: Globals,
@@ -165,6 +172,7 @@ pub struct State {
     pub node_ids: NodeIds,
     pub root: Rc<DisplayNode>,
     pub workspaces: CopyHashMap<String, Rc<WorkspaceNode>>,
+    pub workspace_output_bindings: CopyHashMap<String, Rc<OutputId>>,
     pub dummy_output: CloneCell<Option<Rc<OutputNode>>>,
     pub backend_events: AsyncQueue<BackendEvent>,
     pub input_device_handlers: RefCell<AHashMap<InputDeviceId, InputDeviceData>>,
@@ -184,6 +192,7 @@ pub struct State {
     pub pending_toplevel_screencasts: AsyncQueue<Rc<JayScreencast>>,
     pub pending_screencast_reallocs_or_reconfigures: AsyncQueue<Rc<JayScreencast>>,
     pub pending_placeholder_render_textures: AsyncQueue<Rc<PlaceholderNode>>,
+    pub pending_toplevel_handle_updates: AsyncQueue<Rc<dyn ToplevelNode>>,
     pub dbus: Dbus,
     pub fdcloser: Arc<FdCloser>,
     pub logger: Option<Arc<Logger>>,
@@ -207,13 +216,19 @@ pub struct State {
     pub lock: ScreenlockState,
     pub scales: RefCounted<Scale>,
     pub cursor_sizes: RefCounted<u32>,
+    pub cursor_themes: RefCounted<Rc<String>>,
     pub hardware_tick_cursor: AsyncQueue<Option<Rc<dyn Cursor>>>,
     pub testers: RefCell<AHashMap<(ClientId, JaySeatEventsId), Rc<JaySeatEvents>>>,
     pub render_ctx_watchers: CopyHashMap<(ClientId, JayRenderCtxId), Rc<JayRenderCtx>>,
     pub workspace_watchers: CopyHashMap<(ClientId, JayWorkspaceWatcherId), Rc<JayWorkspaceWatcher>>,
+    pub output_watchers: CopyHashMap<(ClientId, JayOutputWatcherId), Rc<JayOutputWatcher>>,
     pub default_workspace_capture: Cell<bool>,
+    pub default_workspace_layout: Cell<WorkspaceLayout>,
+    pub scratchpads: CopyHashMap<String, Scratchpad>,
+    pub swallow_app_ids: CopyHashMap<String, ()>,
+    pub sandbox_capabilities: CopyHashMap<String, ClientCaps>,
     pub default_gfx_api: Cell<GfxApi>,
-    pub activation_tokens: CopyHashMap<ActivationToken, ()>,
+    pub activation_tokens: CopyHashMap<ActivationToken, ActivationTokenData>,
     pub toplevel_lists:
         CopyHashMap<(ClientId, ExtForeignToplevelListV1Id), Rc<ExtForeignToplevelListV1>>,
     pub dma_buf_ids: DmaBufIds,
@@ -249,6 +264,8 @@ pub struct State {
     pub cpu_worker: Rc<CpuWorker>,
     pub ui_drag_enabled: Cell<bool>,
     pub ui_drag_threshold_squared: Cell<i32>,
+    pub clipboard_persistence: Cell<bool>,
+    pub clipboard_persistence_max_size: Cell<u64>,
     pub toplevels: CopyHashMap<ToplevelIdentifier, Weak<dyn ToplevelNode>>,
     pub const_40hz_latch: EventSource<dyn LatchListener>,
     pub tray_item_ids: TrayItemIds,
@@ -261,6 +278,7 @@ pub struct State {
     pub float_above_fullscreen: Cell<bool>,
     pub icons: Icons,
     pub show_pin_icon: Cell<bool>,
+    pub show_close_icon: Cell<bool>,
     pub cl_matcher_manager: ClMatcherManager,
     pub tl_matcher_manager: TlMatcherManager,
     pub caps_thread: Option<PrCapsThread>,
@@ -272,7 +290,17 @@ pub struct State {
         CopyHashMap<(ClientId, JayHeadManagerSessionV1Id), Rc<JayHeadManagerSessionV1>>,
     pub head_managers_async: AsyncQueue<HeadManagerEvent>,
     pub show_bar: Cell<bool>,
+    pub inactive_window_opacity: Cell<f32>,
+    pub workspace_animation_duration: Cell<Duration>,
+    pub window_animation_duration: Cell<Duration>,
+    pub focus_stealing_policy: Cell<FocusStealingPolicy>,
+    pub decoration_mode: Cell<DecorationMode>,
+    pub client_object_limit: Cell<Option<u64>>,
+    pub client_shm_limit: Cell<Option<u64>>,
     pub enable_primary_selection: Cell<bool>,
+    pub freeze_cursor_animation_when_idle: Cell<bool>,
+    pub low_power_mode: Cell<bool>,
+    pub low_power_max_fps: Cell<Option<f64>>,
 }
 
 // impl Drop for State {
@@ -315,6 +343,7 @@ pub struct IdleState {
     pub inhibited_idle_notifications:
         CopyHashMap<(ClientId, ExtIdleNotificationV1Id), Rc<ExtIdleNotificationV1>>,
     pub in_grace_period: Cell<bool>,
+    pub dim: Cell<f32>,
 }
 
 impl IdleState {
@@ -330,6 +359,12 @@ impl IdleState {
         self.change.trigger();
     }
 
+    pub fn set_dim(&self, dim: f32) {
+        if dim.is_finite() {
+            self.dim.set(dim.clamp(0.0, 1.0));
+        }
+    }
+
     pub fn add_inhibitor(&self, inhibitor: &Rc<ZwpIdleInhibitorV1>) {
         self.inhibitors.set(inhibitor.inhibit_id, inhibitor.clone());
         self.inhibitors_changed.set(true);
@@ -399,6 +434,8 @@ pub struct ConnectorData {
     pub state: Cell<BackendConnectorState>,
     pub head_managers: HeadManagers,
     pub wlr_output_heads: CopyHashMap<WlrOutputManagerId, Rc<ZwlrOutputHeadV1>>,
+    pub output_power: CopyHashMap<(ClientId, ZwlrOutputPowerV1Id), Rc<ZwlrOutputPowerV1>>,
+    pub gamma_control: CloneCell<Option<Rc<ZwlrGammaControlV1>>>,
 }
 
 pub struct OutputData {
@@ -446,6 +483,23 @@ impl ConnectorData {
         Ok(())
     }
 
+    pub fn set_gamma_lut(
+        &self,
+        lut: Option<Rc<BackendGammaLut>>,
+    ) -> Result<(), BackendConnectorTransactionError> {
+        self.connector.set_gamma_lut(lut);
+        let res = (|| {
+            let mut tran = self.connector.create_transaction()?;
+            tran.add(&self.connector, self.state.get())?;
+            tran.prepare()?.apply()?.commit();
+            Ok(())
+        })();
+        if res.is_err() {
+            self.connector.set_gamma_lut(None);
+        }
+        res
+    }
+
     pub fn set_state(&self, state: &State, s: BackendConnectorState) {
         let old = self.state.get();
         if old.serial >= s.serial {
@@ -457,6 +511,9 @@ impl ConnectorData {
         }
         if old.active != s.active {
             self.head_managers.handle_active_change(s.active);
+            for power in self.output_power.lock().values() {
+                power.send_mode(s.active);
+            }
         }
         if old.non_desktop_override != s.non_desktop_override {
             self.head_managers
@@ -567,6 +624,21 @@ impl State {
         }
     }
 
+    pub fn add_cursor_theme(&self, theme: &Rc<String>) {
+        if self.cursor_themes.add(theme.clone())
+            && let Some(ctx) = self.render_ctx.get()
+            && let Some(cursors) = self.load_cursors(&ctx, Some(theme.as_str()))
+        {
+            self.themed_cursors.set(theme.clone(), cursors);
+        }
+    }
+
+    pub fn remove_cursor_theme(&self, theme: &Rc<String>) {
+        if self.cursor_themes.remove(theme) {
+            self.themed_cursors.remove(theme);
+        }
+    }
+
     fn output_scales_changed(&self) {
         UpdateTextTexturesVisitor.visit_display(&self.root);
         self.reload_cursors();
@@ -606,6 +678,7 @@ impl State {
         self.render_ctx.set(ctx.clone());
         self.render_ctx_version.fetch_add(1);
         self.cursors.set(None);
+        self.themed_cursors.clear();
         self.drm_feedback.set(None);
         self.icons.clear();
         self.wait_for_sync_obj
@@ -720,16 +793,29 @@ impl State {
         }
     }
 
+    fn load_cursors(
+        &self,
+        ctx: &Rc<dyn GfxContext>,
+        theme: Option<&str>,
+    ) -> Option<Rc<ServerCursors>> {
+        match ServerCursors::load(ctx, self, theme) {
+            Ok(c) => c.map(Rc::new),
+            Err(e) => {
+                log::error!("Could not load the cursors: {}", ErrorFmt(e));
+                None
+            }
+        }
+    }
+
     fn reload_cursors(&self) {
         if let Some(ctx) = self.render_ctx.get() {
-            let cursors = match ServerCursors::load(&ctx, self) {
-                Ok(c) => c.map(Rc::new),
-                Err(e) => {
-                    log::error!("Could not load the cursors: {}", ErrorFmt(e));
-                    None
+            self.cursors.set(self.load_cursors(&ctx, None));
+            self.themed_cursors.clear();
+            for (theme, _) in self.cursor_themes.lock().iter() {
+                if let Some(cursors) = self.load_cursors(&ctx, Some(theme.as_str())) {
+                    self.themed_cursors.set(theme.clone(), cursors);
                 }
-            };
-            self.cursors.set(cursors);
+            }
             for cursor_user_group in self.cursor_user_groups.lock().values() {
                 cursor_user_group.reload_known_cursor();
             }
@@ -770,16 +856,72 @@ impl State {
 
     pub fn map_tiled(self: &Rc<Self>, node: Rc<dyn ToplevelNode>) {
         let seat = self.seat_queue.last();
-        self.do_map_tiled(seat.as_deref(), node.clone());
+        if !self.swallow(&node) {
+            self.do_map_tiled(seat.as_deref(), node.clone());
+        }
         self.focus_after_map(node, seat.as_deref());
     }
 
+    /// Replaces the terminal that spawned `node` by `node`.
+    ///
+    /// The terminal is restored when `node` is destroyed.
+    fn swallow(&self, node: &Rc<dyn ToplevelNode>) -> bool {
+        const MAX_DEPTH: usize = 16;
+        if self.swallow_app_ids.is_empty() {
+            return false;
+        }
+        let data = node.tl_data();
+        if self.swallow_app_ids.contains(&*data.app_id.borrow()) {
+            return false;
+        }
+        let Some(client) = &data.client else {
+            return false;
+        };
+        let mut pid = client.pid_info.pid;
+        let mut terminal = None;
+        'find: for _ in 0..MAX_DEPTH {
+            pid = match get_ppid(pid) {
+                Some(ppid) if ppid > 1 => ppid,
+                _ => return false,
+            };
+            for tl in self.toplevels.lock().values() {
+                let Some(tl) = tl.upgrade() else {
+                    continue;
+                };
+                let tl_data = tl.tl_data();
+                if tl_data.client.as_ref().map(|c| c.pid_info.pid) == Some(pid)
+                    && self.swallow_app_ids.contains(&*tl_data.app_id.borrow())
+                {
+                    terminal = Some(tl);
+                    break 'find;
+                }
+            }
+        }
+        let Some(terminal) = terminal else {
+            return false;
+        };
+        let tl_data = terminal.tl_data();
+        if tl_data.is_fullscreen.get() {
+            return false;
+        }
+        let Some(parent) = tl_data.parent.get() else {
+            return false;
+        };
+        parent.cnode_replace_child(&*terminal, node.clone());
+        tl_data.parent.take();
+        tl_data.detach_node(&*terminal);
+        terminal.tl_set_visible(false);
+        data.swallowed.set(Some(tl_data.identifier.get()));
+        true
+    }
+
     fn do_map_tiled(self: &Rc<Self>, seat: Option<&Rc<WlSeatGlobal>>, node: Rc<dyn ToplevelNode>) {
         let ws = self.ensure_map_workspace(seat);
         self.map_tiled_on(node, &ws);
     }
 
     pub fn map_tiled_on(self: &Rc<Self>, node: Rc<dyn ToplevelNode>, ws: &Rc<WorkspaceNode>) {
+        let layout = ws.layout.get();
         if let Some(c) = ws.container.get() {
             let la = c.clone().tl_last_active_child();
             let lap = la
@@ -787,13 +929,38 @@ impl State {
                 .parent
                 .get()
                 .and_then(|n| n.node_into_container());
-            if let Some(lap) = lap {
-                lap.add_child_after(&*la, node);
-            } else {
+            let Some(lap) = lap else {
                 c.append_child(node);
+                return;
+            };
+            match layout {
+                WorkspaceLayout::Manual => {}
+                WorkspaceLayout::Bsp => {
+                    let pos = la.node_absolute_position();
+                    let split = match pos.width() >= pos.height() {
+                        true => ContainerSplit::Horizontal,
+                        false => ContainerSplit::Vertical,
+                    };
+                    if lap.num_children.get() > 1 {
+                        let cn = ContainerNode::new(self, ws, la.clone(), split);
+                        lap.cnode_replace_child(&*la, cn.clone());
+                        cn.append_child(node);
+                        return;
+                    }
+                    lap.set_split(split);
+                }
+                WorkspaceLayout::Tabbed => {
+                    if lap.mono_child.is_none() {
+                        lap.set_mono(Some(&*la));
+                    }
+                }
             }
+            lap.add_child_after(&*la, node);
         } else {
-            let container = ContainerNode::new(self, ws, node, ContainerSplit::Horizontal);
+            let container = ContainerNode::new(self, ws, node.clone(), ContainerSplit::Horizontal);
+            if layout == WorkspaceLayout::Tabbed {
+                container.set_mono(Some(&*node));
+            }
             ws.set_container(&container);
         }
     }
@@ -902,6 +1069,39 @@ impl State {
         self.show_workspace2(Some(seat), &ws.output.get(), &ws);
     }
 
+    pub fn workspace_output(&self, name: &str) -> Option<Rc<OutputNode>> {
+        let output = self.workspaces.get(name)?.output.get();
+        (!output.is_dummy).then_some(output)
+    }
+
+    /// Makes the output the desired output of the workspace. The workspace is moved to
+    /// the output now, if it is connected, and whenever the output is connected again.
+    /// Workspaces with this name that are created later start out on the output.
+    pub fn bind_workspace_to_output(&self, name: &str, output: &Rc<OutputNode>) {
+        let output_id = output.global.output_id.clone();
+        self.workspace_output_bindings
+            .set(name.to_string(), output_id.clone());
+        let Some(ws) = self.workspaces.get(name) else {
+            return;
+        };
+        ws.desired_output.set(output_id);
+        if ws.is_dummy || output.is_dummy || ws.output.get().id == output.id {
+            return;
+        }
+        let link = match &*ws.output_link.borrow() {
+            None => return,
+            Some(l) => l.to_ref(),
+        };
+        let config = WsMoveConfig {
+            make_visible_always: false,
+            make_visible_if_empty: true,
+            source_is_destroyed: false,
+            before: None,
+        };
+        move_ws_to_output(&link, output, config);
+        self.tree_changed();
+    }
+
     pub fn float_map_ws(&self) -> Rc<WorkspaceNode> {
         if let Some(seat) = self.seat_queue.last() {
             let output = seat.get_output();
@@ -984,6 +1184,13 @@ impl State {
         }
     }
 
+    pub fn handle_logind_lock(&self) {
+        log::info!("Session manager requested that the session be locked");
+        if let Some(config) = self.config.get() {
+            config.lock_requested();
+        }
+    }
+
     pub fn do_unlock(&self) {
         self.lock.locked.set(false);
         self.lock.lock.take();
@@ -1038,8 +1245,10 @@ impl State {
         self.pending_toplevel_screencasts.clear();
         self.pending_screencast_reallocs_or_reconfigures.clear();
         self.pending_placeholder_render_textures.clear();
+        self.pending_toplevel_handle_updates.clear();
         self.render_ctx_watchers.clear();
         self.workspace_watchers.clear();
+        self.output_watchers.clear();
         self.toplevel_lists.clear();
         self.security_context_acceptors.clear();
         self.slow_clients.clear();
@@ -1048,6 +1257,7 @@ impl State {
         }
         self.backend_events.clear();
         self.workspaces.clear();
+        self.scratchpads.clear();
         {
             let seats = mem::take(self.globals.seats.lock().deref_mut());
             for seat in seats.values() {
@@ -1081,6 +1291,23 @@ impl State {
         self.wait_for_sync_obj.clear();
     }
 
+    /// Returns all mapped windows, i.e. xdg toplevels and X windows.
+    pub fn list_toplevels(&self) -> Vec<Rc<dyn ToplevelNode>> {
+        self.toplevels
+            .lock()
+            .values()
+            .filter_map(|tl| tl.upgrade())
+            .filter(|tl| {
+                let data = tl.tl_data();
+                let is_window = matches!(
+                    data.kind,
+                    ToplevelType::XdgToplevel(_) | ToplevelType::XWindow(_)
+                );
+                is_window && data.parent.is_some()
+            })
+            .collect()
+    }
+
     pub fn remove_toplevel_id(&self, id: ToplevelIdentifier) {
         self.toplevels.remove(&id);
         if let Some(config) = self.config.get() {
@@ -1202,6 +1429,7 @@ impl State {
                 Rect::new_sized(0, 0, width, height).unwrap()
             },
             icons: None,
+            toplevel_alpha: None,
         };
         let mut sample_rect = SampleRect::identity();
         sample_rect.buffer_transform = transform;
@@ -1222,6 +1450,7 @@ impl State {
         );
         if render_hardware_cursors
             && let Some(cursor_user_group) = self.cursor_user_group_hardware_cursor.get()
+            && !cursor_user_group.hidden()
             && let Some(cursor_user) = cursor_user_group.active()
             && let Some(cursor) = cursor_user.get()
         {
@@ -1251,6 +1480,7 @@ impl State {
         x_off: i32,
         y_off: i32,
         size: Option<(i32, i32)>,
+        render_hardware_cursors: bool,
         capture: &Rc<ZwlrScreencopyFrameV1>,
         mem: &Rc<ClientMemOffset>,
         stride: i32,
@@ -1283,7 +1513,7 @@ impl State {
             transform,
             self.color_manager.srgb_srgb(),
             position,
-            true,
+            render_hardware_cursors,
             x_off - capture.rect.x1(),
             y_off - capture.rect.y1(),
             size,
@@ -1328,6 +1558,39 @@ impl State {
     pub fn set_backend_idle(&self, idle: bool) {
         if self.idle.backend_idle.replace(idle) != idle {
             self.root.update_visible(self);
+            if !idle && self.freeze_cursor_animation_when_idle.get() {
+                self.refresh_hardware_cursors();
+            }
+        }
+    }
+
+    pub fn cursor_animation_frozen(&self) -> bool {
+        if self.low_power_mode.get() {
+            return true;
+        }
+        self.freeze_cursor_animation_when_idle.get()
+            && (self.idle.in_grace_period.get() || self.idle.backend_idle.get())
+    }
+
+    pub fn set_low_power_mode(&self, enabled: bool) {
+        if self.low_power_mode.replace(enabled) == enabled {
+            return;
+        }
+        if enabled {
+            for output in self.root.outputs.lock().values() {
+                output.workspace_animation_task.take();
+                output.workspace_animation.take();
+                output.closing_windows_task.take();
+                output.closing_windows.borrow_mut().clear();
+            }
+        }
+        self.refresh_hardware_cursors();
+        self.damage(self.root.extents.get());
+    }
+
+    pub fn set_freeze_cursor_animation_when_idle(&self, freeze: bool) {
+        if self.freeze_cursor_animation_when_idle.replace(freeze) != freeze {
+            self.refresh_hardware_cursors();
         }
     }
 
@@ -1335,6 +1598,46 @@ impl State {
         !self.idle.backend_idle.get()
     }
 
+    /// Returns the output that is closest to `from` in the given direction.
+    pub fn output_in_direction(
+        &self,
+        from: &OutputNode,
+        direction: Direction,
+    ) -> Option<Rc<OutputNode>> {
+        let from_pos = from.global.pos.get();
+        let (from_x, from_y) = from_pos.center();
+        let mut optimal_dist = (i32::MAX, i32::MAX);
+        let mut optimal_output = None;
+        let outputs = self.root.outputs.lock();
+        for output in outputs.values() {
+            if output.id == from.id {
+                continue;
+            }
+            let pos = output.global.pos.get();
+            let (x, y) = pos.center();
+            let dist = match direction {
+                Direction::Left if pos.x2() <= from_pos.x1() => {
+                    (from_pos.x1() - pos.x2(), (y - from_y).abs())
+                }
+                Direction::Right if pos.x1() >= from_pos.x2() => {
+                    (pos.x1() - from_pos.x2(), (y - from_y).abs())
+                }
+                Direction::Up if pos.y2() <= from_pos.y1() => {
+                    (from_pos.y1() - pos.y2(), (x - from_x).abs())
+                }
+                Direction::Down if pos.y1() >= from_pos.y2() => {
+                    (pos.y1() - from_pos.y2(), (x - from_x).abs())
+                }
+                _ => continue,
+            };
+            if dist < optimal_dist {
+                optimal_dist = dist;
+                optimal_output = Some(output.clone());
+            }
+        }
+        optimal_output
+    }
+
     pub fn find_closest_output(&self, mut x: i32, mut y: i32) -> (Rc<OutputNode>, i32, i32) {
         let mut optimal_dist = i32::MAX;
         let mut optimal_output = None;
@@ -1506,6 +1809,10 @@ impl State {
         self.config.get()?.initial_tile_state(data)
     }
 
+    pub fn initial_float_size(&self, data: &ToplevelData) -> Option<(i32, i32)> {
+        self.config.get()?.initial_float_size(data)
+    }
+
     pub fn node_at(&self, x: i32, y: i32) -> FoundNode {
         let mut found_tree = self.node_at_tree.borrow_mut();
         found_tree.push(FoundNode {
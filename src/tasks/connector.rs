@@ -87,6 +87,8 @@ pub fn handle(state: &Rc<State>, connector: &Rc<dyn Connector>) {
         state: Cell::new(backend_state),
         head_managers: HeadManagers::new(state.head_names.next(), head_state),
         wlr_output_heads: Default::default(),
+        output_power: Default::default(),
+        gamma_control: Default::default(),
     });
     if let Some(dev) = drm_dev {
         dev.connectors.set(id, data.clone());
@@ -158,6 +160,13 @@ impl ConnectorHandler {
         for head in self.data.wlr_output_heads.lock().drain_values() {
             head.handle_disconnected();
         }
+        for power in self.data.output_power.lock().drain_values() {
+            power.send_failed();
+        }
+        if let Some(gc) = self.data.gamma_control.take() {
+            gc.send_failed();
+        }
+        self.data.connector.set_gamma_lut(None);
         log::info!("Connector {} disconnected", self.data.connector.kernel_id());
     }
 
@@ -183,6 +192,8 @@ impl ConnectorHandler {
                     vrr_cursor_hz: Cell::new(self.state.default_vrr_cursor_hz.get()),
                     tearing_mode: Cell::new(self.state.default_tearing_mode.get()),
                     brightness: Cell::new(None),
+                    cursor_size: Cell::new(None),
+                    max_fps: Cell::new(None),
                 });
                 self.state
                     .persistent_output_states
@@ -203,12 +214,14 @@ impl ConnectorHandler {
             info.color_spaces.clone(),
             info.primaries,
             info.luminance,
+            info.vrr_range,
         ));
         let schedule = Rc::new(OutputSchedule::new(
             &self.state.ring,
             &self.state.eng,
             &self.data,
             &desired_state,
+            info.vrr_range,
         ));
         let _schedule = self
             .state
@@ -242,6 +255,8 @@ impl ConnectorHandler {
             screencasts: Default::default(),
             update_render_data_scheduled: Cell::new(false),
             hardware_cursor_needs_render: Cell::new(false),
+            hardware_cursor_fallback: Default::default(),
+            hardware_cursor_fallback_logged: Default::default(),
             screencopies: Default::default(),
             title_visible: Default::default(),
             schedule,
@@ -257,11 +272,18 @@ impl ConnectorHandler {
             ext_workspace_groups: Default::default(),
             pinned: Default::default(),
             tearing: Default::default(),
+            workspace_animation: Default::default(),
+            workspace_animation_task: Default::default(),
+            closing_windows: Default::default(),
+            closing_windows_task: Default::default(),
         });
         on.update_visible();
         on.update_rects();
         self.state
             .add_output_scale(on.global.persistent.scale.get());
+        if let Some(size) = on.global.persistent.cursor_size.get() {
+            self.state.add_cursor_size(size);
+        }
         let output_data = Rc::new(OutputData {
             connector: self.data.clone(),
             monitor_info: Rc::new(info),
@@ -324,6 +346,9 @@ impl ConnectorHandler {
             .head_managers
             .handle_output_connected(&output_data);
         self.state.wlr_output_managers.announce_head(&output_data);
+        for watcher in self.state.output_watchers.lock().values() {
+            watcher.send_connected(&self.data.name, &output_id);
+        }
         'outer: loop {
             while let Some(event) = self.data.connector.event() {
                 match event {
@@ -404,9 +429,15 @@ impl ConnectorHandler {
         }
         self.state
             .remove_output_scale(on.global.persistent.scale.get());
+        if let Some(size) = on.global.persistent.cursor_size.get() {
+            self.state.remove_cursor_size(size);
+        }
         on.clear();
         let _ = self.state.remove_global(&global);
         let _ = self.state.remove_global(&tray);
+        for watcher in self.state.output_watchers.lock().values() {
+            watcher.send_disconnected(&self.data.name, &output_id);
+        }
         self.state.tree_changed();
         self.state.damage(self.state.root.extents.get());
     }
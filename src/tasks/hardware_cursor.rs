@@ -14,6 +14,14 @@ pub async fn handle_hardware_cursor_tick(state: Rc<State>) {
             continue;
         }
         loop {
+            let frozen = match state.cursor_user_group_hardware_cursor.get() {
+                Some(g) => g.animation_frozen(),
+                None => state.cursor_animation_frozen(),
+            };
+            if frozen {
+                state.hardware_tick_cursor.non_empty().await;
+                break;
+            }
             let tick = cursor.time_until_tick().as_nanos().div_ceil(1_000_000);
             if tick > 0 {
                 let res = select! {
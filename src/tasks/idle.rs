@@ -88,7 +88,11 @@ impl Idle {
             return;
         }
         self.state.damage(self.state.root.extents.get());
-        self.state.damage_hardware_cursors(false);
+        if !val && self.state.freeze_cursor_animation_when_idle.get() {
+            self.state.refresh_hardware_cursors();
+        } else {
+            self.state.damage_hardware_cursors(false);
+        }
     }
 
     fn handle_idle_changes(&mut self) {
@@ -1,7 +1,9 @@
 #![expect(clippy::excessive_precision)]
 
 use {
-    crate::{cmm::cmm_transfer_function::TransferFunction, utils::clonecell::CloneCell},
+    crate::{
+        cmm::cmm_transfer_function::TransferFunction, rect::Rect, utils::clonecell::CloneCell,
+    },
     num_traits::Float,
     std::{cell::Cell, cmp::Ordering, ops::Mul, sync::Arc},
 };
@@ -398,6 +400,7 @@ colors! {
     bar_text = (0xff, 0xff, 0xff),
     attention_requested_background = (0x23, 0x09, 0x2c),
     highlight = (0x9d, 0x28, 0xc6, 0x7f),
+    shadow = (0x00, 0x00, 0x00, 0x80),
 }
 
 macro_rules! sizes {
@@ -475,6 +478,11 @@ macro_rules! sizes {
 sizes! {
     title_height = (1, 1000, 17),
     border_width = (1, 1000, 4),
+    shadow_size = (0, 100, 0),
+    shadow_offset_x = (-100, 100, 0),
+    shadow_offset_y = (-100, 100, 0),
+    inner_gap = (0, 1000, 0),
+    outer_gap = (0, 1000, 0),
 }
 
 pub const DEFAULT_FONT: &str = "monospace 8";
@@ -486,6 +494,24 @@ pub struct Theme {
     pub default_font: Arc<String>,
 }
 
+impl Theme {
+    pub fn float_shadow_extents(&self, rect: Rect) -> Rect {
+        let size = self.sizes.shadow_size.get();
+        if size <= 0 {
+            return rect;
+        }
+        let dx = self.sizes.shadow_offset_x.get();
+        let dy = self.sizes.shadow_offset_y.get();
+        let shadow = Rect::new_unchecked(
+            rect.x1() + dx - size,
+            rect.y1() + dy - size,
+            rect.x2() + dx + size,
+            rect.y2() + dy + size,
+        );
+        rect.union(shadow)
+    }
+}
+
 impl Default for Theme {
     fn default() -> Self {
         let default_font = Arc::new(DEFAULT_FONT.to_string());
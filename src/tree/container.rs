@@ -124,7 +124,7 @@ pub struct ContainerNode {
     layout_scheduled: Cell<bool>,
     compute_render_positions_scheduled: Cell<bool>,
     render_titles_scheduled: Cell<bool>,
-    num_children: NumCell<usize>,
+    pub num_children: NumCell<usize>,
     pub children: LinkedList<ContainerChild>,
     focus_history: LinkedList<NodeRef<ContainerChild>>,
     child_nodes: RefCell<AHashMap<NodeId, LinkedNode<ContainerChild>>>,
@@ -448,7 +448,7 @@ impl ContainerNode {
 
     fn perform_split_layout(self: &Rc<Self>) {
         let sum_factors = self.sum_factors.get();
-        let border_width = self.state.theme.sizes.border_width.get();
+        let border_width = self.separator_width();
         let title_height = self.state.theme.sizes.title_height.get();
         let split = self.split.get();
         let (content_size, other_content_size) = match split {
@@ -533,8 +533,13 @@ impl ContainerNode {
         }
     }
 
+    /// Returns the distance between two children in split mode.
+    fn separator_width(&self) -> i32 {
+        self.state.theme.sizes.border_width.get() + self.workspace.get().inner_gap()
+    }
+
     fn update_content_size(&self) {
-        let border_width = self.state.theme.sizes.border_width.get();
+        let border_width = self.separator_width();
         let title_height = self.state.theme.sizes.title_height.get();
         let nc = self.num_children.get();
         match self.split.get() {
@@ -814,6 +819,7 @@ impl ContainerNode {
         let have_active = self.children.iter().any(|c| c.active.get());
         let abs_x = self.abs_x1.get();
         let abs_y = self.abs_y1.get();
+        let gap = self.workspace.get().inner_gap();
         for (i, child) in self.children.iter().enumerate() {
             let rect = child.title_rect.get();
             if self.toplevel_data.visible.get() && !mono && split != ContainerSplit::Horizontal {
@@ -824,7 +830,7 @@ impl ContainerNode {
                     rect.height() + 1,
                 ));
             }
-            if i > 0 {
+            if i > 0 && (mono || gap == 0) {
                 let rect = if mono {
                     Rect::new_sized(rect.x1() - bw, 0, bw, th)
                 } else if split == ContainerSplit::Horizontal {
@@ -1977,7 +1983,7 @@ impl ContainingNode for ContainerNode {
     ) {
         let theme = &self.state.theme;
         let th = theme.sizes.title_height.get();
-        let bw = theme.sizes.border_width.get();
+        let sw = self.separator_width();
         let mut left_outside = false;
         let mut right_outside = false;
         let mut top_outside = false;
@@ -2017,8 +2023,8 @@ impl ContainingNode for ContainerNode {
                 ci = 1;
             }
             let (new_delta, between) = match split {
-                ContainerSplit::Horizontal => (self.abs_x1.get(), bw),
-                ContainerSplit::Vertical => (self.abs_y1.get(), bw + th + 1),
+                ContainerSplit::Horizontal => (self.abs_x1.get(), sw),
+                ContainerSplit::Vertical => (self.abs_y1.get(), sw + th + 1),
             };
             let new_i1 = new_i1.map(|v| v - new_delta);
             let new_i2 = new_i2.map(|v| v - new_delta);
@@ -147,7 +147,7 @@ impl FloatNode {
         child.tl_restack_popups();
         floater.schedule_layout();
         if floater.visible.get() {
-            state.damage(position);
+            floater.damage_rect(position);
         }
         if child.tl_data().pinned.get() {
             floater.toggle_pinned();
@@ -228,6 +228,9 @@ impl FloatNode {
             if self.state.show_pin_icon.get() || self.pinned_link.borrow().is_some() {
                 width = (width - th).max(0);
             }
+            if self.state.show_close_icon.get() {
+                width = (width - th).max(0);
+            }
             if *scale != 1 {
                 let scale = scale.to_f64();
                 th = (th as f64 * scale).round() as _;
@@ -357,11 +360,31 @@ impl FloatNode {
                     y2 = y2.max(y1 + 2 * bw + th + 1);
                 }
             }
+            if seat_state.op_type != OpType::Move
+                && let Some(child) = self.child.get()
+            {
+                let (width, height) = child
+                    .tl_data()
+                    .apply_size_limits(x2 - x1 - 2 * bw, y2 - y1 - 2 * bw - th - 1);
+                let width = width + 2 * bw;
+                let height = height + 2 * bw + th + 1;
+                use OpType::*;
+                match seat_state.op_type {
+                    ResizeLeft | ResizeTopLeft | ResizeBottomLeft => x1 = x2 - width,
+                    ResizeRight | ResizeTopRight | ResizeBottomRight => x2 = x1 + width,
+                    _ => {}
+                }
+                match seat_state.op_type {
+                    ResizeTop | ResizeTopLeft | ResizeTopRight => y1 = y2 - height,
+                    ResizeBottom | ResizeBottomLeft | ResizeBottomRight => y2 = y1 + height,
+                    _ => {}
+                }
+            }
             let new_pos = Rect::new(x1, y1, x2, y2).unwrap();
             self.position.set(new_pos);
             if self.visible.get() {
-                self.state.damage(pos);
-                self.state.damage(new_pos);
+                self.damage_rect(pos);
+                self.damage_rect(new_pos);
             }
             self.schedule_layout();
             return;
@@ -474,8 +497,8 @@ impl FloatNode {
         let new_pos = Rect::new(x1, y1, x2, y2).unwrap();
         self.position.set(new_pos);
         if self.visible.get() {
-            self.state.damage(pos);
-            self.state.damage(new_pos);
+            self.damage_rect(pos);
+            self.damage_rect(new_pos);
         }
         self.schedule_layout();
     }
@@ -520,12 +543,17 @@ impl FloatNode {
         }
     }
 
-    fn restack(&self) {
+    fn damage_rect(&self, rect: Rect) {
+        self.state
+            .damage(self.state.theme.float_shadow_extents(rect));
+    }
+
+    pub fn restack(&self) {
         if let Some(dl) = &*self.display_link.borrow() {
             if dl.next().is_none() {
                 return;
             }
-            self.state.damage(self.position.get());
+            self.damage_rect(self.position.get());
             self.state.root.stacked.add_last_existing(&dl);
             if let Some(tl) = self.child.get() {
                 tl.tl_restack_popups();
@@ -567,13 +595,21 @@ impl FloatNode {
         if pressed && cursor_data.x >= bw && cursor_data.y >= bw && cursor_data.y < bw + th {
             enum FloatIcon {
                 Pin,
+                Close,
             }
             let mut icons = ArrayVec::<FloatIcon, 1>::new();
             if self.state.show_pin_icon.get() || self.pinned_link.borrow().is_some() {
                 icons.push(FloatIcon::Pin);
             }
             let mut x2 = bw + th;
+            let width = self.position.get().width();
             let icon = 'icon: {
+                if self.state.show_close_icon.get()
+                    && cursor_data.x >= width - bw - th
+                    && cursor_data.x < width - bw
+                {
+                    break 'icon Some(FloatIcon::Close);
+                }
                 for icon in icons {
                     if cursor_data.x < x2 {
                         break 'icon Some(icon);
@@ -586,6 +622,11 @@ impl FloatNode {
                 is_icon_press = true;
                 match icon {
                     FloatIcon::Pin => self.toggle_pinned(),
+                    FloatIcon::Close => {
+                        if let Some(tl) = self.child.get() {
+                            tl.tl_close();
+                        }
+                    }
                 }
             }
         }
@@ -900,7 +941,7 @@ impl ContainingNode for FloatNode {
         new.tl_set_visible(self.visible.get());
         self.schedule_layout();
         if self.visible.get() {
-            self.state.damage(self.position.get());
+            self.damage_rect(self.position.get());
         }
     }
 
@@ -911,7 +952,7 @@ impl ContainingNode for FloatNode {
         self.workspace_link.set(None);
         self.pinned_link.take();
         if self.visible.get() {
-            self.state.damage(self.position.get());
+            self.damage_rect(self.position.get());
         }
     }
 
@@ -944,8 +985,8 @@ impl ContainingNode for FloatNode {
         if pos.position() != (x, y) {
             let new_pos = pos.at_point(x, y);
             self.position.set(new_pos);
-            self.state.damage(pos);
-            self.state.damage(new_pos);
+            self.damage_rect(pos);
+            self.damage_rect(new_pos);
             self.schedule_layout();
         }
     }
@@ -982,8 +1023,8 @@ impl ContainingNode for FloatNode {
         if new_pos != pos {
             self.position.set(new_pos);
             if self.visible.get() {
-                self.state.damage(pos);
-                self.state.damage(new_pos);
+                self.damage_rect(pos);
+                self.damage_rect(new_pos);
             }
             self.schedule_layout();
         }
@@ -1008,7 +1049,7 @@ impl ContainingNode for FloatNode {
 impl StackedNode for FloatNode {
     fn stacked_set_visible(&self, visible: bool) {
         if self.visible.replace(visible) != visible {
-            self.state.damage(self.position.get());
+            self.damage_rect(self.position.get());
         }
         if let Some(child) = self.child.get() {
             child.tl_set_visible(visible);
@@ -1,5 +1,6 @@
 use {
     crate::{
+        async_engine::SpawnedFuture,
         backend::{
             BackendColorSpace, BackendConnectorState, BackendTransferFunction, HardwareCursor,
             KeyState, Mode,
@@ -8,7 +9,9 @@ use {
         cmm::cmm_description::ColorDescription,
         cursor::KnownCursor,
         fixed::Fixed,
-        gfx_api::{AcquireSync, BufferResv, GfxTexture, ReleaseSync},
+        gfx_api::{
+            AcquireSync, BufferResv, GfxApiOpt, GfxTexture, ReleaseSync, renderer_base, scale_ops,
+        },
         ifs::{
             ext_image_copy::ext_image_copy_capture_session_v1::ExtImageCopyCaptureSessionV1,
             jay_output::JayOutput,
@@ -25,6 +28,7 @@ use {
                 SurfaceSendPreferredTransformVisitor,
                 ext_session_lock_surface_v1::ExtSessionLockSurfaceV1,
                 tray::DynTrayItem,
+                xdg_surface::xdg_toplevel::XdgToplevel,
                 zwlr_layer_surface_v1::{ExclusiveSize, ZwlrLayerSurfaceV1},
             },
             workspace_manager::{
@@ -42,10 +46,12 @@ use {
         scale::Scale,
         state::State,
         text::TextTexture,
+        time::Time,
         tree::{
             Direction, FindTreeResult, FindTreeUsecase, FoundNode, Node, NodeId, NodeLayerLink,
-            NodeLocation, PinnedNode, StackedNode, TddType, TileDragDestination,
-            WorkspaceDragDestination, WorkspaceNode, WorkspaceNodeId, walker::NodeVisitor,
+            NodeLocation, PinnedNode, StackedNode, TddType, TileDragDestination, ToplevelNodeBase,
+            WorkspaceDragDestination, WorkspaceNode, WorkspaceNodeId,
+            walker::{NodeVisitor, NodeVisitorBase},
         },
         utils::{
             asyncevent::AsyncEvent, bitflags::BitflagsExt, clonecell::CloneCell,
@@ -65,9 +71,29 @@ use {
         fmt::{Debug, Formatter},
         ops::{BitOrAssign, Deref},
         rc::Rc,
+        time::Duration,
     },
 };
 
+pub const MAX_ZOOM: f64 = 32.0;
+
+/// A magnification of the rendered output.
+#[derive(Copy, Clone, Debug)]
+pub struct OutputZoom {
+    pub factor: f64,
+    /// The point that stays fixed, relative to the output in logical pixels.
+    pub x: f64,
+    pub y: f64,
+}
+
+impl OutputZoom {
+    pub fn apply(&self, ops: &mut [GfxApiOpt], scale: f64, fb_width: f32, fb_height: f32) {
+        let cx = (2.0 * self.x * scale / fb_width as f64 - 1.0) as f32;
+        let cy = (2.0 * self.y * scale / fb_height as f64 - 1.0) as f32;
+        scale_ops(ops, self.factor as f32, [cx, cy], [cx, cy]);
+    }
+}
+
 tree_id!(OutputNodeId);
 pub struct OutputNode {
     pub id: OutputNodeId,
@@ -91,6 +117,8 @@ pub struct OutputNode {
     pub lock_surface: CloneCell<Option<Rc<ExtSessionLockSurfaceV1>>>,
     pub hardware_cursor: CloneCell<Option<Rc<dyn HardwareCursor>>>,
     pub hardware_cursor_needs_render: Cell<bool>,
+    pub hardware_cursor_fallback: Cell<bool>,
+    pub hardware_cursor_fallback_logged: Cell<bool>,
     pub update_render_data_scheduled: Cell<bool>,
     pub screencasts: CopyHashMap<(ClientId, JayScreencastId), Rc<JayScreencast>>,
     pub screencopies: CopyHashMap<(ClientId, ZwlrScreencopyFrameV1Id), Rc<ZwlrScreencopyFrameV1>>,
@@ -109,6 +137,62 @@ pub struct OutputNode {
     pub ext_workspace_groups: CopyHashMap<WorkspaceManagerId, Rc<ExtWorkspaceGroupHandleV1>>,
     pub pinned: LinkedList<Rc<dyn PinnedNode>>,
     pub tearing: Cell<bool>,
+    pub workspace_animation: CloneCell<Option<Rc<WorkspaceAnimation>>>,
+    pub workspace_animation_task: Cell<Option<SpawnedFuture<()>>>,
+    pub closing_windows: RefCell<Vec<ClosingWindow>>,
+    pub closing_windows_task: Cell<Option<SpawnedFuture<()>>>,
+}
+
+pub struct WorkspaceAnimation {
+    pub old: CloneCell<Option<Rc<WorkspaceNode>>>,
+    pub start: Time,
+    pub duration: Duration,
+    pub forward: bool,
+    pub done: Cell<bool>,
+}
+
+impl WorkspaceAnimation {
+    /// Returns the progress of the animation in the range `[0, 1]`.
+    pub fn progress(&self, now: Time) -> f64 {
+        ease_out(animation_progress(self.start, self.duration, now))
+    }
+}
+
+/// The last frame of a closed window that is being animated out.
+pub struct ClosingWindow {
+    /// The render operations of the window, relative to the output.
+    pub ops: Vec<GfxApiOpt>,
+    /// The position of the window relative to the output.
+    pub rect: Rect,
+    pub scale: Scale,
+    pub transform: Transform,
+    pub fb_size: (f32, f32),
+    pub start: Time,
+    pub duration: Duration,
+}
+
+impl ClosingWindow {
+    /// Returns the progress of the animation in the range `[0, 1]`.
+    pub fn progress(&self, now: Time) -> f64 {
+        animation_progress(self.start, self.duration, now)
+    }
+
+    fn end(&self) -> Time {
+        self.start + self.duration
+    }
+}
+
+/// Returns the linear progress of an animation in the range `[0, 1]`.
+pub fn animation_progress(start: Time, duration: Duration, now: Time) -> f64 {
+    if now <= start {
+        return 0.0;
+    }
+    let t = (now - start).as_secs_f64() / duration.as_secs_f64();
+    t.min(1.0)
+}
+
+pub fn ease_out(t: f64) -> f64 {
+    1.0 - (1.0 - t).powi(3)
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -246,6 +330,19 @@ impl OutputNode {
             if let Some(c) = self.workspace.get() {
                 c.change_extents(&self.workspace_rect.get());
             }
+            struct V;
+            impl NodeVisitorBase for V {
+                fn visit_toplevel(&mut self, node: &Rc<XdgToplevel>) {
+                    node.tl_work_area_changed();
+                    node.node_visit_children(self);
+                }
+            }
+            for ws in self.workspaces.iter() {
+                ws.node_visit_children(&mut V);
+                for stacked in ws.stacked.iter() {
+                    stacked.deref().clone().node_visit(&mut V);
+                }
+            }
             if self.node_visible() {
                 self.state.damage(self.global.pos.get());
             }
@@ -343,6 +440,7 @@ impl OutputNode {
         }
         let now = self.state.now();
         for capture in self.screencopies.lock().drain_values() {
+            let render_hardware_cursors = render_hardware_cursors && capture.overlay_cursor;
             let wl_buffer = match capture.buffer.take() {
                 Some(b) => b,
                 _ => {
@@ -367,6 +465,7 @@ impl OutputNode {
                             x_off,
                             y_off,
                             size,
+                            render_hardware_cursors,
                             &capture,
                             mem,
                             *stride,
@@ -449,6 +548,10 @@ impl OutputNode {
         self.vblank_event.clear();
         self.presentation_event.clear();
         self.render_data.borrow_mut().clear();
+        self.workspace_animation_task.take();
+        self.workspace_animation.take();
+        self.closing_windows_task.take();
+        self.closing_windows.borrow_mut().clear();
     }
 
     pub fn on_spaces_changed(self: &Rc<Self>) {
@@ -661,7 +764,21 @@ impl OutputNode {
     }
 
     pub fn generate_workspace(self: &Rc<Self>) -> Rc<WorkspaceNode> {
+        let bound = self
+            .state
+            .workspace_output_bindings
+            .lock()
+            .iter()
+            .filter(|(name, output_id)| {
+                *output_id == self.global.output_id && self.state.workspaces.not_contains(*name)
+            })
+            .map(|(name, _)| name.clone())
+            // Sort numeric names numerically so that `2` comes before `10`.
+            .min_by_key(|name| (name.parse::<u64>().unwrap_or(u64::MAX), name.clone()));
         let name = 'name: {
+            if let Some(name) = bound {
+                break 'name name;
+            }
             for i in 1.. {
                 let name = i.to_string();
                 if !self.state.workspaces.contains(&name) {
@@ -683,6 +800,7 @@ impl OutputNode {
             for pinned in self.pinned.iter() {
                 pinned.deref().clone().set_workspace(ws, false);
             }
+            self.start_workspace_animation(&old, ws);
             if old.is_empty() {
                 for jw in old.jay_workspaces.lock().values() {
                     jw.send_destroyed();
@@ -712,7 +830,123 @@ impl OutputNode {
         true
     }
 
+    fn start_workspace_animation(&self, old: &Rc<WorkspaceNode>, new: &Rc<WorkspaceNode>) {
+        self.workspace_animation_task.take();
+        self.workspace_animation.take();
+        let duration = self.state.workspace_animation_duration.get();
+        if duration.is_zero()
+            || self.state.low_power_mode.get()
+            || !self.node_visible()
+            || old.fullscreen.is_some()
+            || new.fullscreen.is_some()
+        {
+            return;
+        }
+        let mut forward = true;
+        for ws in self.workspaces.iter() {
+            if ws.id == old.id {
+                break;
+            }
+            if ws.id == new.id {
+                forward = false;
+                break;
+            }
+        }
+        let start = self.state.now();
+        let animation = Rc::new(WorkspaceAnimation {
+            old: CloneCell::new(Some(old.clone())),
+            start,
+            duration,
+            forward,
+            done: Cell::new(false),
+        });
+        self.workspace_animation.set(Some(animation.clone()));
+        let task = animate_workspace_switch(
+            self.state.clone(),
+            self.global.clone(),
+            animation,
+            start + duration,
+        );
+        let task = self.state.eng.spawn("workspace animation", task);
+        self.workspace_animation_task.set(Some(task));
+    }
+
+    /// Keeps the current contents of `node` on screen and animates them out.
+    ///
+    /// `pos` is the absolute position of the node.
+    pub fn add_closing_window(self: &Rc<Self>, node: &dyn Node, pos: Rect, duration: Duration) {
+        let global = &self.global;
+        let opos = global.pos.get();
+        let rect = pos.move_(-opos.x1(), -opos.y1());
+        let mode = global.mode.get();
+        let scale = global.persistent.scale.get();
+        let transform = global.persistent.transform.get();
+        let mut ops = vec![];
+        let mut renderer = Renderer {
+            base: renderer_base((mode.width, mode.height), &mut ops, scale, transform),
+            state: &self.state,
+            logical_extents: opos.at_point(0, 0),
+            pixel_extents: {
+                let (width, height) = global.pixel_size();
+                Rect::new(0, 0, width, height).unwrap()
+            },
+            icons: None,
+            toplevel_alpha: None,
+        };
+        node.node_render(&mut renderer, rect.x1(), rect.y1(), None);
+        let fb_size = (renderer.base.fb_width, renderer.base.fb_height);
+        if ops.is_empty() {
+            return;
+        }
+        for op in &mut ops {
+            if let GfxApiOpt::CopyTexture(ct) = op {
+                // The buffers are returned to the client, only the textures are kept.
+                ct.buffer_resv = None;
+                ct.acquire_sync = AcquireSync::Unnecessary;
+                ct.release_sync = ReleaseSync::None;
+            }
+        }
+        let start = self.state.now();
+        let window = ClosingWindow {
+            ops,
+            rect,
+            scale,
+            transform,
+            fb_size,
+            start,
+            duration,
+        };
+        let mut end = window.end();
+        {
+            let mut windows = self.closing_windows.borrow_mut();
+            for window in windows.iter() {
+                end = end.max(window.end());
+            }
+            windows.push(window);
+        }
+        let task = animate_closing_windows(self.state.clone(), self.clone(), end);
+        let task = self.state.eng.spawn("window close animation", task);
+        self.closing_windows_task.set(Some(task));
+    }
+
     pub fn create_workspace(self: &Rc<Self>, name: &str) -> Rc<WorkspaceNode> {
+        let mut desired_output = self.global.output_id.clone();
+        if let Some(output_id) = self.state.workspace_output_bindings.get(name) {
+            let bound = self
+                .state
+                .root
+                .outputs
+                .lock()
+                .values()
+                .find(|o| o.global.output_id == output_id)
+                .cloned();
+            if let Some(bound) = bound
+                && bound.id != self.id
+            {
+                return bound.create_workspace(name);
+            }
+            desired_output = output_id;
+        }
         let ws = Rc::new(WorkspaceNode {
             id: self.state.node_ids.next(),
             state: self.state.clone(),
@@ -728,10 +962,13 @@ impl OutputNode {
             visible: Cell::new(false),
             fullscreen: Default::default(),
             visible_on_desired_output: Cell::new(false),
-            desired_output: CloneCell::new(self.global.output_id.clone()),
+            desired_output: CloneCell::new(desired_output),
             jay_workspaces: Default::default(),
             may_capture: self.state.default_workspace_capture.clone(),
             has_capture: Cell::new(false),
+            layout: self.state.default_workspace_layout.clone(),
+            inner_gap_override: Default::default(),
+            outer_gap_override: Default::default(),
             title_texture: Default::default(),
             attention_requests: Default::default(),
             render_highlight: Default::default(),
@@ -909,6 +1146,9 @@ impl OutputNode {
         if old.mode != state.mode {
             self.update_mode(state.mode);
         }
+        if !old.active && state.active {
+            self.state.damage(self.global.pos.get());
+        }
         self.global.format.set(state.format);
     }
 
@@ -946,6 +1186,44 @@ impl OutputNode {
         }
     }
 
+    pub fn set_max_fps(&self, max_fps: Option<f64>) {
+        self.global.persistent.max_fps.set(max_fps);
+    }
+
+    /// Returns the minimum time between two page flips.
+    ///
+    /// The frame rate cap does not apply while VRR is active since the refresh rate
+    /// then follows the presentation rate. In low-power mode, the lower of the output
+    /// cap and the low-power cap is used.
+    pub fn min_present_interval_nsec(&self) -> Option<u64> {
+        if self.schedule.vrr_enabled() {
+            return None;
+        }
+        let mut max_fps = self.global.persistent.max_fps.get();
+        if self.state.low_power_mode.get()
+            && let Some(low_power) = self.state.low_power_max_fps.get()
+        {
+            max_fps = Some(max_fps.map_or(low_power, |fps| fps.min(low_power)));
+        }
+        Some((1_000_000_000.0 / max_fps?) as u64)
+    }
+
+    pub fn set_cursor_size(&self, size: Option<u32>) {
+        let old = self.global.persistent.cursor_size.replace(size);
+        if old == size {
+            return;
+        }
+        if let Some(size) = size {
+            self.state.add_cursor_size(size);
+        }
+        if let Some(old) = old {
+            self.state.remove_cursor_size(old);
+        }
+        for group in self.state.cursor_user_groups.lock().values() {
+            group.output_cursor_size_changed(self);
+        }
+    }
+
     fn find_stacked_at(
         &self,
         stack: &LinkedList<Rc<dyn StackedNode>>,
@@ -1025,6 +1303,43 @@ impl OutputNode {
             .set(id, (x.round_down(), y.round_down()));
     }
 
+    /// Sets the magnification of the output.
+    ///
+    /// The zoomed view is centered on the cursor. A zoom of 1 disables the magnifier.
+    pub fn set_zoom(&self, zoom: f64) {
+        let zoom = if zoom.is_nan() {
+            1.0
+        } else {
+            zoom.clamp(1.0, MAX_ZOOM)
+        };
+        if self.global.zoom.replace(zoom) == zoom {
+            return;
+        }
+        self.state.refresh_hardware_cursors();
+        self.state.damage(self.global.pos.get());
+    }
+
+    /// Returns the transformation to apply to the rendered output if it is zoomed.
+    pub fn zoom(&self) -> Option<OutputZoom> {
+        let factor = self.global.zoom.get();
+        if factor <= 1.0 {
+            return None;
+        }
+        let pos = self.global.pos.get();
+        let mut x = pos.width() as f64 / 2.0;
+        let mut y = pos.height() as f64 / 2.0;
+        for seat in self.state.globals.lock_seats().values() {
+            let cursor = seat.pointer_cursor();
+            if cursor.output().id == self.id {
+                let (cx, cy) = cursor.position();
+                x = cx.to_f64() - pos.x1() as f64;
+                y = cy.to_f64() - pos.y1() as f64;
+                break;
+            }
+        }
+        Some(OutputZoom { factor, x, y })
+    }
+
     pub fn has_fullscreen(&self) -> bool {
         self.workspace
             .get()
@@ -1426,6 +1741,70 @@ impl OutputRenderData {
     }
 }
 
+/// Calls `tick` once per frame of the output until `end` has passed or `tick` returns
+/// `false`.
+pub async fn animation_frames(
+    state: &State,
+    global: &WlOutputGlobal,
+    end: Time,
+    mut tick: impl FnMut() -> bool,
+) {
+    let refresh_mhz = global.mode.get().refresh_rate_millihz as u64;
+    let frame_ms = match refresh_mhz {
+        0 => 16,
+        _ => (1_000_000 / refresh_mhz).max(1),
+    };
+    while state.now() < end {
+        if let Err(e) = state.wheel.timeout(frame_ms).await {
+            log::error!(
+                "Could not wait for the next animation frame: {}",
+                ErrorFmt(e)
+            );
+            return;
+        }
+        if !tick() {
+            return;
+        }
+    }
+}
+
+async fn animate_workspace_switch(
+    state: Rc<State>,
+    global: Rc<WlOutputGlobal>,
+    animation: Rc<WorkspaceAnimation>,
+    end: Time,
+) {
+    animation_frames(&state, &global, end, || {
+        state.damage(global.pos.get());
+        true
+    })
+    .await;
+    animation.done.set(true);
+    animation.old.take();
+    state.damage(global.pos.get());
+}
+
+async fn animate_closing_windows(state: Rc<State>, output: Rc<OutputNode>, end: Time) {
+    let damage = || {
+        let opos = output.global.pos.get();
+        for window in output.closing_windows.borrow().iter() {
+            state.damage(window.rect.move_(opos.x1(), opos.y1()));
+        }
+    };
+    animation_frames(&state, &output.global, end, || {
+        damage();
+        let now = state.now();
+        output
+            .closing_windows
+            .borrow_mut()
+            .retain(|w| w.end() > now);
+        true
+    })
+    .await;
+    damage();
+    output.closing_windows.borrow_mut().clear();
+}
+
 impl Debug for OutputNode {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("OutputNode").finish_non_exhaustive()
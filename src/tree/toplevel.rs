@@ -1,5 +1,6 @@
 use {
     crate::{
+        async_engine::SpawnedFuture,
         client::{Client, ClientId},
         criteria::{
             CritDestroyListener, CritMatcherId,
@@ -15,7 +16,7 @@ use {
             ext_image_copy::ext_image_copy_capture_session_v1::ExtImageCopyCaptureSessionV1,
             jay_screencast::JayScreencast,
             jay_toplevel::JayToplevel,
-            wl_seat::{NodeSeatState, SeatId, collect_kb_foci, collect_kb_foci2},
+            wl_seat::{NodeSeatState, SeatId, WlSeatGlobal, collect_kb_foci, collect_kb_foci2},
             wl_surface::{
                 WlSurface, x_surface::xwindow::XwindowData,
                 xdg_surface::xdg_toplevel::XdgToplevelToplevelData,
@@ -26,13 +27,15 @@ use {
         },
         rect::Rect,
         state::State,
+        time::Time,
         tree::{
             ContainerNode, ContainerSplit, ContainingNode, Direction, FloatNode, Node, NodeId,
-            NodeLayerLink, OutputNode, PlaceholderNode, WorkspaceNode,
+            NodeLayerLink, OutputNode, PlaceholderNode, WorkspaceNode, animation_frames,
+            animation_progress, ease_out,
         },
         utils::{
             array_to_tuple::ArrayToTuple,
-            clonecell::CloneCell,
+            clonecell::{CloneCell, UnsafeCellCloneSafe},
             copyhashmap::CopyHashMap,
             hash_map_ext::HashMapExt,
             numcell::NumCell,
@@ -51,6 +54,7 @@ use {
         cell::{Cell, RefCell},
         ops::Deref,
         rc::{Rc, Weak},
+        time::Duration,
     },
 };
 
@@ -118,6 +122,7 @@ impl<T: ToplevelNodeBase> ToplevelNode for T {
         let parent_was_none = data.parent.set(Some(parent.clone())).is_none();
         if parent_was_none {
             data.mapped_during_iteration.set(data.state.eng.iteration());
+            data.start_open_animation();
             data.property_changed(TL_CHANGED_NEW);
         }
         let was_floating = data.parent_is_float.get();
@@ -206,7 +211,13 @@ impl<T: ToplevelNodeBase> ToplevelNode for T {
     }
 
     fn tl_destroy(&self) {
-        self.tl_data().destroy_node(self);
+        let data = self.tl_data();
+        if let Some(surface) = self.tl_scanout_surface()
+            && surface.buffer.is_some()
+        {
+            data.start_close_animation(self);
+        }
+        data.destroy_node(self);
         self.tl_destroy_impl();
     }
 
@@ -285,6 +296,10 @@ pub trait ToplevelNodeBase: Node {
         // nothing
     }
 
+    fn tl_work_area_changed(&self) {
+        // nothing
+    }
+
     fn tl_admits_children(&self) -> bool;
 
     fn tl_tile_drag_destination(
@@ -369,6 +384,10 @@ pub struct ToplevelData {
     pub float: CloneCell<Option<Rc<FloatNode>>>,
     pub float_width: Cell<i32>,
     pub float_height: Cell<i32>,
+    pub min_width: Cell<Option<i32>>,
+    pub min_height: Cell<Option<i32>>,
+    pub max_width: Cell<Option<i32>>,
+    pub max_height: Cell<Option<i32>>,
     pub pinned: Cell<bool>,
     pub is_fullscreen: Cell<bool>,
     pub self_or_ancestor_is_fullscreen: Cell<bool>,
@@ -382,6 +401,7 @@ pub struct ToplevelData {
     pub seat_state: NodeSeatState,
     pub wants_attention: Cell<bool>,
     pub requested_attention: Cell<bool>,
+    pub keyboard_group: Cell<Option<u32>>,
     pub app_id: RefCell<String>,
     pub identifier: Cell<ToplevelIdentifier>,
     pub handles:
@@ -397,8 +417,15 @@ pub struct ToplevelData {
     pub destroyed: CopyHashMap<CritMatcherId, Weak<dyn CritDestroyListener<ToplevelData>>>,
     pub changed_properties: Cell<TlMatcherChange>,
     pub just_mapped_scheduled: Cell<bool>,
+    pub pending_title_update: Cell<bool>,
+    pub pending_app_id_update: Cell<bool>,
     pub seat_foci: CopyHashMap<SeatId, ()>,
     pub content_type: Cell<Option<ContentType>>,
+    pub opacity: Cell<f32>,
+    pub open_animation: Cell<Option<(Time, Duration)>>,
+    pub open_animation_task: Cell<Option<SpawnedFuture<()>>>,
+    /// The terminal that was hidden when this window was mapped.
+    pub swallowed: Cell<Option<ToplevelIdentifier>>,
 }
 
 impl ToplevelData {
@@ -425,6 +452,10 @@ impl ToplevelData {
             float: Default::default(),
             float_width: Default::default(),
             float_height: Default::default(),
+            min_width: Default::default(),
+            min_height: Default::default(),
+            max_width: Default::default(),
+            max_height: Default::default(),
             pinned: Cell::new(false),
             is_fullscreen: Default::default(),
             self_or_ancestor_is_fullscreen: Default::default(),
@@ -438,6 +469,7 @@ impl ToplevelData {
             seat_state: Default::default(),
             wants_attention: Cell::new(false),
             requested_attention: Cell::new(false),
+            keyboard_group: Cell::new(None),
             app_id: Default::default(),
             identifier: Cell::new(id),
             handles: Default::default(),
@@ -450,8 +482,14 @@ impl ToplevelData {
             destroyed: Default::default(),
             changed_properties: Default::default(),
             just_mapped_scheduled: Cell::new(false),
+            pending_title_update: Cell::new(false),
+            pending_app_id_update: Cell::new(false),
             seat_foci: Default::default(),
             content_type: Default::default(),
+            opacity: Cell::new(1.0),
+            open_animation: Cell::new(None),
+            open_animation_task: Default::default(),
+            swallowed: Default::default(),
         }
     }
 
@@ -464,13 +502,23 @@ impl ToplevelData {
         f();
         let active_new = self.active();
         if active_old != active_new {
+            let urgent_old = self.wants_attention.get();
+            if active_new {
+                self.clear_attention(tl);
+            }
             tl.tl_set_active(active_new);
+            if self.state.inactive_window_opacity.get() < 1.0 {
+                self.state.damage(tl.node_absolute_position());
+            }
             if let Some(parent) = self.parent.get() {
                 parent.node_child_active_changed(tl, active_new, 1);
             }
-            for handle in self.handles.lock().values() {
-                handle.send_state(active_new, self.is_fullscreen.get());
-                handle.send_done();
+            // Clearing the urgent state has already sent the new state.
+            if urgent_old == self.wants_attention.get() {
+                for handle in self.handles.lock().values() {
+                    handle.send_state(active_new, self.is_fullscreen.get(), urgent_old);
+                    handle.send_done();
+                }
             }
         }
     }
@@ -483,12 +531,35 @@ impl ToplevelData {
         let output = ws.output.get().global.pos.get();
         let mut width = self.float_width.get();
         let mut height = self.float_height.get();
+        if width == 0
+            && height == 0
+            && let Some((w, h)) = self.state.initial_float_size(self)
+        {
+            width = w;
+            height = h;
+        }
         if width == 0 {
             width = output.width() / 2;
         }
         if height == 0 {
             height = output.height() / 2;
         }
+        self.apply_size_limits(width, height)
+    }
+
+    pub fn apply_size_limits(&self, mut width: i32, mut height: i32) -> (i32, i32) {
+        if let Some(min) = self.min_width.get() {
+            width = width.max(min);
+        }
+        if let Some(max) = self.max_width.get() {
+            width = width.min(max);
+        }
+        if let Some(min) = self.min_height.get() {
+            height = height.max(min);
+        }
+        if let Some(max) = self.max_height.get() {
+            height = height.min(max);
+        }
         (width, height)
     }
 
@@ -535,11 +606,39 @@ impl ToplevelData {
                 handle.send_closed();
             }
         }
+        self.restore_swallowed(node);
         self.detach_node(node);
         self.property_changed(TL_CHANGED_DESTROYED);
     }
 
+    fn restore_swallowed(&self, node: &dyn Node) {
+        let Some(id) = self.swallowed.take() else {
+            return;
+        };
+        let Some(tl) = self.state.toplevels.get(&id).and_then(|tl| tl.upgrade()) else {
+            return;
+        };
+        if tl.tl_data().parent.is_some() {
+            return;
+        }
+        if !self.is_fullscreen.get()
+            && let Some(parent) = self.parent.get()
+            && let Some(slf) = self.slf.upgrade()
+        {
+            let kb_foci = collect_kb_foci(slf);
+            parent.cnode_replace_child(node, tl.clone());
+            self.parent.take();
+            for seat in kb_foci {
+                tl.clone().node_do_focus(&seat, Direction::Unspecified);
+            }
+        } else {
+            self.state.map_tiled(tl);
+        }
+    }
+
     pub fn detach_node(&self, node: &dyn Node) {
+        self.open_animation_task.take();
+        self.open_animation.take();
         if let Some(fd) = self.fullscrceen_data.borrow_mut().take() {
             fd.placeholder.tl_destroy();
         }
@@ -551,6 +650,39 @@ impl ToplevelData {
         self.seat_state.destroy_node(node);
     }
 
+    fn start_open_animation(&self) {
+        let duration = self.state.window_animation_duration.get();
+        if duration.is_zero() || self.state.low_power_mode.get() {
+            return;
+        }
+        let start = self.state.now();
+        self.open_animation.set(Some((start, duration)));
+        let task = animate_open(self.state.clone(), self.slf.clone(), start + duration);
+        let task = self.state.eng.spawn("window open animation", task);
+        self.open_animation_task.set(Some(task));
+    }
+
+    /// Returns the progress of the open animation if it is currently running.
+    pub fn open_animation_progress(&self, now: Time) -> Option<f64> {
+        let (start, duration) = self.open_animation.get()?;
+        Some(animation_progress(start, duration, now))
+    }
+
+    fn start_close_animation(&self, node: &dyn Node) {
+        let duration = self.state.window_animation_duration.get();
+        if duration.is_zero()
+            || self.state.low_power_mode.get()
+            || !self.visible.get()
+            || self.self_or_ancestor_is_fullscreen.get()
+            || self.parent.is_none()
+        {
+            return;
+        }
+        if let Some(output) = self.output_opt() {
+            output.add_closing_window(node, self.pos.get(), duration);
+        }
+    }
+
     pub fn broadcast(&self, toplevel: Rc<dyn ToplevelNode>) {
         let id = self.identifier.get().to_string();
         let title = self.title.borrow();
@@ -588,7 +720,11 @@ impl ToplevelData {
     }
 
     pub fn send_extra_toplevel_state(&self, handle: &ExtForeignToplevelHandleV1) {
-        handle.send_state(self.active(), self.is_fullscreen.get());
+        handle.send_state(
+            self.active(),
+            self.is_fullscreen.get(),
+            self.wants_attention.get(),
+        );
         handle.send_done();
     }
 
@@ -611,7 +747,11 @@ impl ToplevelData {
         handle.send_identifier(id);
         handle.send_title(title);
         handle.send_app_id(app_id);
-        handle.send_state(self.active(), self.is_fullscreen.get());
+        handle.send_state(
+            self.active(),
+            self.is_fullscreen.get(),
+            self.wants_attention.get(),
+        );
         handle.send_done();
         self.handles
             .set((handle.client.id, handle.id), handle.clone());
@@ -679,14 +819,7 @@ impl ToplevelData {
 
     pub fn set_title(&self, title: &str) {
         *self.title.borrow_mut() = title.to_string();
-        for handle in self.handles.lock().values() {
-            handle.send_title(title);
-            handle.send_done();
-        }
-        for handle in self.manager_handles.lock().values() {
-            handle.send_title(title);
-            handle.send_done();
-        }
+        self.schedule_handle_update(&self.pending_title_update);
     }
 
     pub fn set_app_id(&self, app_id: &str) {
@@ -695,15 +828,41 @@ impl ToplevelData {
             return;
         }
         *dst = app_id.to_string();
+        self.schedule_handle_update(&self.pending_app_id_update);
+        self.property_changed(TL_CHANGED_APP_ID)
+    }
+
+    fn schedule_handle_update(&self, pending: &Cell<bool>) {
+        let scheduled = self.pending_title_update.get() || self.pending_app_id_update.get();
+        pending.set(true);
+        if !scheduled && let Some(node) = self.slf.upgrade() {
+            self.state.pending_toplevel_handle_updates.push(node);
+        }
+    }
+
+    fn flush_handle_updates(&self) {
+        let title_changed = self.pending_title_update.take();
+        let app_id_changed = self.pending_app_id_update.take();
+        let title = &*self.title.borrow();
+        let app_id = &*self.app_id.borrow();
         for handle in self.handles.lock().values() {
-            handle.send_app_id(app_id);
+            if title_changed {
+                handle.send_title(title);
+            }
+            if app_id_changed {
+                handle.send_app_id(app_id);
+            }
             handle.send_done();
         }
         for handle in self.manager_handles.lock().values() {
-            handle.send_app_id(app_id);
+            if title_changed {
+                handle.send_title(title);
+            }
+            if app_id_changed {
+                handle.send_app_id(app_id);
+            }
             handle.send_done();
         }
-        self.property_changed(TL_CHANGED_APP_ID)
     }
 
     pub fn set_fullscreen(
@@ -767,7 +926,11 @@ impl ToplevelData {
         node.tl_mark_fullscreen(true);
         self.property_changed(TL_CHANGED_FULLSCREEN);
         for handle in self.handles.lock().values() {
-            handle.send_state(self.active(), self.is_fullscreen.get());
+            handle.send_state(
+                self.active(),
+                self.is_fullscreen.get(),
+                self.wants_attention.get(),
+            );
             handle.send_done();
         }
         node.tl_set_parent(ws.clone());
@@ -798,7 +961,11 @@ impl ToplevelData {
         node.tl_mark_fullscreen(false);
         self.property_changed(TL_CHANGED_FULLSCREEN);
         for handle in self.handles.lock().values() {
-            handle.send_state(self.active(), self.is_fullscreen.get());
+            handle.send_state(
+                self.active(),
+                self.is_fullscreen.get(),
+                self.wants_attention.get(),
+            );
             handle.send_done();
         }
         match fd.workspace.fullscreen.get() {
@@ -847,20 +1014,11 @@ impl ToplevelData {
         for sc in self.ext_copy_sessions.lock().values() {
             sc.update_latch_listener();
         }
-        if !visible {
-            return;
-        }
-        if !self.requested_attention.replace(false) {
-            return;
-        }
-        self.set_wants_attention(false);
-        if let Some(parent) = self.parent.get() {
-            parent.cnode_child_attention_request_changed(node, false);
-        }
     }
 
+    /// Marks the window as urgent until it is focused.
     pub fn request_attention(&self, node: &dyn Node) {
-        if self.visible.get() {
+        if self.active() {
             return;
         }
         if self.requested_attention.replace(true) {
@@ -872,9 +1030,23 @@ impl ToplevelData {
         }
     }
 
+    pub fn clear_attention(&self, node: &dyn Node) {
+        if !self.requested_attention.replace(false) {
+            return;
+        }
+        self.set_wants_attention(false);
+        if let Some(parent) = self.parent.get() {
+            parent.cnode_child_attention_request_changed(node, false);
+        }
+    }
+
     pub fn set_wants_attention(&self, value: bool) {
         if self.wants_attention.replace(value) != value {
             self.property_changed(TL_CHANGED_URGENT);
+            for handle in self.handles.lock().values() {
+                handle.send_state(self.active(), self.is_fullscreen.get(), value);
+                handle.send_done();
+            }
         }
     }
 
@@ -1065,3 +1237,128 @@ pub fn toplevel_set_workspace(state: &Rc<State>, tl: Rc<dyn ToplevelNode>, ws: &
         tl.tl_set_fullscreen(true, Some(ws.clone()));
     }
 }
+
+/// A window that has been moved to a named scratchpad.
+#[derive(Copy, Clone, Debug)]
+pub struct Scratchpad {
+    pub toplevel: ToplevelIdentifier,
+    /// The position of the window relative to its output when it was last floating.
+    pub rel_pos: Option<(i32, i32)>,
+}
+
+unsafe impl UnsafeCellCloneSafe for Scratchpad {}
+
+pub fn toplevel_move_to_scratchpad(state: &Rc<State>, tl: Rc<dyn ToplevelNode>, name: &str) {
+    let data = tl.tl_data();
+    if !matches!(
+        data.kind,
+        ToplevelType::XdgToplevel { .. } | ToplevelType::XWindow { .. }
+    ) {
+        return;
+    }
+    let Some(ws) = data.workspace.get() else {
+        return;
+    };
+    if data.is_fullscreen.get() {
+        tl.clone().tl_set_fullscreen(false, None);
+        if data.is_fullscreen.get() {
+            return;
+        }
+    }
+    let mut rel_pos = None;
+    if data.parent_is_float.get() {
+        let pos = data.pos.get();
+        let output = ws.output.get().global.pos.get();
+        rel_pos = Some((pos.x1() - output.x1(), pos.y1() - output.y1()));
+    }
+    let id = data.identifier.get();
+    state.scratchpads.lock().retain(|_, sp| sp.toplevel != id);
+    let scratchpad = Scratchpad {
+        toplevel: id,
+        rel_pos,
+    };
+    let prev = state.scratchpads.set(name.to_string(), scratchpad);
+    let kb_foci = collect_kb_foci(tl.clone());
+    data.detach_node(&*tl);
+    tl.tl_set_visible(false);
+    for focus in kb_foci {
+        ws.clone().node_do_focus(&focus, Direction::Unspecified);
+    }
+    if let Some(prev) = prev
+        && let Some(prev_tl) = state.toplevels.get(&prev.toplevel)
+        && let Some(prev_tl) = prev_tl.upgrade()
+        && prev_tl.tl_data().parent.is_none()
+    {
+        // The scratchpad already contained a hidden window. Show it instead of losing
+        // track of it.
+        show_scratchpad(state, prev_tl, &ws, prev.rel_pos);
+    }
+    state.tree_changed();
+}
+
+pub fn toplevel_toggle_scratchpad(state: &Rc<State>, seat: &Rc<WlSeatGlobal>, name: &str) {
+    let Some(scratchpad) = state.scratchpads.get(name) else {
+        return;
+    };
+    let Some(tl) = state
+        .toplevels
+        .get(&scratchpad.toplevel)
+        .and_then(|tl| tl.upgrade())
+    else {
+        state.scratchpads.remove(name);
+        return;
+    };
+    if tl.tl_data().parent.is_some() {
+        toplevel_move_to_scratchpad(state, tl, name);
+        return;
+    }
+    let ws = seat.get_output().ensure_workspace();
+    show_scratchpad(state, tl.clone(), &ws, scratchpad.rel_pos);
+    seat.focus_toplevel(tl);
+}
+
+fn show_scratchpad(
+    state: &Rc<State>,
+    tl: Rc<dyn ToplevelNode>,
+    ws: &Rc<WorkspaceNode>,
+    rel_pos: Option<(i32, i32)>,
+) {
+    let (width, height) = tl.tl_data().float_size(ws);
+    let output = ws.output.get().global.pos.get();
+    let abs_pos = rel_pos.map(|(x, y)| (output.x1() + x, output.y1() + y));
+    state.map_floating(tl, width, height, ws, abs_pos);
+}
+
+pub async fn handle_toplevel_handle_updates(state: Rc<State>) {
+    loop {
+        let node = state.pending_toplevel_handle_updates.pop().await;
+        node.tl_data().flush_handle_updates();
+    }
+}
+
+/// The size of a window, relative to its regular size, at the start of its open
+/// animation and at the end of its close animation.
+const WINDOW_ANIMATION_SCALE: f64 = 0.9;
+
+/// Returns the scale of a window whose open animation has the progress `t`.
+pub fn window_animation_scale(t: f64) -> f32 {
+    (WINDOW_ANIMATION_SCALE + (1.0 - WINDOW_ANIMATION_SCALE) * ease_out(t)) as f32
+}
+
+async fn animate_open(state: Rc<State>, node: Weak<dyn ToplevelNode>, end: Time) {
+    let Some(output) = node.upgrade().map(|n| n.tl_data().output()) else {
+        return;
+    };
+    animation_frames(&state, &output.global, end, || match node.upgrade() {
+        Some(node) => {
+            state.damage(node.node_absolute_position());
+            true
+        }
+        None => false,
+    })
+    .await;
+    if let Some(node) = node.upgrade() {
+        node.tl_data().open_animation.take();
+        state.damage(node.node_absolute_position());
+    }
+}
@@ -45,6 +45,18 @@ use {
 
 tree_id!(WorkspaceNodeId);
 
+/// Determines where new tiled windows are placed on a workspace.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum WorkspaceLayout {
+    /// New windows are placed next to the last active window in its container.
+    #[default]
+    Manual,
+    /// New windows split the last active window along its longer side.
+    Bsp,
+    /// New windows are added as tabs to the container of the last active window.
+    Tabbed,
+}
+
 pub struct WorkspaceNode {
     pub id: WorkspaceNodeId,
     pub state: Rc<State>,
@@ -64,6 +76,9 @@ pub struct WorkspaceNode {
     pub jay_workspaces: CopyHashMap<(ClientId, JayWorkspaceId), Rc<JayWorkspace>>,
     pub may_capture: Cell<bool>,
     pub has_capture: Cell<bool>,
+    pub layout: Cell<WorkspaceLayout>,
+    pub inner_gap_override: Cell<Option<i32>>,
+    pub outer_gap_override: Cell<Option<i32>>,
     pub title_texture: RefCell<Option<TextTexture>>,
     pub attention_requests: ThresholdCounter,
     pub render_highlight: NumCell<u32>,
@@ -159,6 +174,28 @@ impl WorkspaceNode {
         for stacked in self.stacked.iter() {
             stacked.deref().clone().node_visit(&mut visitor);
         }
+        self.send_scanout_feedback();
+    }
+
+    /// Sends the scanout feedback of the current output to the fullscreen surface.
+    pub fn send_scanout_feedback(&self) {
+        let Some(node) = self.fullscreen.get() else {
+            return;
+        };
+        let Some(surface) = node.tl_scanout_surface() else {
+            return;
+        };
+        let fb = self
+            .output
+            .get()
+            .global
+            .connector
+            .connector
+            .drm_feedback()
+            .or_else(|| surface.client.state.drm_feedback.get());
+        if let Some(fb) = fb {
+            surface.send_feedback(&fb);
+        }
     }
 
     pub fn set_container(self: &Rc<Self>, container: &Rc<ContainerNode>) {
@@ -166,8 +203,7 @@ impl WorkspaceNode {
             self.discard_child_properties(&*prev);
         }
         self.pull_child_properties(&**container);
-        let pos = self.position.get();
-        container.clone().tl_change_extents(&pos);
+        container.clone().tl_change_extents(&self.container_rect());
         container.tl_set_parent(self.clone());
         container.tl_set_visible(self.container_visible());
         self.container.set(Some(container.clone()));
@@ -189,10 +225,51 @@ impl WorkspaceNode {
     pub fn change_extents(&self, rect: &Rect) {
         self.position.set(*rect);
         if let Some(c) = self.container.get() {
-            c.tl_change_extents(rect);
+            c.tl_change_extents(&self.container_rect());
         }
     }
 
+    pub fn inner_gap(&self) -> i32 {
+        self.inner_gap_override
+            .get()
+            .unwrap_or_else(|| self.state.theme.sizes.inner_gap.get())
+    }
+
+    pub fn outer_gap(&self) -> i32 {
+        self.outer_gap_override
+            .get()
+            .unwrap_or_else(|| self.state.theme.sizes.outer_gap.get())
+    }
+
+    /// Returns the area of the workspace that is available to the tiled container.
+    ///
+    /// This is the position of the workspace shrunk by the outer gap.
+    pub fn container_rect(&self) -> Rect {
+        let pos = self.position.get();
+        let gap = self.outer_gap();
+        Rect::new_sized_unchecked(
+            pos.x1() + gap,
+            pos.y1() + gap,
+            (pos.width() - 2 * gap).max(0),
+            (pos.height() - 2 * gap).max(0),
+        )
+    }
+
+    pub fn on_gaps_changed(&self) {
+        struct V;
+        impl NodeVisitorBase for V {
+            fn visit_container(&mut self, node: &Rc<ContainerNode>) {
+                node.on_spaces_changed();
+                node.node_visit_children(self);
+            }
+        }
+        if let Some(c) = self.container.get() {
+            c.node_visit(&mut V);
+        }
+        self.change_extents(&self.position.get());
+        self.state.damage(self.position.get());
+    }
+
     pub fn flush_jay_workspaces(&self) {
         for jw in self.jay_workspaces.lock().values() {
             jw.send_done();
@@ -234,11 +311,7 @@ impl WorkspaceNode {
         } else {
             node.tl_set_visible(false);
         }
-        if let Some(surface) = node.tl_scanout_surface()
-            && let Some(fb) = self.output.get().global.connector.connector.drm_feedback()
-        {
-            surface.send_feedback(&fb);
-        }
+        self.send_scanout_feedback();
         self.output.get().update_presentation_type();
     }
 
@@ -354,6 +427,12 @@ impl Node for WorkspaceNode {
         usecase: FindTreeUsecase,
     ) -> FindTreeResult {
         if let Some(n) = self.container.get() {
+            let pos = self.position.get();
+            let rect = self.container_rect().move_(-pos.x1(), -pos.y1());
+            if !rect.contains(x, y) {
+                return FindTreeResult::Other;
+            }
+            let (x, y) = rect.translate(x, y);
             tree.push(FoundNode {
                 node: n.clone(),
                 x,
@@ -40,6 +40,22 @@ pub fn get_pid_info(uid: c::uid_t, pid: c::pid_t) -> PidInfo {
     }
 }
 
+pub fn get_ppid(pid: c::pid_t) -> Option<c::pid_t> {
+    let stat = match std::fs::read(format!("/proc/{}/stat", pid)) {
+        Ok(stat) => stat,
+        Err(e) => {
+            log::debug!("Could not read `stat` of pid {}: {}", pid, ErrorFmt(e));
+            return None;
+        }
+    };
+    // The second field is the command name in parentheses, which can itself contain
+    // spaces and parentheses.
+    let pos = stat.rfind_byte(b')')?;
+    let mut fields = stat[pos + 1..].fields();
+    let _state = fields.next()?;
+    fields.next()?.to_str().ok()?.parse().ok()
+}
+
 pub fn get_socket_creds(socket: &OwnedFd) -> Option<(c::uid_t, c::pid_t)> {
     let mut cred = c::ucred {
         pid: 0,
@@ -10,6 +10,12 @@ use {
     },
 };
 
+/// A random 128-bit identifier of a mapped toplevel.
+///
+/// Identifiers are never reused. A toplevel gets a new identifier every time it is
+/// unmapped. They are not stable across restarts of the compositor or reconnects of
+/// the client. Deriving them from attributes such as the app-id would allow a
+/// different window to be mistaken for a window that no longer exists.
 #[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
 pub struct ToplevelIdentifier(Opaque);
 
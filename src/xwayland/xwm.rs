@@ -9,7 +9,7 @@ use {
             ipc::{
                 DataOfferId, DataSourceId, DynDataOffer, DynDataSource, IpcLocation, IpcVtable,
                 SourceData, add_data_source_mime_type, destroy_data_device, destroy_data_offer,
-                destroy_data_source, receive_data_offer,
+                destroy_data_source, receive_data_offer, teardown_data_source,
                 x_data_device::{XClipboardIpc, XIpc, XIpcDevice, XPrimarySelectionIpc},
                 x_data_offer::XDataOffer,
                 x_data_source::XDataSource,
@@ -48,13 +48,13 @@ use {
                 CONFIG_WINDOW_HEIGHT, CONFIG_WINDOW_WIDTH, CONFIG_WINDOW_X, CONFIG_WINDOW_Y,
                 EVENT_MASK_FOCUS_CHANGE, EVENT_MASK_PROPERTY_CHANGE,
                 EVENT_MASK_SUBSTRUCTURE_NOTIFY, EVENT_MASK_SUBSTRUCTURE_REDIRECT,
-                ICCCM_WM_HINT_INPUT, ICCCM_WM_STATE_ICONIC, ICCCM_WM_STATE_NORMAL,
-                ICCCM_WM_STATE_WITHDRAWN, INPUT_FOCUS_POINTER_ROOT, MWM_HINTS_DECORATIONS_FIELD,
-                MWM_HINTS_FLAGS_FIELD, NOTIFY_DETAIL_POINTER, NOTIFY_MODE_GRAB, NOTIFY_MODE_UNGRAB,
-                PROP_MODE_APPEND, PROP_MODE_REPLACE, RES_CLIENT_ID_MASK_LOCAL_CLIENT_PID,
-                SELECTION_CLIENT_CLOSE_MASK, SELECTION_WINDOW_DESTROY_MASK,
-                SET_SELECTION_OWNER_MASK, STACK_MODE_ABOVE, STACK_MODE_BELOW,
-                WINDOW_CLASS_INPUT_OUTPUT,
+                ICCCM_WM_HINT_INPUT, ICCCM_WM_HINT_X_URGENCY, ICCCM_WM_STATE_ICONIC,
+                ICCCM_WM_STATE_NORMAL, ICCCM_WM_STATE_WITHDRAWN, INPUT_FOCUS_POINTER_ROOT,
+                MWM_HINTS_DECORATIONS_FIELD, MWM_HINTS_FLAGS_FIELD, NOTIFY_DETAIL_POINTER,
+                NOTIFY_MODE_GRAB, NOTIFY_MODE_UNGRAB, PROP_MODE_APPEND, PROP_MODE_REPLACE,
+                RES_CLIENT_ID_MASK_LOCAL_CLIENT_PID, SELECTION_CLIENT_CLOSE_MASK,
+                SELECTION_WINDOW_DESTROY_MASK, SET_SELECTION_OWNER, SET_SELECTION_OWNER_MASK,
+                STACK_MODE_ABOVE, STACK_MODE_BELOW, WINDOW_CLASS_INPUT_OUTPUT,
             },
         },
         xwayland::{XWaylandError, XWaylandEvent},
@@ -175,12 +175,15 @@ impl<T: XIpc> SelectionData<T> {
             destroy_data_offer::<T>(&offer.offer);
         }
         self.active_offer.take();
-        self.destroy_sources();
+        self.destroy_sources(true);
     }
 
-    fn destroy_sources(&self) {
+    fn destroy_sources(&self, teardown: bool) {
         for source in self.sources.lock().drain_values() {
-            destroy_data_source::<T>(&source);
+            match teardown {
+                true => teardown_data_source::<T>(&source),
+                false => destroy_data_source::<T>(&source),
+            }
         }
     }
 
@@ -1304,6 +1307,20 @@ impl Wm {
         {
             data.info.icccm_hints.input.set(true);
         }
+        if let Some(window) = data.window.get() {
+            let tl = &window.toplevel_data;
+            if data
+                .info
+                .icccm_hints
+                .flags
+                .get()
+                .contains(ICCCM_WM_HINT_X_URGENCY)
+            {
+                tl.request_attention(&*window);
+            } else {
+                tl.clear_attention(&*window);
+            }
+        }
         self.compute_input_model(data);
     }
 
@@ -1603,7 +1620,7 @@ impl Wm {
         if event.owner == sd.win.get() {
             return Ok(());
         }
-        sd.destroy_sources();
+        sd.destroy_sources(event.subtype != SET_SELECTION_OWNER);
         let cs = ConvertSelection {
             requestor: sd.win.get(),
             selection: sd.selection.get(),
@@ -17,16 +17,18 @@ use {
                 config::{ConfigParser, ConfigParserError},
                 float::Float,
                 focus_history::FocusHistory,
+                low_power::LowPower,
             },
         },
         toml::{self},
     },
     ahash::AHashMap,
     jay_config::{
-        Axis, Direction, Workspace,
+        Axis, DecorationMode, Direction, FocusStealingPolicy, Workspace, WorkspaceLayout,
+        client::ClientCapabilities,
         input::{
             LayerDirection, SwitchEvent, Timeline, acceleration::AccelProfile,
-            clickmethod::ClickMethod,
+            clickmethod::ClickMethod, scrollmethod::ScrollMethod, sendeventsmode::SendEventsMode,
         },
         keyboard::{Keymap, ModifiedKeySym, mods::Modifiers, syms::KeySym},
         logging::LogLevel,
@@ -81,6 +83,12 @@ pub enum SimpleCommand {
     FocusTiles,
     CreateMark,
     JumpToMark,
+    CycleKeyboardLayout(bool),
+    ShowWorkspaceRel(bool),
+    ResetGaps,
+    WarpPointerToWindow,
+    Zoom(bool),
+    ResetZoom,
 }
 
 #[derive(Debug, Clone)]
@@ -98,6 +106,7 @@ pub enum Action {
     ConfigureIdle {
         idle: Option<Duration>,
         grace_period: Option<Duration>,
+        dim: Option<f64>,
     },
     ConfigureInput {
         input: Box<Input>,
@@ -167,6 +176,29 @@ pub enum Action {
     CreateMark(u32),
     JumpToMark(u32),
     CopyMark(u32, u32),
+    SetWorkspaceLayout {
+        layout: WorkspaceLayout,
+    },
+    SetGaps {
+        inner: Option<i32>,
+        outer: Option<i32>,
+    },
+    AdjustGaps {
+        inner: i32,
+        outer: i32,
+    },
+    MoveWindowToOutput {
+        output: OutputMatch,
+    },
+    MoveWindowToAdjacentOutput {
+        direction: Direction,
+    },
+    MoveToScratchpad {
+        name: String,
+    },
+    ToggleScratchpad {
+        name: String,
+    },
 }
 
 #[derive(Debug, Clone, Default)]
@@ -189,6 +221,12 @@ pub struct Theme {
     pub border_width: Option<i32>,
     pub title_height: Option<i32>,
     pub font: Option<String>,
+    pub shadow_color: Option<Color>,
+    pub shadow_size: Option<i32>,
+    pub shadow_offset_x: Option<i32>,
+    pub shadow_offset_y: Option<i32>,
+    pub inner_gap: Option<i32>,
+    pub outer_gap: Option<i32>,
 }
 
 #[derive(Debug, Clone)]
@@ -204,6 +242,23 @@ pub struct UiDrag {
     pub threshold: Option<i32>,
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct SeatConfig {
+    pub cursor_theme: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ClientLimits {
+    pub max_objects: Option<u64>,
+    pub max_shm_size: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ClipboardPersistence {
+    pub enabled: Option<bool>,
+    pub max_size: Option<u64>,
+}
+
 #[derive(Debug, Clone)]
 pub enum OutputMatch {
     Any(Vec<OutputMatch>),
@@ -266,6 +321,9 @@ pub struct WindowRule {
     pub latch: Option<Action>,
     pub auto_focus: Option<bool>,
     pub initial_tile_state: Option<TileState>,
+    pub may_inhibit_shortcuts: Option<bool>,
+    pub opacity: Option<f64>,
+    pub initial_float_size: Option<(i32, i32)>,
 }
 
 #[derive(Default, Debug, Clone)]
@@ -342,6 +400,9 @@ pub struct Output {
     pub color_space: Option<ColorSpace>,
     pub transfer_function: Option<TransferFunction>,
     pub brightness: Option<Option<f64>>,
+    pub cursor_size: Option<i32>,
+    pub max_fps: Option<f64>,
+    pub workspaces: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -358,6 +419,8 @@ pub enum InputMatch {
         name: Option<String>,
         syspath: Option<String>,
         devnode: Option<String>,
+        vendor_id: Option<u32>,
+        product_id: Option<u32>,
         is_keyboard: Option<bool>,
         is_pointer: Option<bool>,
         is_touch: Option<bool>,
@@ -381,6 +444,9 @@ pub struct Input {
     pub natural_scrolling: Option<bool>,
     pub click_method: Option<ClickMethod>,
     pub middle_button_emulation: Option<bool>,
+    pub scroll_method: Option<ScrollMethod>,
+    pub disable_while_typing: Option<bool>,
+    pub send_events_mode: Option<SendEventsMode>,
     pub px_per_wheel_scroll: Option<f64>,
     pub transform_matrix: Option<[[f64; 2]; 2]>,
     pub keymap: Option<ConfigKeymap>,
@@ -452,6 +518,14 @@ pub struct Shortcut {
     pub keysym: ModifiedKeySym,
     pub action: Action,
     pub latch: Option<Action>,
+    pub repeat: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct SwipeGesture {
+    pub finger_count: u32,
+    pub direction: Direction,
+    pub action: Action,
 }
 
 #[derive(Debug, Clone)]
@@ -467,6 +541,7 @@ pub struct Config {
     pub shortcuts: Vec<Shortcut>,
     pub on_graphics_initialized: Option<Action>,
     pub on_idle: Option<Action>,
+    pub on_lock_request: Option<Action>,
     pub status: Option<Status>,
     pub connectors: Vec<ConfigConnector>,
     pub outputs: Vec<Output>,
@@ -483,6 +558,7 @@ pub struct Config {
     pub inputs: Vec<Input>,
     pub idle: Option<Duration>,
     pub grace_period: Option<Duration>,
+    pub idle_dim: Option<f64>,
     pub explicit_sync_enabled: Option<bool>,
     pub focus_follows_mouse: bool,
     pub window_management_key: Option<ModifiedKeySym>,
@@ -502,6 +578,27 @@ pub struct Config {
     pub show_bar: Option<bool>,
     pub focus_history: Option<FocusHistory>,
     pub middle_click_paste: Option<bool>,
+    pub freeze_cursor_animation_when_idle: Option<bool>,
+    pub cursor_theme: Option<String>,
+    pub inactive_window_opacity: Option<f64>,
+    pub workspace_animation_duration_ms: Option<f64>,
+    pub window_animation_duration_ms: Option<f64>,
+    pub focus_follows_mouse_delay_ms: Option<f64>,
+    pub focus_stealing_policy: Option<FocusStealingPolicy>,
+    pub per_window_keyboard_layout: Option<bool>,
+    pub swipe_gestures: Vec<SwipeGesture>,
+    pub workspace_layout: Option<WorkspaceLayout>,
+    pub swallow_app_ids: Option<Vec<String>>,
+    pub clipboard_persistence: ClipboardPersistence,
+    pub double_click_time_ms: Option<f64>,
+    pub double_click_distance: Option<i32>,
+    pub decoration_mode: Option<DecorationMode>,
+    pub client_limits: ClientLimits,
+    pub sandbox_capabilities: Vec<(String, ClientCapabilities)>,
+    pub low_power: Option<LowPower>,
+    pub cursor_follows_focus: Option<bool>,
+    pub hide_cursor_while_typing: Option<bool>,
+    pub seats: Vec<(String, SeatConfig)>,
 }
 
 #[derive(Debug, Error)]
@@ -8,19 +8,23 @@ use {
 
 pub mod action;
 mod actions;
+mod client_limits;
 mod client_match;
 mod client_rule;
+mod clipboard_persistence;
 mod color;
 pub mod color_management;
 pub mod config;
 mod connector;
 mod connector_match;
 mod content_type;
+mod direction;
 mod drm_device;
 mod drm_device_match;
 mod env;
 pub mod exec;
 pub mod float;
+mod float_size;
 pub mod focus_history;
 mod format;
 mod gfx_api;
@@ -30,14 +34,18 @@ mod input_match;
 pub mod keymap;
 mod libei;
 mod log_level;
+pub mod low_power;
 pub mod mark_id;
 mod mode;
 pub mod modified_keysym;
 mod output;
 mod output_match;
 mod repeat_rate;
+mod sandbox_capabilities;
+mod seat;
 pub mod shortcuts;
 mod status;
+mod swipe_gesture;
 mod tearing;
 mod theme;
 mod tile_state;
@@ -46,6 +54,7 @@ mod vrr;
 mod window_match;
 mod window_rule;
 mod window_type;
+mod workspace_layout;
 mod xwayland;
 
 #[derive(Debug, Error)]
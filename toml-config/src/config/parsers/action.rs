@@ -3,11 +3,12 @@ use {
         config::{
             Action, SimpleCommand,
             context::Context,
-            extractor::{Extractor, ExtractorError, arr, bol, n32, opt, str, val},
+            extractor::{Extractor, ExtractorError, arr, bol, n32, opt, s32, str, val},
             parser::{DataType, ParseResult, Parser, UnexpectedDataType},
             parsers::{
                 StringParser, StringParserError,
                 connector::{ConnectorParser, ConnectorParserError},
+                direction::{DirectionParser, DirectionParserError},
                 drm_device::{DrmDeviceParser, DrmDeviceParserError},
                 drm_device_match::{DrmDeviceMatchParser, DrmDeviceMatchParserError},
                 env::{EnvParser, EnvParserError},
@@ -23,6 +24,7 @@ use {
                 repeat_rate::{RepeatRateParser, RepeatRateParserError},
                 status::{StatusParser, StatusParserError},
                 theme::{ThemeParser, ThemeParserError},
+                workspace_layout::{WorkspaceLayoutParser, WorkspaceLayoutParserError},
             },
             spanned::SpannedErrorExt,
         },
@@ -88,6 +90,14 @@ pub enum ActionParserError {
     JumpToMark(#[source] MarkIdParserError),
     #[error("Could not parse a copy-mark action")]
     CopyMark(#[source] MarkIdParserError),
+    #[error("Could not parse a set-workspace-layout action")]
+    SetWorkspaceLayout(#[source] WorkspaceLayoutParserError),
+    #[error("Could not parse a move-window-to-output action")]
+    MoveWindowToOutput(#[source] OutputMatchParserError),
+    #[error("Could not parse a move-window-to-output action")]
+    MoveWindowToAdjacentOutput(#[source] DirectionParserError),
+    #[error("Exactly one of `output` and `direction` must be specified")]
+    OutputOrDirection,
 }
 
 pub struct ActionParser<'a>(pub &'a Context<'a>);
@@ -151,6 +161,15 @@ impl ActionParser<'_> {
             "focus-tiles" => FocusTiles,
             "create-mark" => CreateMark,
             "jump-to-mark" => JumpToMark,
+            "next-keyboard-layout" => CycleKeyboardLayout(true),
+            "prev-keyboard-layout" => CycleKeyboardLayout(false),
+            "show-next-workspace" => ShowWorkspaceRel(true),
+            "show-prev-workspace" => ShowWorkspaceRel(false),
+            "reset-gaps" => ResetGaps,
+            "warp-pointer-to-window" => WarpPointerToWindow,
+            "zoom-in" => Zoom(true),
+            "zoom-out" => Zoom(false),
+            "reset-zoom" => ResetZoom,
             _ => {
                 return Err(
                     ActionParserError::UnknownSimpleAction(string.to_string()).spanned(span)
@@ -220,6 +239,7 @@ impl ActionParser<'_> {
         Ok(Action::ConfigureIdle {
             idle: idle.timeout,
             grace_period: idle.grace_period,
+            dim: idle.dim,
         })
     }
 
@@ -348,6 +368,29 @@ impl ActionParser<'_> {
         })
     }
 
+    fn parse_move_window_to_output(
+        &mut self,
+        span: Span,
+        ext: &mut Extractor<'_>,
+    ) -> ParseResult<Self> {
+        let (output, direction) = ext.extract((opt(val("output")), opt(val("direction"))))?;
+        match (output, direction) {
+            (Some(output), None) => {
+                let output = output
+                    .parse_map(&mut OutputMatchParser(self.0))
+                    .map_spanned_err(ActionParserError::MoveWindowToOutput)?;
+                Ok(Action::MoveWindowToOutput { output })
+            }
+            (None, Some(direction)) => {
+                let direction = direction
+                    .parse_map(&mut DirectionParser)
+                    .map_spanned_err(ActionParserError::MoveWindowToAdjacentOutput)?;
+                Ok(Action::MoveWindowToAdjacentOutput { direction })
+            }
+            _ => Err(ActionParserError::OutputOrDirection.spanned(span)),
+        }
+    }
+
     fn parse_set_repeat_rate(&mut self, ext: &mut Extractor<'_>) -> ParseResult<Self> {
         let rate = ext
             .extract(val("rate"))?
@@ -414,6 +457,44 @@ impl ActionParser<'_> {
             .map_spanned_err(ActionParserError::CopyMark)?;
         Ok(Action::CopyMark(src, dst))
     }
+
+    fn parse_set_workspace_layout(&mut self, ext: &mut Extractor<'_>) -> ParseResult<Self> {
+        let layout = ext
+            .extract(val("layout"))?
+            .parse_map(&mut WorkspaceLayoutParser)
+            .map_spanned_err(ActionParserError::SetWorkspaceLayout)?;
+        Ok(Action::SetWorkspaceLayout { layout })
+    }
+
+    fn parse_move_to_scratchpad(&mut self, ext: &mut Extractor<'_>) -> ParseResult<Self> {
+        let name = ext.extract(str("name"))?;
+        Ok(Action::MoveToScratchpad {
+            name: name.value.to_string(),
+        })
+    }
+
+    fn parse_toggle_scratchpad(&mut self, ext: &mut Extractor<'_>) -> ParseResult<Self> {
+        let name = ext.extract(str("name"))?;
+        Ok(Action::ToggleScratchpad {
+            name: name.value.to_string(),
+        })
+    }
+
+    fn parse_set_gaps(&mut self, ext: &mut Extractor<'_>) -> ParseResult<Self> {
+        let (inner, outer) = ext.extract((opt(s32("inner")), opt(s32("outer"))))?;
+        Ok(Action::SetGaps {
+            inner: inner.despan(),
+            outer: outer.despan(),
+        })
+    }
+
+    fn parse_adjust_gaps(&mut self, ext: &mut Extractor<'_>) -> ParseResult<Self> {
+        let (inner, outer) = ext.extract((opt(s32("inner")), opt(s32("outer"))))?;
+        Ok(Action::AdjustGaps {
+            inner: inner.despan().unwrap_or_default(),
+            outer: outer.despan().unwrap_or_default(),
+        })
+    }
 }
 
 impl Parser for ActionParser<'_> {
@@ -471,6 +552,12 @@ impl Parser for ActionParser<'_> {
             "create-mark" => self.parse_create_mark(&mut ext),
             "jump-to-mark" => self.parse_jump_to_mark(&mut ext),
             "copy-mark" => self.parse_copy_mark(&mut ext),
+            "set-workspace-layout" => self.parse_set_workspace_layout(&mut ext),
+            "set-gaps" => self.parse_set_gaps(&mut ext),
+            "adjust-gaps" => self.parse_adjust_gaps(&mut ext),
+            "move-window-to-output" => self.parse_move_window_to_output(span, &mut ext),
+            "move-to-scratchpad" => self.parse_move_to_scratchpad(&mut ext),
+            "toggle-scratchpad" => self.parse_toggle_scratchpad(&mut ext),
             v => {
                 ext.ignore_unused();
                 return Err(ActionParserError::UnknownType(v.to_string()).spanned(ty.span));
@@ -0,0 +1,55 @@
+use {
+    crate::{
+        config::{
+            ClientLimits,
+            context::Context,
+            extractor::{Extractor, ExtractorError, int, opt, recover},
+            parser::{DataType, ParseResult, Parser, UnexpectedDataType},
+        },
+        toml::{
+            toml_span::{Span, Spanned},
+            toml_value::Value,
+        },
+    },
+    indexmap::IndexMap,
+    thiserror::Error,
+};
+
+#[derive(Debug, Error)]
+pub enum ClientLimitsParserError {
+    #[error(transparent)]
+    Expected(#[from] UnexpectedDataType),
+    #[error(transparent)]
+    Extract(#[from] ExtractorError),
+}
+
+pub struct ClientLimitsParser<'a>(pub &'a Context<'a>);
+
+impl Parser for ClientLimitsParser<'_> {
+    type Value = ClientLimits;
+    type Error = ClientLimitsParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::Table];
+
+    fn parse_table(
+        &mut self,
+        span: Span,
+        table: &IndexMap<Spanned<String>, Spanned<Value>>,
+    ) -> ParseResult<Self> {
+        let mut ext = Extractor::new(self.0, span, table);
+        let (max_objects, max_shm_size) = ext.extract((
+            recover(opt(int("max-objects"))),
+            recover(opt(int("max-shm-size"))),
+        ))?;
+        let non_negative = |v: Option<Spanned<i64>>, name: &str| match v {
+            Some(v) if v.value < 0 => {
+                log::warn!("{name} must not be negative: {}", self.0.error3(v.span));
+                None
+            }
+            v => v.map(|v| v.value as u64),
+        };
+        Ok(ClientLimits {
+            max_objects: non_negative(max_objects, "max-objects"),
+            max_shm_size: non_negative(max_shm_size, "max-shm-size"),
+        })
+    }
+}
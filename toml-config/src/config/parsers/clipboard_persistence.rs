@@ -0,0 +1,53 @@
+use {
+    crate::{
+        config::{
+            ClipboardPersistence,
+            context::Context,
+            extractor::{Extractor, ExtractorError, bol, int, opt, recover},
+            parser::{DataType, ParseResult, Parser, UnexpectedDataType},
+        },
+        toml::{
+            toml_span::{DespanExt, Span, Spanned},
+            toml_value::Value,
+        },
+    },
+    indexmap::IndexMap,
+    thiserror::Error,
+};
+
+#[derive(Debug, Error)]
+pub enum ClipboardPersistenceParserError {
+    #[error(transparent)]
+    Expected(#[from] UnexpectedDataType),
+    #[error(transparent)]
+    Extract(#[from] ExtractorError),
+}
+
+pub struct ClipboardPersistenceParser<'a>(pub &'a Context<'a>);
+
+impl Parser for ClipboardPersistenceParser<'_> {
+    type Value = ClipboardPersistence;
+    type Error = ClipboardPersistenceParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::Table];
+
+    fn parse_table(
+        &mut self,
+        span: Span,
+        table: &IndexMap<Spanned<String>, Spanned<Value>>,
+    ) -> ParseResult<Self> {
+        let mut ext = Extractor::new(self.0, span, table);
+        let (enabled, max_size) =
+            ext.extract((recover(opt(bol("enabled"))), recover(opt(int("max-size")))))?;
+        let mut max_size = max_size.despan();
+        if let Some(size) = max_size
+            && size < 0
+        {
+            log::warn!("max-size must not be negative");
+            max_size = None;
+        }
+        Ok(ClipboardPersistence {
+            enabled: enabled.despan(),
+            max_size: max_size.map(|v| v as u64),
+        })
+    }
+}
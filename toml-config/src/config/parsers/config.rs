@@ -1,15 +1,20 @@
 use {
     crate::{
         config::{
-            Action, Config, Libei, Theme, UiDrag,
+            Action, ClientLimits, ClipboardPersistence, Config, Libei, Theme, UiDrag,
             context::Context,
-            extractor::{Extractor, ExtractorError, arr, bol, int, opt, recover, str, val},
+            extractor::{
+                Extractor, ExtractorError, arr, bol, fltorint, int, opt, recover, str, val,
+            },
             keysyms::KEYSYMS,
             parser::{DataType, ParseResult, Parser, UnexpectedDataType},
             parsers::{
+                StringParser,
                 action::ActionParser,
                 actions::ActionsParser,
+                client_limits::ClientLimitsParser,
                 client_rule::ClientRulesParser,
+                clipboard_persistence::ClipboardPersistenceParser,
                 color_management::ColorManagementParser,
                 connector::ConnectorsParser,
                 drm_device::DrmDevicesParser,
@@ -23,18 +28,23 @@ use {
                 keymap::KeymapParser,
                 libei::LibeiParser,
                 log_level::LogLevelParser,
+                low_power::LowPowerParser,
                 output::OutputsParser,
                 repeat_rate::RepeatRateParser,
+                sandbox_capabilities::SandboxCapabilitiesParser,
+                seat::SeatsParser,
                 shortcuts::{
                     ComplexShortcutsParser, ShortcutsParser, ShortcutsParserError,
                     parse_modified_keysym_str,
                 },
                 status::StatusParser,
+                swipe_gesture::SwipeGesturesParser,
                 tearing::TearingParser,
                 theme::ThemeParser,
                 ui_drag::UiDragParser,
                 vrr::VrrParser,
                 window_rule::WindowRulesParser,
+                workspace_layout::WorkspaceLayoutParser,
                 xwayland::XwaylandParser,
             },
             spanned::SpannedErrorExt,
@@ -45,6 +55,7 @@ use {
         },
     },
     indexmap::IndexMap,
+    jay_config::{DecorationMode, FocusStealingPolicy},
     std::collections::HashSet,
     thiserror::Error,
 };
@@ -136,7 +147,31 @@ impl Parser for ConfigParser<'_> {
                 show_bar,
                 focus_history_val,
             ),
-            (middle_click_paste,),
+            (
+                middle_click_paste,
+                freeze_cursor_animation_when_idle,
+                cursor_theme,
+                on_lock_request_val,
+                inactive_window_opacity,
+                workspace_animation_duration_ms,
+                window_animation_duration_ms,
+                focus_follows_mouse_delay_ms,
+                focus_stealing_policy_val,
+                per_window_keyboard_layout,
+            ),
+            (
+                swipe_gestures_val,
+                workspace_layout_val,
+                swallow_app_ids_val,
+                clipboard_persistence_val,
+                double_click_time_ms,
+                double_click_distance,
+                decoration_mode_val,
+                client_limits_val,
+                sandbox_capabilities_val,
+                low_power_val,
+            ),
+            (cursor_follows_focus, hide_cursor_while_typing, seats_val),
         ) = ext.extract((
             (
                 opt(val("keymap")),
@@ -186,8 +221,71 @@ impl Parser for ConfigParser<'_> {
                 recover(opt(bol("show-bar"))),
                 opt(val("focus-history")),
             ),
-            (recover(opt(bol("middle-click-paste"))),),
+            (
+                recover(opt(bol("middle-click-paste"))),
+                recover(opt(bol("freeze-cursor-animation-when-idle"))),
+                recover(opt(str("cursor-theme"))),
+                opt(val("on-lock-request")),
+                recover(opt(fltorint("inactive-window-opacity"))),
+                recover(opt(fltorint("workspace-animation-duration-ms"))),
+                recover(opt(fltorint("window-animation-duration-ms"))),
+                recover(opt(fltorint("focus-follows-mouse-delay-ms"))),
+                recover(opt(str("focus-stealing-policy"))),
+                recover(opt(bol("per-window-keyboard-layout"))),
+            ),
+            (
+                opt(val("swipe-gestures")),
+                opt(val("workspace-layout")),
+                recover(opt(arr("swallow-app-ids"))),
+                opt(val("clipboard-persistence")),
+                recover(opt(fltorint("double-click-time-ms"))),
+                recover(opt(int("double-click-distance"))),
+                recover(opt(str("decoration-mode"))),
+                opt(val("client-limits")),
+                opt(val("sandbox-capabilities")),
+                opt(val("low-power")),
+            ),
+            (
+                recover(opt(bol("cursor-follows-focus"))),
+                recover(opt(bol("hide-cursor-while-typing"))),
+                opt(val("seats")),
+            ),
         ))?;
+        let focus_stealing_policy = match focus_stealing_policy_val {
+            None => None,
+            Some(p) => match p.value {
+                "never" => Some(FocusStealingPolicy::NEVER),
+                "always" => Some(FocusStealingPolicy::ALWAYS),
+                "same-app" => Some(FocusStealingPolicy::SAME_APP),
+                "smart" => Some(FocusStealingPolicy::SMART),
+                _ => {
+                    log::warn!(
+                        "Unknown focus stealing policy {}. Valid values are never, always, \
+                         same-app, and smart: {}",
+                        p.value,
+                        self.0.error3(p.span)
+                    );
+                    None
+                }
+            },
+        };
+        let decoration_mode = match decoration_mode_val {
+            None => None,
+            Some(p) => match p.value {
+                "client-preference" => Some(DecorationMode::CLIENT_PREFERENCE),
+                "server-side" => Some(DecorationMode::SERVER_SIDE),
+                "client-side" => Some(DecorationMode::CLIENT_SIDE),
+                _ => {
+                    log::warn!(
+                        "Unknown decoration mode {}. Valid values are client-preference, \
+                         server-side, and client-side: {}",
+                        p.value,
+                        self.0.error3(p.span)
+                    );
+                    None
+                }
+            },
+        };
         let mut keymap = None;
         if let Some(value) = keymap_val {
             match value.parse(&mut KeymapParser {
@@ -226,6 +324,7 @@ impl Parser for ConfigParser<'_> {
         let on_graphics_initialized =
             self.parse_action("on-graphics-initialized", on_graphics_init_val);
         let on_idle = self.parse_action("on-idle", on_idle_val);
+        let on_lock_request = self.parse_action("on-lock-request", on_lock_request_val);
         let on_startup = self.parse_action("on-startup", on_startup_val);
         let mut status = None;
         if let Some(value) = status_val {
@@ -328,11 +427,13 @@ impl Parser for ConfigParser<'_> {
         }
         let mut idle = None;
         let mut grace_period = None;
+        let mut idle_dim = None;
         if let Some(value) = idle_val {
             match value.parse(&mut IdleParser(self.0)) {
                 Ok(v) => {
                     idle = v.timeout;
                     grace_period = v.grace_period;
+                    idle_dim = v.dim;
                 }
                 Err(e) => {
                     log::warn!("Could not parse the idle timeout: {}", self.0.error(e));
@@ -390,6 +491,48 @@ impl Parser for ConfigParser<'_> {
                 }
             }
         }
+        let mut clipboard_persistence = ClipboardPersistence::default();
+        if let Some(value) = clipboard_persistence_val {
+            match value.parse(&mut ClipboardPersistenceParser(self.0)) {
+                Ok(v) => clipboard_persistence = v,
+                Err(e) => {
+                    log::warn!(
+                        "Could not parse clipboard-persistence setting: {}",
+                        self.0.error(e)
+                    );
+                }
+            }
+        }
+        let mut client_limits = ClientLimits::default();
+        if let Some(value) = client_limits_val {
+            match value.parse(&mut ClientLimitsParser(self.0)) {
+                Ok(v) => client_limits = v,
+                Err(e) => {
+                    log::warn!("Could not parse client-limits setting: {}", self.0.error(e));
+                }
+            }
+        }
+        let mut seats = vec![];
+        if let Some(value) = seats_val {
+            match value.parse(&mut SeatsParser(self.0)) {
+                Ok(v) => seats = v,
+                Err(e) => {
+                    log::warn!("Could not parse seats setting: {}", self.0.error(e));
+                }
+            }
+        }
+        let mut sandbox_capabilities = vec![];
+        if let Some(value) = sandbox_capabilities_val {
+            match value.parse(&mut SandboxCapabilitiesParser) {
+                Ok(v) => sandbox_capabilities = v,
+                Err(e) => {
+                    log::warn!(
+                        "Could not parse sandbox-capabilities setting: {}",
+                        self.0.error(e)
+                    );
+                }
+            }
+        }
         let mut xwayland = None;
         if let Some(value) = xwayland_val {
             match value.parse(&mut XwaylandParser(self.0)) {
@@ -411,6 +554,18 @@ impl Parser for ConfigParser<'_> {
                 }
             }
         }
+        let mut low_power = None;
+        if let Some(value) = low_power_val {
+            match value.parse(&mut LowPowerParser(self.0)) {
+                Ok(v) => low_power = Some(v),
+                Err(e) => {
+                    log::warn!(
+                        "Could not parse the low-power settings: {}",
+                        self.0.error(e)
+                    );
+                }
+            }
+        }
         let mut float = None;
         if let Some(value) = float_val {
             match value.parse(&mut FloatParser(self.0)) {
@@ -456,6 +611,31 @@ impl Parser for ConfigParser<'_> {
                 Err(e) => log::warn!("Could not parse the window rules: {}", self.0.error(e)),
             }
         }
+        let mut swipe_gestures = vec![];
+        if let Some(value) = swipe_gestures_val {
+            match value.parse(&mut SwipeGesturesParser(self.0)) {
+                Ok(v) => swipe_gestures = v,
+                Err(e) => log::warn!("Could not parse the swipe gestures: {}", self.0.error(e)),
+            }
+        }
+        let mut workspace_layout = None;
+        if let Some(value) = workspace_layout_val {
+            match value.parse(&mut WorkspaceLayoutParser) {
+                Ok(v) => workspace_layout = Some(v),
+                Err(e) => log::warn!("Could not parse the workspace layout: {}", self.0.error(e)),
+            }
+        }
+        let mut swallow_app_ids = None;
+        if let Some(value) = swallow_app_ids_val {
+            let mut app_ids = vec![];
+            for value in value.value {
+                match value.parse(&mut StringParser) {
+                    Ok(v) => app_ids.push(v),
+                    Err(e) => log::warn!("Could not parse an app-id: {}", self.0.error(e)),
+                }
+            }
+            swallow_app_ids = Some(app_ids);
+        }
         let mut pointer_revert_key = None;
         if let Some(value) = pointer_revert_key_str {
             match KEYSYMS.get(value.value) {
@@ -481,6 +661,7 @@ impl Parser for ConfigParser<'_> {
             shortcuts,
             on_graphics_initialized,
             on_idle,
+            on_lock_request,
             status,
             outputs,
             connectors,
@@ -498,6 +679,7 @@ impl Parser for ConfigParser<'_> {
             inputs,
             idle,
             grace_period,
+            idle_dim,
             focus_follows_mouse: focus_follows_mouse.despan().unwrap_or(true),
             window_management_key,
             vrr,
@@ -516,6 +698,27 @@ impl Parser for ConfigParser<'_> {
             show_bar: show_bar.despan(),
             focus_history,
             middle_click_paste: middle_click_paste.despan(),
+            freeze_cursor_animation_when_idle: freeze_cursor_animation_when_idle.despan(),
+            cursor_theme: cursor_theme.despan().map(|v| v.to_string()),
+            inactive_window_opacity: inactive_window_opacity.despan(),
+            workspace_animation_duration_ms: workspace_animation_duration_ms.despan(),
+            window_animation_duration_ms: window_animation_duration_ms.despan(),
+            focus_follows_mouse_delay_ms: focus_follows_mouse_delay_ms.despan(),
+            focus_stealing_policy,
+            per_window_keyboard_layout: per_window_keyboard_layout.despan(),
+            swipe_gestures,
+            workspace_layout,
+            swallow_app_ids,
+            clipboard_persistence,
+            double_click_time_ms: double_click_time_ms.despan(),
+            double_click_distance: double_click_distance.despan().map(|v| v as i32),
+            decoration_mode,
+            client_limits,
+            sandbox_capabilities,
+            low_power,
+            cursor_follows_focus: cursor_follows_focus.despan(),
+            hide_cursor_while_typing: hide_cursor_while_typing.despan(),
+            seats,
         })
     }
 }
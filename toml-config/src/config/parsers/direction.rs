@@ -0,0 +1,35 @@
+use {
+    crate::{
+        config::parser::{DataType, ParseResult, Parser, UnexpectedDataType},
+        toml::toml_span::{Span, SpannedExt},
+    },
+    jay_config::Direction,
+    thiserror::Error,
+};
+
+pub struct DirectionParser;
+
+#[derive(Debug, Error)]
+pub enum DirectionParserError {
+    #[error(transparent)]
+    DataType(#[from] UnexpectedDataType),
+    #[error("Unknown direction {0}")]
+    Unknown(String),
+}
+
+impl Parser for DirectionParser {
+    type Value = Direction;
+    type Error = DirectionParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::String];
+
+    fn parse_string(&mut self, span: Span, string: &str) -> ParseResult<Self> {
+        let direction = match string {
+            "left" => Direction::Left,
+            "right" => Direction::Right,
+            "up" => Direction::Up,
+            "down" => Direction::Down,
+            _ => return Err(DirectionParserError::Unknown(string.to_string()).spanned(span)),
+        };
+        Ok(direction)
+    }
+}
@@ -27,6 +27,7 @@ pub struct FloatParser<'a>(pub &'a Context<'a>);
 #[derive(Debug, Clone)]
 pub struct Float {
     pub show_pin_icon: Option<bool>,
+    pub show_close_icon: Option<bool>,
 }
 
 impl Parser for FloatParser<'_> {
@@ -40,9 +41,13 @@ impl Parser for FloatParser<'_> {
         table: &IndexMap<Spanned<String>, Spanned<Value>>,
     ) -> ParseResult<Self> {
         let mut ext = Extractor::new(self.0, span, table);
-        let (show_pin_icon,) = ext.extract((recover(opt(bol("show-pin-icon"))),))?;
+        let (show_pin_icon, show_close_icon) = ext.extract((
+            recover(opt(bol("show-pin-icon"))),
+            recover(opt(bol("show-close-icon"))),
+        ))?;
         Ok(Float {
             show_pin_icon: show_pin_icon.despan(),
+            show_close_icon: show_close_icon.despan(),
         })
     }
 }
@@ -2,7 +2,7 @@ use {
     crate::{
         config::{
             context::Context,
-            extractor::{Extractor, ExtractorError, n64, opt, val},
+            extractor::{Extractor, ExtractorError, fltorint, n64, opt, recover, val},
             parser::{DataType, ParseResult, Parser, UnexpectedDataType},
         },
         toml::{
@@ -28,6 +28,7 @@ pub struct IdleParser<'a>(pub &'a Context<'a>);
 pub struct Idle {
     pub timeout: Option<Duration>,
     pub grace_period: Option<Duration>,
+    pub dim: Option<f64>,
 }
 
 impl Parser for IdleParser<'_> {
@@ -41,10 +42,11 @@ impl Parser for IdleParser<'_> {
         table: &IndexMap<Spanned<String>, Spanned<Value>>,
     ) -> ParseResult<Self> {
         let mut ext = Extractor::new(self.0, span, table);
-        let (minutes, seconds, grace_period_val) = ext.extract((
+        let (minutes, seconds, grace_period_val, dim) = ext.extract((
             opt(n64("minutes")),
             opt(n64("seconds")),
             opt(val("grace-period")),
+            recover(opt(fltorint("dim"))),
         ))?;
         let mut timeout = None;
         if minutes.is_some() || seconds.is_some() {
@@ -54,9 +56,17 @@ impl Parser for IdleParser<'_> {
         if let Some(gp) = grace_period_val {
             grace_period = Some(gp.parse(&mut GracePeriodParser(self.0))?);
         }
+        let dim = match dim {
+            Some(d) if !(0.0..=1.0).contains(&d.value) => {
+                log::warn!("dim must be between 0 and 1: {}", self.0.error3(d.span));
+                None
+            }
+            d => d.despan(),
+        };
         Ok(Idle {
             timeout,
             grace_period,
+            dim,
         })
     }
 }
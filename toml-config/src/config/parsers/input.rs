@@ -23,6 +23,14 @@ use {
         SwitchEvent,
         acceleration::{ACCEL_PROFILE_ADAPTIVE, ACCEL_PROFILE_FLAT},
         clickmethod::{CLICK_METHOD_BUTTON_AREAS, CLICK_METHOD_CLICKFINGER, CLICK_METHOD_NONE},
+        scrollmethod::{
+            SCROLL_METHOD_EDGE, SCROLL_METHOD_NO_SCROLL, SCROLL_METHOD_ON_BUTTON_DOWN,
+            SCROLL_METHOD_TWO_FINGER,
+        },
+        sendeventsmode::{
+            SEND_EVENTS_MODE_DISABLED, SEND_EVENTS_MODE_DISABLED_ON_EXTERNAL_MOUSE,
+            SEND_EVENTS_MODE_ENABLED,
+        },
     },
     thiserror::Error,
 };
@@ -90,7 +98,7 @@ impl Parser for InputParser<'_> {
                 calibration_matrix,
                 click_method,
             ),
-            (middle_button_emulation,),
+            (middle_button_emulation, scroll_method, disable_while_typing, send_events_mode),
         ) = ext.extract((
             (
                 opt(str("tag")),
@@ -116,7 +124,12 @@ impl Parser for InputParser<'_> {
                 recover(opt(val("calibration-matrix"))),
                 recover(opt(str("click-method"))),
             ),
-            (recover(opt(bol("middle-button-emulation"))),),
+            (
+                recover(opt(bol("middle-button-emulation"))),
+                recover(opt(str("scroll-method"))),
+                recover(opt(bol("disable-while-typing"))),
+                recover(opt(str("send-events-mode"))),
+            ),
         ))?;
         let accel_profile = match accel_profile {
             None => None,
@@ -141,6 +154,31 @@ impl Parser for InputParser<'_> {
                 }
             },
         };
+        let scroll_method = match scroll_method {
+            None => None,
+            Some(p) => match p.value.to_ascii_lowercase().as_str() {
+                "no-scroll" => Some(SCROLL_METHOD_NO_SCROLL),
+                "two-finger" => Some(SCROLL_METHOD_TWO_FINGER),
+                "edge" => Some(SCROLL_METHOD_EDGE),
+                "on-button-down" => Some(SCROLL_METHOD_ON_BUTTON_DOWN),
+                v => {
+                    log::warn!("Unknown scroll-method {v}: {}", self.cx.error3(p.span));
+                    None
+                }
+            },
+        };
+        let send_events_mode = match send_events_mode {
+            None => None,
+            Some(p) => match p.value.to_ascii_lowercase().as_str() {
+                "enabled" => Some(SEND_EVENTS_MODE_ENABLED),
+                "disabled" => Some(SEND_EVENTS_MODE_DISABLED),
+                "disabled-on-external-mouse" => Some(SEND_EVENTS_MODE_DISABLED_ON_EXTERNAL_MOUSE),
+                v => {
+                    log::warn!("Unknown send-events-mode {v}: {}", self.cx.error3(p.span));
+                    None
+                }
+            },
+        };
         let transform_matrix = match transform_matrix {
             None => None,
             Some(matrix) => match matrix.parse(&mut TransformMatrixParser) {
@@ -261,6 +299,9 @@ impl Parser for InputParser<'_> {
             natural_scrolling: natural_scrolling.despan(),
             middle_button_emulation: middle_button_emulation.despan(),
             click_method,
+            scroll_method,
+            disable_while_typing: disable_while_typing.despan(),
+            send_events_mode,
             px_per_wheel_scroll: px_per_wheel_scroll.despan(),
             transform_matrix,
             keymap,
@@ -3,7 +3,7 @@ use {
         config::{
             InputMatch,
             context::Context,
-            extractor::{Extractor, ExtractorError, bol, opt, str},
+            extractor::{Extractor, ExtractorError, bol, n32, opt, recover, str},
             parser::{DataType, ParseResult, Parser, UnexpectedDataType},
         },
         toml::{
@@ -62,7 +62,7 @@ impl Parser for InputMatchParser<'_> {
                 is_tablet_pad,
                 is_gesture,
             ),
-            (is_switch,),
+            (is_switch, vendor_id, product_id),
         ) = ext.extract((
             (
                 opt(str("tag")),
@@ -76,7 +76,11 @@ impl Parser for InputMatchParser<'_> {
                 opt(bol("is-tablet-pad")),
                 opt(bol("is-gesture")),
             ),
-            (opt(bol("is-switch")),),
+            (
+                opt(bol("is-switch")),
+                recover(opt(n32("vendor-id"))),
+                recover(opt(n32("product-id"))),
+            ),
         ))?;
         if let Some(tag) = tag {
             self.0.used.borrow_mut().inputs.push(tag.into());
@@ -86,6 +90,8 @@ impl Parser for InputMatchParser<'_> {
             name: name.despan_into(),
             syspath: syspath.despan_into(),
             devnode: devnode.despan_into(),
+            vendor_id: vendor_id.despan(),
+            product_id: product_id.despan(),
             is_keyboard: is_keyboard.despan(),
             is_pointer: is_pointer.despan(),
             is_touch: is_touch.despan(),
@@ -3,8 +3,9 @@ use {
         config::{
             ConfigKeymap,
             context::Context,
-            extractor::{Extractor, ExtractorError, opt, str},
+            extractor::{Extractor, ExtractorError, arr, opt, recover, str},
             parser::{DataType, ParseResult, Parser, UnexpectedDataType},
+            parsers::StringParser,
         },
         toml::{
             toml_span::{Span, Spanned, SpannedExt},
@@ -16,7 +17,7 @@ use {
         config_dir,
         keyboard::{Keymap, parse_keymap},
     },
-    std::{io, path::PathBuf},
+    std::{fmt::Write, io, path::PathBuf},
     thiserror::Error,
 };
 
@@ -34,6 +35,12 @@ pub enum KeymapParserError {
     DefinitionRequired,
     #[error("Could not read {0}")]
     ReadFile(String, #[source] io::Error),
+    #[error("`layouts` must contain at least one layout")]
+    NoLayouts,
+    #[error("`{0}` is not a valid XKB component name")]
+    InvalidComponent(String),
+    #[error("`{0}` is not a valid XKB option")]
+    InvalidOption(String),
 }
 
 pub struct KeymapParser<'a> {
@@ -56,8 +63,24 @@ impl Parser for KeymapParser<'_> {
         table: &IndexMap<Spanned<String>, Spanned<Value>>,
     ) -> ParseResult<Self> {
         let mut ext = Extractor::new(self.cx, span, table);
-        let (mut name_val, mut map_val, mut path) =
-            ext.extract((opt(str("name")), opt(str("map")), opt(str("path"))))?;
+        let (mut name_val, mut map_val, mut path, mut layouts, variants, options) =
+            ext.extract((
+                opt(str("name")),
+                opt(str("map")),
+                opt(str("path")),
+                recover(opt(arr("layouts"))),
+                recover(opt(arr("variants"))),
+                recover(opt(arr("options"))),
+            ))?;
+        if let Some(l) = layouts
+            && (map_val.is_some() || path.is_some())
+        {
+            log::warn!(
+                "`layouts` cannot be combined with `map` or `path`. Ignoring `layouts`: {}",
+                self.cx.error3(l.span)
+            );
+            layouts = None;
+        }
         if map_val.is_some() && path.is_some() {
             log::warn!(
                 "Both `name` and `path` are specified. Ignoring `path`: {}",
@@ -78,6 +101,22 @@ impl Parser for KeymapParser<'_> {
             };
             map_val = Some(file_content.as_str().spanned(path.span));
         }
+        let layouts_content;
+        if let Some(layouts) = layouts {
+            let layouts_list = self.strings(layouts.value);
+            let variants = variants.map(|v| self.strings(v.value)).unwrap_or_default();
+            let options = options.map(|v| self.strings(v.value)).unwrap_or_default();
+            layouts_content = build_keymap(&layouts_list, &variants, &options)
+                .map_err(|e| e.spanned(layouts.span))?;
+            map_val = Some(layouts_content.as_str().spanned(layouts.span));
+        } else {
+            for v in [variants, options].into_iter().flatten() {
+                log::warn!(
+                    "`variants` and `options` have no effect without `layouts`: {}",
+                    self.cx.error3(v.span)
+                );
+            }
+        }
         if self.definition && (name_val.is_none() || map_val.is_none()) {
             return Err(KeymapParserError::DefinitionRequired.spanned(span));
         }
@@ -114,6 +153,78 @@ impl Parser for KeymapParser<'_> {
     }
 }
 
+impl KeymapParser<'_> {
+    fn strings(&self, array: &[Spanned<Value>]) -> Vec<String> {
+        let mut res = vec![];
+        for el in array {
+            match el.parse(&mut StringParser) {
+                Ok(s) => res.push(s),
+                Err(e) => log::warn!("Could not parse string: {}", self.cx.error(e)),
+            }
+        }
+        res
+    }
+}
+
+fn build_keymap(
+    layouts: &[String],
+    variants: &[String],
+    options: &[String],
+) -> Result<String, KeymapParserError> {
+    fn check(s: &str) -> Result<(), KeymapParserError> {
+        let valid = !s.is_empty()
+            && s.bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'_' | b'-'));
+        match valid {
+            true => Ok(()),
+            false => Err(KeymapParserError::InvalidComponent(s.to_string())),
+        }
+    }
+    if layouts.is_empty() {
+        return Err(KeymapParserError::NoLayouts);
+    }
+    let mut symbols = "pc".to_string();
+    for (idx, layout) in layouts.iter().enumerate() {
+        check(layout)?;
+        symbols.push('+');
+        symbols.push_str(layout);
+        if let Some(variant) = variants.get(idx)
+            && !variant.is_empty()
+        {
+            check(variant)?;
+            let _ = write!(symbols, "({variant})");
+        }
+        if idx > 0 {
+            let _ = write!(symbols, ":{}", idx + 1);
+        }
+    }
+    symbols.push_str("+inet(evdev)");
+    for option in options {
+        let Some((prefix, name)) = option.split_once(':') else {
+            return Err(KeymapParserError::InvalidOption(option.to_string()));
+        };
+        check(prefix)?;
+        check(name)?;
+        let file = match prefix {
+            "grp" => "group",
+            "caps" => "capslock",
+            "lv3" => "level3",
+            "lv5" => "level5",
+            _ => prefix,
+        };
+        let _ = write!(symbols, "+{file}({name})");
+    }
+    Ok(format!(
+        r#"xkb_keymap {{
+    xkb_keycodes {{ include "evdev+aliases(qwerty)" }};
+    xkb_types    {{ include "complete"              }};
+    xkb_compat   {{ include "complete"              }};
+    xkb_symbols  {{ include "{symbols}" }};
+}};
+"#
+    ))
+}
+
 fn parse(span: Span, string: &str) -> Result<Keymap, Spanned<KeymapParserError>> {
     let map = parse_keymap(string);
     match map.is_valid() {
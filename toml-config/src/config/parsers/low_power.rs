@@ -0,0 +1,60 @@
+use {
+    crate::{
+        config::{
+            context::Context,
+            extractor::{Extractor, ExtractorError, bol, fltorint, opt, recover},
+            parser::{DataType, ParseResult, Parser, UnexpectedDataType},
+        },
+        toml::{
+            toml_span::{DespanExt, Span, Spanned},
+            toml_value::Value,
+        },
+    },
+    indexmap::IndexMap,
+    thiserror::Error,
+};
+
+#[derive(Debug, Error)]
+pub enum LowPowerParserError {
+    #[error(transparent)]
+    Expected(#[from] UnexpectedDataType),
+    #[error(transparent)]
+    Extract(#[from] ExtractorError),
+}
+
+pub struct LowPowerParser<'a>(pub &'a Context<'a>);
+
+#[derive(Debug, Clone)]
+pub struct LowPower {
+    pub enabled: Option<bool>,
+    pub max_fps: Option<f64>,
+}
+
+impl Parser for LowPowerParser<'_> {
+    type Value = LowPower;
+    type Error = LowPowerParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::Table];
+
+    fn parse_table(
+        &mut self,
+        span: Span,
+        table: &IndexMap<Spanned<String>, Spanned<Value>>,
+    ) -> ParseResult<Self> {
+        let mut ext = Extractor::new(self.0, span, table);
+        let (enabled, max_fps) = ext.extract((
+            recover(opt(bol("enabled"))),
+            recover(opt(fltorint("max-fps"))),
+        ))?;
+        let max_fps = match max_fps {
+            Some(f) if f.value < 0.0 => {
+                log::warn!("max-fps must not be negative: {}", self.0.error3(f.span));
+                None
+            }
+            f => f.despan(),
+        };
+        Ok(LowPower {
+            enabled: enabled.despan(),
+            max_fps,
+        })
+    }
+}
@@ -1,11 +1,15 @@
+#[cfg(test)]
+mod tests;
+
 use {
     crate::{
         config::{
             Output,
             context::Context,
-            extractor::{Extractor, ExtractorError, fltorint, opt, recover, s32, str, val},
+            extractor::{Extractor, ExtractorError, arr, fltorint, opt, recover, s32, str, val},
             parser::{DataType, ParseResult, Parser, UnexpectedDataType},
             parsers::{
+                StringParser,
                 format::FormatParser,
                 mode::ModeParser,
                 output_match::{OutputMatchParser, OutputMatchParserError},
@@ -51,7 +55,7 @@ impl Parser for OutputParser<'_> {
         let mut ext = Extractor::new(self.cx, span, table);
         let (
             (name, match_val, x, y, scale, transform, mode, vrr_val, tearing_val, format_val),
-            (color_space, transfer_function, brightness_val),
+            (color_space, transfer_function, brightness_val, cursor_size, max_fps, workspaces_val),
         ) = ext.extract((
             (
                 opt(str("name")),
@@ -69,25 +73,52 @@ impl Parser for OutputParser<'_> {
                 recover(opt(str("color-space"))),
                 recover(opt(str("transfer-function"))),
                 opt(val("brightness")),
+                recover(opt(s32("cursor-size"))),
+                recover(opt(fltorint("max-fps"))),
+                recover(opt(arr("workspaces"))),
             ),
         ))?;
+        let scale = match scale {
+            Some(s) if !(0.1..=1000.0).contains(&s.value) => {
+                log::warn!(
+                    "Scale must be between 0.1 and 1000, for example 1, 1.25, or 1.5: {}",
+                    self.cx.error3(s.span)
+                );
+                None
+            }
+            s => s.despan(),
+        };
         let transform = match transform {
             None => None,
             Some(t) => match t.value {
-                "none" => Some(Transform::None),
-                "rotate-90" => Some(Transform::Rotate90),
-                "rotate-180" => Some(Transform::Rotate180),
-                "rotate-270" => Some(Transform::Rotate270),
-                "flip" => Some(Transform::Flip),
-                "flip-rotate-90" => Some(Transform::FlipRotate90),
-                "flip-rotate-180" => Some(Transform::FlipRotate180),
-                "flip-rotate-270" => Some(Transform::FlipRotate270),
+                "none" | "normal" => Some(Transform::None),
+                "rotate-90" | "90" => Some(Transform::Rotate90),
+                "rotate-180" | "180" => Some(Transform::Rotate180),
+                "rotate-270" | "270" => Some(Transform::Rotate270),
+                "flip" | "flipped" => Some(Transform::Flip),
+                "flip-rotate-90" | "flipped-90" => Some(Transform::FlipRotate90),
+                "flip-rotate-180" | "flipped-180" => Some(Transform::FlipRotate180),
+                "flip-rotate-270" | "flipped-270" => Some(Transform::FlipRotate270),
                 _ => {
-                    log::warn!("Unknown transform {}: {}", t.value, self.cx.error3(t.span));
+                    log::warn!(
+                        "Unknown transform {}. Valid values are none, rotate-90, rotate-180, \
+                         rotate-270, flip, flip-rotate-90, flip-rotate-180, flip-rotate-270, \
+                         normal, 90, 180, 270, flipped, flipped-90, flipped-180, and \
+                         flipped-270: {}",
+                        t.value,
+                        self.cx.error3(t.span)
+                    );
                     None
                 }
             },
         };
+        let max_fps = match max_fps {
+            Some(f) if f.value < 0.0 => {
+                log::warn!("max-fps must not be negative: {}", self.cx.error3(f.span));
+                None
+            }
+            f => f.despan(),
+        };
         let color_space = match color_space {
             None => None,
             Some(cs) => match cs.value {
@@ -181,12 +212,23 @@ impl Parser for OutputParser<'_> {
                 }
             }
         }
+        let mut workspaces = vec![];
+        if let Some(value) = workspaces_val {
+            for ws in value.value {
+                match ws.parse(&mut StringParser) {
+                    Ok(v) => workspaces.push(v),
+                    Err(e) => {
+                        log::warn!("Could not parse workspace name: {}", self.cx.error(e));
+                    }
+                }
+            }
+        }
         Ok(Output {
             name: name.despan().map(|v| v.to_string()),
             match_: match_val.parse_map(&mut OutputMatchParser(self.cx))?,
             x: x.despan(),
             y: y.despan(),
-            scale: scale.despan(),
+            scale,
             transform,
             mode,
             vrr,
@@ -195,6 +237,9 @@ impl Parser for OutputParser<'_> {
             color_space,
             transfer_function,
             brightness,
+            cursor_size: cursor_size.despan(),
+            max_fps,
+            workspaces,
         })
     }
 }
@@ -0,0 +1,71 @@
+use {
+    crate::config::{Output, parse_config},
+    jay_config::video::Transform,
+};
+
+fn parse_output(fields: &str) -> Output {
+    let input = format!("[[outputs]]\nmatch.name = \"left\"\n{fields}\n");
+    let config = parse_config(input.as_bytes(), &Default::default(), |e| {
+        panic!("could not parse config: {e}")
+    })
+    .unwrap();
+    let [output] = <[Output; 1]>::try_from(config.outputs).unwrap();
+    output
+}
+
+#[test]
+fn transform() {
+    let cases = [
+        ("none", Transform::None),
+        ("normal", Transform::None),
+        ("rotate-90", Transform::Rotate90),
+        ("90", Transform::Rotate90),
+        ("rotate-180", Transform::Rotate180),
+        ("180", Transform::Rotate180),
+        ("rotate-270", Transform::Rotate270),
+        ("270", Transform::Rotate270),
+        ("flip", Transform::Flip),
+        ("flipped", Transform::Flip),
+        ("flip-rotate-90", Transform::FlipRotate90),
+        ("flipped-90", Transform::FlipRotate90),
+        ("flip-rotate-180", Transform::FlipRotate180),
+        ("flipped-180", Transform::FlipRotate180),
+        ("flip-rotate-270", Transform::FlipRotate270),
+        ("flipped-270", Transform::FlipRotate270),
+    ];
+    for (name, transform) in cases {
+        let output = parse_output(&format!("transform = \"{name}\""));
+        assert_eq!(output.transform, Some(transform), "{name}");
+    }
+}
+
+#[test]
+fn transform_invalid() {
+    for name in ["", "45", "rotate-45", "flipped-0", "Normal"] {
+        let output = parse_output(&format!("transform = \"{name}\""));
+        assert_eq!(output.transform, None, "{name}");
+    }
+}
+
+#[test]
+fn scale() {
+    let cases = [
+        ("1", 1.0),
+        ("2", 2.0),
+        ("1.25", 1.25),
+        ("1.5", 1.5),
+        ("0.1", 0.1),
+    ];
+    for (value, scale) in cases {
+        let output = parse_output(&format!("scale = {value}"));
+        assert_eq!(output.scale, Some(scale), "{value}");
+    }
+}
+
+#[test]
+fn scale_invalid() {
+    for value in ["0", "0.0", "-1", "-1.5", "0.05", "1001", "\"1.5\""] {
+        let output = parse_output(&format!("scale = {value}"));
+        assert_eq!(output.scale, None, "{value}");
+    }
+}
@@ -0,0 +1,72 @@
+use {
+    crate::{
+        config::parser::{DataType, ParseResult, Parser, UnexpectedDataType},
+        toml::{
+            toml_span::{Span, Spanned, SpannedExt},
+            toml_value::Value,
+        },
+    },
+    indexmap::IndexMap,
+    jay_config::{client, client::ClientCapabilities},
+    thiserror::Error,
+};
+
+#[derive(Debug, Error)]
+pub enum SandboxCapabilitiesParserError {
+    #[error(transparent)]
+    Expected(#[from] UnexpectedDataType),
+    #[error("Unknown capability `{}`", .0)]
+    UnknownCapability(String),
+}
+
+pub struct ClientCapabilitiesParser;
+
+impl Parser for ClientCapabilitiesParser {
+    type Value = ClientCapabilities;
+    type Error = SandboxCapabilitiesParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::Array, DataType::String];
+
+    fn parse_string(&mut self, span: Span, string: &str) -> ParseResult<Self> {
+        let caps = match string {
+            "screencopy" => client::CAP_SCREENCOPY,
+            "virtual-keyboard" => client::CAP_VIRTUAL_KEYBOARD,
+            "input-method" => client::CAP_INPUT_METHOD,
+            "data-control" => client::CAP_DATA_CONTROL,
+            _ => {
+                return Err(
+                    SandboxCapabilitiesParserError::UnknownCapability(string.to_owned())
+                        .spanned(span),
+                );
+            }
+        };
+        Ok(caps)
+    }
+
+    fn parse_array(&mut self, _span: Span, array: &[Spanned<Value>]) -> ParseResult<Self> {
+        let mut caps = ClientCapabilities(0);
+        for el in array {
+            caps |= el.parse(&mut ClientCapabilitiesParser)?;
+        }
+        Ok(caps)
+    }
+}
+
+pub struct SandboxCapabilitiesParser;
+
+impl Parser for SandboxCapabilitiesParser {
+    type Value = Vec<(String, ClientCapabilities)>;
+    type Error = SandboxCapabilitiesParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::Table];
+
+    fn parse_table(
+        &mut self,
+        _span: Span,
+        table: &IndexMap<Spanned<String>, Spanned<Value>>,
+    ) -> ParseResult<Self> {
+        let mut grants = vec![];
+        for (k, v) in table {
+            grants.push((k.value.to_string(), v.parse(&mut ClientCapabilitiesParser)?));
+        }
+        Ok(grants)
+    }
+}
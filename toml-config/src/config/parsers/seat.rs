@@ -0,0 +1,69 @@
+use {
+    crate::{
+        config::{
+            SeatConfig,
+            context::Context,
+            extractor::{Extractor, ExtractorError, opt, recover, str},
+            parser::{DataType, ParseResult, Parser, UnexpectedDataType},
+        },
+        toml::{
+            toml_span::{DespanExt, Span, Spanned},
+            toml_value::Value,
+        },
+    },
+    indexmap::IndexMap,
+    thiserror::Error,
+};
+
+#[derive(Debug, Error)]
+pub enum SeatParserError {
+    #[error(transparent)]
+    Expected(#[from] UnexpectedDataType),
+    #[error(transparent)]
+    Extract(#[from] ExtractorError),
+}
+
+pub struct SeatParser<'a>(pub &'a Context<'a>);
+
+impl Parser for SeatParser<'_> {
+    type Value = SeatConfig;
+    type Error = SeatParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::Table];
+
+    fn parse_table(
+        &mut self,
+        span: Span,
+        table: &IndexMap<Spanned<String>, Spanned<Value>>,
+    ) -> ParseResult<Self> {
+        let mut ext = Extractor::new(self.0, span, table);
+        let (cursor_theme,) = ext.extract((recover(opt(str("cursor-theme"))),))?;
+        Ok(SeatConfig {
+            cursor_theme: cursor_theme.despan().map(|v| v.to_string()),
+        })
+    }
+}
+
+pub struct SeatsParser<'a>(pub &'a Context<'a>);
+
+impl Parser for SeatsParser<'_> {
+    type Value = Vec<(String, SeatConfig)>;
+    type Error = SeatParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::Table];
+
+    fn parse_table(
+        &mut self,
+        _span: Span,
+        table: &IndexMap<Spanned<String>, Spanned<Value>>,
+    ) -> ParseResult<Self> {
+        let mut seats = vec![];
+        for (k, v) in table {
+            match v.parse(&mut SeatParser(self.0)) {
+                Ok(v) => seats.push((k.value.to_string(), v)),
+                Err(e) => {
+                    log::warn!("Could not parse seat {}: {}", k.value, self.0.error(e));
+                }
+            }
+        }
+        Ok(seats)
+    }
+}
@@ -0,0 +1,97 @@
+use {
+    crate::{
+        config::{
+            SwipeGesture,
+            context::Context,
+            extractor::{Extractor, ExtractorError, n32, val},
+            parser::{DataType, ParseResult, Parser, UnexpectedDataType},
+            parsers::{
+                action::{ActionParser, ActionParserError},
+                direction::{DirectionParser, DirectionParserError},
+            },
+            spanned::SpannedErrorExt,
+        },
+        toml::{
+            toml_span::{Span, Spanned},
+            toml_value::Value,
+        },
+    },
+    indexmap::IndexMap,
+    thiserror::Error,
+};
+
+#[derive(Debug, Error)]
+pub enum SwipeGestureParserError {
+    #[error(transparent)]
+    Expected(#[from] UnexpectedDataType),
+    #[error(transparent)]
+    Extract(#[from] ExtractorError),
+    #[error(transparent)]
+    Action(ActionParserError),
+    #[error(transparent)]
+    Direction(DirectionParserError),
+}
+
+pub struct SwipeGestureParser<'a>(pub &'a Context<'a>);
+
+impl Parser for SwipeGestureParser<'_> {
+    type Value = SwipeGesture;
+    type Error = SwipeGestureParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::Table];
+
+    fn parse_table(
+        &mut self,
+        span: Span,
+        table: &IndexMap<Spanned<String>, Spanned<Value>>,
+    ) -> ParseResult<Self> {
+        let mut ext = Extractor::new(self.0, span, table);
+        let (finger_count, direction, action_val) =
+            ext.extract((n32("finger-count"), val("direction"), val("action")))?;
+        let direction = direction
+            .parse_map(&mut DirectionParser)
+            .map_spanned_err(SwipeGestureParserError::Direction)?;
+        let action = action_val
+            .parse(&mut ActionParser(self.0))
+            .map_spanned_err(SwipeGestureParserError::Action)?;
+        Ok(SwipeGesture {
+            finger_count: finger_count.value,
+            direction,
+            action,
+        })
+    }
+}
+
+pub struct SwipeGesturesParser<'a>(pub &'a Context<'a>);
+
+impl Parser for SwipeGesturesParser<'_> {
+    type Value = Vec<SwipeGesture>;
+    type Error = SwipeGestureParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::Table, DataType::Array];
+
+    fn parse_array(&mut self, _span: Span, array: &[Spanned<Value>]) -> ParseResult<Self> {
+        let mut res = vec![];
+        for el in array {
+            match el.parse(&mut SwipeGestureParser(self.0)) {
+                Ok(o) => res.push(o),
+                Err(e) => {
+                    log::warn!("Could not parse swipe gesture: {}", self.0.error(e));
+                }
+            }
+        }
+        Ok(res)
+    }
+
+    fn parse_table(
+        &mut self,
+        span: Span,
+        table: &IndexMap<Spanned<String>, Spanned<Value>>,
+    ) -> ParseResult<Self> {
+        log::warn!(
+            "`swipe-gestures` value should be an array: {}",
+            self.0.error3(span)
+        );
+        SwipeGestureParser(self.0)
+            .parse_table(span, table)
+            .map(|v| vec![v])
+    }
+}
@@ -60,6 +60,7 @@ impl Parser for ThemeParser<'_> {
                 title_height,
                 font,
             ),
+            (shadow_color, shadow_size, shadow_offset_x, shadow_offset_y, inner_gap, outer_gap),
         ) = ext.extract((
             (
                 opt(val("attention-requested-bg-color")),
@@ -83,6 +84,14 @@ impl Parser for ThemeParser<'_> {
                 recover(opt(s32("title-height"))),
                 recover(opt(str("font"))),
             ),
+            (
+                opt(val("shadow-color")),
+                recover(opt(s32("shadow-size"))),
+                recover(opt(s32("shadow-offset-x"))),
+                recover(opt(s32("shadow-offset-y"))),
+                recover(opt(s32("inner-gap"))),
+                recover(opt(s32("outer-gap"))),
+            ),
         ))?;
         macro_rules! color {
             ($e:expr) => {
@@ -117,6 +126,12 @@ impl Parser for ThemeParser<'_> {
             border_width: border_width.despan(),
             title_height: title_height.despan(),
             font: font.map(|f| f.value.to_string()),
+            shadow_color: color!(shadow_color),
+            shadow_size: shadow_size.despan(),
+            shadow_offset_x: shadow_offset_x.despan(),
+            shadow_offset_y: shadow_offset_y.despan(),
+            inner_gap: inner_gap.despan(),
+            outer_gap: outer_gap.despan(),
         })
     }
 }
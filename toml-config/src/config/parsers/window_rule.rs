@@ -3,10 +3,11 @@ use {
         config::{
             WindowMatch, WindowRule,
             context::Context,
-            extractor::{Extractor, ExtractorError, bol, opt, recover, str, val},
+            extractor::{Extractor, ExtractorError, bol, fltorint, opt, recover, str, val},
             parser::{DataType, ParseResult, Parser, UnexpectedDataType},
             parsers::{
                 action::{ActionParser, ActionParserError},
+                float_size::FloatSizeParser,
                 tile_state::TileStateParser,
                 window_match::{WindowMatchParser, WindowMatchParserError},
             },
@@ -48,15 +49,27 @@ impl Parser for WindowRuleParser<'_> {
         table: &IndexMap<Spanned<String>, Spanned<Value>>,
     ) -> ParseResult<Self> {
         let mut ext = Extractor::new(self.0, span, table);
-        let (name, match_val, action_val, latch_val, auto_focus, initial_tile_state_val) = ext
-            .extract((
-                opt(str("name")),
-                opt(val("match")),
-                opt(val("action")),
-                opt(val("latch")),
-                recover(opt(bol("auto-focus"))),
-                opt(val("initial-tile-state")),
-            ))?;
+        let (
+            name,
+            match_val,
+            action_val,
+            latch_val,
+            auto_focus,
+            initial_tile_state_val,
+            may_inhibit_shortcuts,
+            opacity,
+            initial_float_size_val,
+        ) = ext.extract((
+            opt(str("name")),
+            opt(val("match")),
+            opt(val("action")),
+            opt(val("latch")),
+            recover(opt(bol("auto-focus"))),
+            opt(val("initial-tile-state")),
+            recover(opt(bol("may-inhibit-shortcuts"))),
+            recover(opt(fltorint("opacity"))),
+            opt(val("initial-float-size")),
+        ))?;
         let mut action = None;
         if let Some(value) = action_val {
             action = Some(
@@ -85,6 +98,18 @@ impl Parser for WindowRuleParser<'_> {
                 }
             }
         }
+        let mut initial_float_size = None;
+        if let Some(value) = initial_float_size_val {
+            match value.parse(&mut FloatSizeParser(self.0)) {
+                Ok(v) => initial_float_size = Some(v),
+                Err(e) => {
+                    log::warn!(
+                        "Could not parse the initial float size: {}",
+                        self.0.error(e)
+                    );
+                }
+            }
+        }
         let match_ = match match_val {
             None => WindowMatch::default(),
             Some(m) => m.parse_map(&mut WindowMatchParser(self.0))?,
@@ -96,6 +121,9 @@ impl Parser for WindowRuleParser<'_> {
             latch,
             auto_focus: auto_focus.despan(),
             initial_tile_state,
+            may_inhibit_shortcuts: may_inhibit_shortcuts.despan(),
+            opacity: opacity.despan(),
+            initial_float_size,
         })
     }
 }
@@ -0,0 +1,36 @@
+use {
+    crate::{
+        config::parser::{DataType, ParseResult, Parser, UnexpectedDataType},
+        toml::toml_span::{Span, SpannedExt},
+    },
+    jay_config::WorkspaceLayout,
+    thiserror::Error,
+};
+
+pub struct WorkspaceLayoutParser;
+
+#[derive(Debug, Error)]
+pub enum WorkspaceLayoutParserError {
+    #[error(transparent)]
+    DataType(#[from] UnexpectedDataType),
+    #[error("Unknown workspace layout {0}")]
+    Unknown(String),
+}
+
+impl Parser for WorkspaceLayoutParser {
+    type Value = WorkspaceLayout;
+    type Error = WorkspaceLayoutParserError;
+    const EXPECTED: &'static [DataType] = &[DataType::String];
+
+    fn parse_string(&mut self, span: Span, string: &str) -> ParseResult<Self> {
+        let layout = match string.to_ascii_lowercase().as_str() {
+            "manual" => WorkspaceLayout::MANUAL,
+            "bsp" => WorkspaceLayout::BSP,
+            "tabbed" => WorkspaceLayout::TABBED,
+            _ => {
+                return Err(WorkspaceLayoutParserError::Unknown(string.to_string()).spanned(span));
+            }
+        };
+        Ok(layout)
+    }
+}
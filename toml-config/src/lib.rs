@@ -13,30 +13,39 @@ use {
     crate::{
         config::{
             Action, ClientRule, Config, ConfigConnector, ConfigDrmDevice, ConfigKeymap,
-            ConnectorMatch, DrmDeviceMatch, Exec, Input, InputMatch, Output, OutputMatch, Shortcut,
-            SimpleCommand, Status, Theme, WindowRule, parse_config,
+            ConnectorMatch, DrmDeviceMatch, Exec, Input, InputMatch, Output, OutputMatch,
+            SeatConfig, Shortcut, SimpleCommand, Status, SwipeGesture, Theme, WindowRule,
+            parse_config,
         },
         rules::{MatcherTemp, RuleMapper},
     },
     ahash::{AHashMap, AHashSet},
     error_reporter::Report,
     jay_config::{
-        client::Client,
+        Direction,
+        client::{Client, set_sandbox_capabilities},
         config, config_dir,
         exec::{Command, set_env, unset_env},
         get_workspace,
         input::{
-            FocusFollowsMouseMode, InputDevice, Seat, SwitchEvent, capability::CAP_SWITCH,
-            get_seat, input_devices, on_input_device_removed, on_new_input_device,
+            FocusFollowsMouseMode, InputDevice, Seat, SwipeEvent, SwitchEvent,
+            capability::CAP_SWITCH, get_seat, get_seats, input_devices, on_input_device_removed,
+            on_new_input_device, set_double_click_distance, set_double_click_time,
             set_libei_socket_enabled,
         },
         is_reload,
         keyboard::{Keymap, ModifiedKeySym},
         logging::set_log_level,
-        on_devices_enumerated, on_idle, on_unload, quit, reload, set_color_management_enabled,
-        set_default_workspace_capture, set_explicit_sync_enabled, set_float_above_fullscreen,
-        set_idle, set_idle_grace_period, set_middle_click_paste_enabled, set_show_bar,
-        set_show_float_pin_icon, set_ui_drag_enabled, set_ui_drag_threshold,
+        on_devices_enumerated, on_idle, on_lock_requested, on_unload, quit, reload,
+        set_client_object_limit, set_client_shm_limit, set_clipboard_persistence_enabled,
+        set_clipboard_persistence_max_size, set_color_management_enabled, set_decoration_mode,
+        set_default_workspace_capture, set_default_workspace_layout, set_explicit_sync_enabled,
+        set_float_above_fullscreen, set_focus_stealing_policy,
+        set_freeze_cursor_animation_when_idle, set_idle, set_idle_dim, set_idle_grace_period,
+        set_inactive_window_opacity, set_low_power_max_fps, set_low_power_mode,
+        set_middle_click_paste_enabled, set_show_bar, set_show_float_close_icon,
+        set_show_float_pin_icon, set_swallow_app_ids, set_ui_drag_enabled, set_ui_drag_threshold,
+        set_window_animation_duration, set_workspace_animation_duration,
         status::{set_i3bar_separator, set_status, set_status_command, unset_status_command},
         switch_to_vt,
         theme::{reset_colors, reset_font, reset_sizes, set_font},
@@ -176,6 +185,28 @@ impl Action {
                     let persistent = state.persistent.clone();
                     B::new(move || persistent.seat.jump_to_mark(None))
                 }
+                SimpleCommand::CycleKeyboardLayout(true) => {
+                    B::new(move || s.next_keyboard_layout())
+                }
+                SimpleCommand::CycleKeyboardLayout(false) => {
+                    B::new(move || s.prev_keyboard_layout())
+                }
+                SimpleCommand::ShowWorkspaceRel(true) => B::new(move || s.show_next_workspace()),
+                SimpleCommand::ShowWorkspaceRel(false) => B::new(move || s.show_prev_workspace()),
+                SimpleCommand::ResetGaps => B::new(move || {
+                    let ws = s.get_keyboard_workspace();
+                    ws.set_inner_gap(None);
+                    ws.set_outer_gap(None);
+                }),
+                SimpleCommand::WarpPointerToWindow => B::new(move || {
+                    let window = s.window();
+                    if window.exists() {
+                        s.warp_pointer_to_window(window);
+                    }
+                }),
+                SimpleCommand::Zoom(true) => B::new(move || s.adjust_zoom(ZOOM_STEP)),
+                SimpleCommand::Zoom(false) => B::new(move || s.adjust_zoom(1.0 / ZOOM_STEP)),
+                SimpleCommand::ResetZoom => B::new(move || s.reset_zoom()),
             },
             Action::Multi { actions } => {
                 let actions: Vec<_> = actions.into_iter().map(|a| a.into_fn(state)).collect();
@@ -220,6 +251,7 @@ impl Action {
                             out.apply(c);
                         }
                     }
+                    warn_about_overlapping_outputs();
                 })
             }
             Action::SetEnv { env } => B::new(move || {
@@ -265,17 +297,26 @@ impl Action {
                     for d in drm_devices() {
                         if dev.matches(d, &state) {
                             d.make_render_device();
+                            return;
                         }
                     }
+                    log::warn!("No DRM device matches the requested render device");
                 })
             }
-            Action::ConfigureIdle { idle, grace_period } => B::new(move || {
+            Action::ConfigureIdle {
+                idle,
+                grace_period,
+                dim,
+            } => B::new(move || {
                 if let Some(idle) = idle {
                     set_idle(Some(idle))
                 }
                 if let Some(period) = grace_period {
                     set_idle_grace_period(period)
                 }
+                if let Some(dim) = dim {
+                    set_idle_dim(dim as f32)
+                }
             }),
             Action::MoveToOutput { output, workspace } => {
                 let state = state.clone();
@@ -344,6 +385,43 @@ impl Action {
                 let persistent = state.persistent.clone();
                 B::new(move || persistent.seat.copy_mark(s, d))
             }
+            Action::SetWorkspaceLayout { layout } => {
+                B::new(move || s.get_keyboard_workspace().set_layout(layout))
+            }
+            Action::SetGaps { inner, outer } => B::new(move || {
+                let ws = s.get_keyboard_workspace();
+                if inner.is_some() {
+                    ws.set_inner_gap(inner);
+                }
+                if outer.is_some() {
+                    ws.set_outer_gap(outer);
+                }
+            }),
+            Action::MoveWindowToOutput { output } => {
+                let state = state.clone();
+                B::new(move || {
+                    for connector in connectors() {
+                        if connector.connected() && output.matches(connector, &state) {
+                            s.move_window_to_output(connector);
+                            return;
+                        }
+                    }
+                })
+            }
+            Action::MoveWindowToAdjacentOutput { direction } => {
+                B::new(move || s.move_window_to_adjacent_output(direction))
+            }
+            Action::MoveToScratchpad { name } => B::new(move || s.move_to_scratchpad(&name)),
+            Action::ToggleScratchpad { name } => B::new(move || s.toggle_scratchpad(&name)),
+            Action::AdjustGaps { inner, outer } => B::new(move || {
+                let ws = s.get_keyboard_workspace();
+                if inner != 0 {
+                    ws.set_inner_gap(Some((ws.inner_gap() + inner).max(0)));
+                }
+                if outer != 0 {
+                    ws.set_outer_gap(Some((ws.outer_gap() + outer).max(0)));
+                }
+            }),
         }
     }
 }
@@ -475,6 +553,8 @@ impl InputMatch {
                 name,
                 syspath,
                 devnode,
+                vendor_id,
+                product_id,
                 is_keyboard,
                 is_pointer,
                 is_touch,
@@ -510,6 +590,16 @@ impl InputMatch {
                 {
                     return false;
                 }
+                if let Some(vendor_id) = *vendor_id
+                    && d.vendor_id() != vendor_id
+                {
+                    return false;
+                }
+                if let Some(product_id) = *product_id
+                    && d.product_id() != product_id
+                {
+                    return false;
+                }
                 macro_rules! check_cap {
                     ($is:expr, $cap:ident) => {
                         if let Some(is) = *$is
@@ -586,6 +676,15 @@ impl Input {
         if let Some(v) = self.middle_button_emulation {
             c.set_middle_button_emulation_enabled(v);
         }
+        if let Some(v) = self.scroll_method {
+            c.set_scroll_method(v);
+        }
+        if let Some(v) = self.disable_while_typing {
+            c.set_disable_while_typing_enabled(v);
+        }
+        if let Some(v) = self.send_events_mode {
+            c.set_send_events_mode(v);
+        }
     }
 }
 
@@ -729,6 +828,15 @@ impl Output {
         if let Some(brightness) = self.brightness {
             c.set_brightness(brightness);
         }
+        if let Some(size) = self.cursor_size {
+            c.set_cursor_size(Some(size));
+        }
+        if let Some(max_fps) = self.max_fps {
+            c.set_max_fps(Some(max_fps));
+        }
+        for workspace in &self.workspaces {
+            get_workspace(workspace).bind_to_output(c);
+        }
     }
 }
 
@@ -792,6 +900,13 @@ impl State {
                     s.latch(move || l());
                 });
             }
+            if shortcut.repeat {
+                let s = self.persistent.seat;
+                f = Box::new(move || {
+                    f();
+                    s.repeat_shortcut();
+                });
+            }
             self.persistent
                 .seat
                 .bind_masked(shortcut.mask, shortcut.keysym, f);
@@ -870,6 +985,7 @@ impl State {
         color!(UNFOCUSED_TITLE_BACKGROUND_COLOR, unfocused_title_bg_color);
         color!(UNFOCUSED_TITLE_TEXT_COLOR, unfocused_title_text_color);
         color!(HIGHLIGHT_COLOR, highlight_color);
+        color!(SHADOW_COLOR, shadow_color);
         macro_rules! size {
             ($sized:ident, $field:ident) => {
                 if let Some(size) = theme.$field {
@@ -879,6 +995,11 @@ impl State {
         }
         size!(BORDER_WIDTH, border_width);
         size!(TITLE_HEIGHT, title_height);
+        size!(SHADOW_SIZE, shadow_size);
+        size!(SHADOW_OFFSET_X, shadow_offset_x);
+        size!(SHADOW_OFFSET_Y, shadow_offset_y);
+        size!(INNER_GAP, inner_gap);
+        size!(OUTER_GAP, outer_gap);
         if let Some(font) = &theme.font {
             set_font(font);
         }
@@ -1099,6 +1220,7 @@ fn load_config(initial_load: bool, persistent: &Rc<PersistentState>) {
         }
     }
     let switch_actions = Rc::new(switch_actions);
+    handle_swipe_gestures(&state, config.swipe_gestures.drain(..));
     match config.on_graphics_initialized {
         None => on_graphics_initialized(|| ()),
         Some(a) => on_graphics_initialized(a.into_fn(&state)),
@@ -1107,6 +1229,10 @@ fn load_config(initial_load: bool, persistent: &Rc<PersistentState>) {
         None => on_idle(|| ()),
         Some(a) => on_idle(a.into_fn(&state)),
     }
+    match config.on_lock_request {
+        None => on_lock_requested(|| ()),
+        Some(a) => on_lock_requested(a.into_fn(&state)),
+    }
     state.unbind_all();
     state.apply_shortcuts(config.shortcuts);
     if let Some(keymap) = config.keymap {
@@ -1140,6 +1266,7 @@ fn load_config(initial_load: bool, persistent: &Rc<PersistentState>) {
                         output.apply(c);
                     }
                 }
+                warn_about_overlapping_outputs();
             }
         }
     });
@@ -1150,6 +1277,19 @@ fn load_config(initial_load: bool, persistent: &Rc<PersistentState>) {
         }
     });
     set_default_workspace_capture(config.workspace_capture);
+    if let Some(layout) = config.workspace_layout {
+        set_default_workspace_layout(layout);
+    }
+    if let Some(app_ids) = &config.swallow_app_ids {
+        let app_ids: Vec<_> = app_ids.iter().map(|s| s.as_str()).collect();
+        set_swallow_app_ids(&app_ids);
+    }
+    let sandbox_capabilities: Vec<_> = config
+        .sandbox_capabilities
+        .iter()
+        .map(|(app_id, caps)| (app_id.as_str(), *caps))
+        .collect();
+    set_sandbox_capabilities(&sandbox_capabilities);
     for (k, v) in config.env {
         set_env(&k, &v);
     }
@@ -1166,6 +1306,9 @@ fn load_config(initial_load: bool, persistent: &Rc<PersistentState>) {
         if let Some(period) = config.grace_period {
             set_idle_grace_period(period);
         }
+        if let Some(dim) = config.idle_dim {
+            set_idle_dim(dim as f32);
+        }
     }
     on_devices_enumerated({
         let state = state.clone();
@@ -1177,6 +1320,9 @@ fn load_config(initial_load: bool, persistent: &Rc<PersistentState>) {
                         return;
                     }
                 }
+                log::warn!(
+                    "No DRM device matches the configured render device. Using the default device."
+                );
             }
         }
     });
@@ -1203,6 +1349,13 @@ fn load_config(initial_load: bool, persistent: &Rc<PersistentState>) {
             }
         }
     });
+    for c in jay_config::input::input_devices() {
+        for input in &config.inputs {
+            if input.match_.matches(c, &state) {
+                input.apply(c, &state);
+            }
+        }
+    }
     on_new_input_device({
         let state = state.clone();
         let switch_actions = switch_actions.clone();
@@ -1236,6 +1389,20 @@ fn load_config(initial_load: bool, persistent: &Rc<PersistentState>) {
             true => FocusFollowsMouseMode::True,
             false => FocusFollowsMouseMode::False,
         });
+    if let Some(enabled) = config.per_window_keyboard_layout {
+        persistent.seat.set_per_window_keyboard_layout(enabled);
+    }
+    if let Some(enabled) = config.cursor_follows_focus {
+        persistent.seat.set_cursor_follows_focus(enabled);
+    }
+    if let Some(enabled) = config.hide_cursor_while_typing {
+        persistent.seat.set_hide_cursor_while_typing(enabled);
+    }
+    if let Some(ms) = config.focus_follows_mouse_delay_ms {
+        persistent
+            .seat
+            .set_focus_follows_mouse_delay(Duration::from_nanos((ms.max(0.0) * 1_000_000.0) as _));
+    }
     if let Some(window_management_key) = config.window_management_key {
         persistent
             .seat
@@ -1261,6 +1428,14 @@ fn load_config(initial_load: bool, persistent: &Rc<PersistentState>) {
     if let Some(threshold) = config.ui_drag.threshold {
         set_ui_drag_threshold(threshold);
     }
+    if let Some(enabled) = config.clipboard_persistence.enabled {
+        set_clipboard_persistence_enabled(enabled);
+    }
+    if let Some(max_size) = config.clipboard_persistence.max_size {
+        set_clipboard_persistence_max_size(max_size);
+    }
+    set_client_object_limit(config.client_limits.max_objects);
+    set_client_shm_limit(config.client_limits.max_shm_size);
     if let Some(xwayland) = config.xwayland
         && let Some(mode) = xwayland.scaling_mode
     {
@@ -1271,10 +1446,13 @@ fn load_config(initial_load: bool, persistent: &Rc<PersistentState>) {
     {
         set_color_management_enabled(enabled);
     }
-    if let Some(float) = config.float
-        && let Some(show) = float.show_pin_icon
-    {
-        set_show_float_pin_icon(show);
+    if let Some(float) = config.float {
+        if let Some(show) = float.show_pin_icon {
+            set_show_float_pin_icon(show);
+        }
+        if let Some(show) = float.show_close_icon {
+            set_show_float_close_icon(show);
+        }
     }
     if let Some(key) = config.pointer_revert_key {
         persistent.seat.set_pointer_revert_key(key);
@@ -1296,6 +1474,94 @@ fn load_config(initial_load: bool, persistent: &Rc<PersistentState>) {
     if let Some(v) = config.middle_click_paste {
         set_middle_click_paste_enabled(v);
     }
+    if let Some(v) = config.freeze_cursor_animation_when_idle {
+        set_freeze_cursor_animation_when_idle(v);
+    }
+    apply_cursor_themes(
+        persistent.seat,
+        config.cursor_theme.as_deref(),
+        &config.seats,
+    );
+    if let Some(v) = config.inactive_window_opacity {
+        set_inactive_window_opacity(v as f32);
+    }
+    if let Some(ms) = config.workspace_animation_duration_ms {
+        set_workspace_animation_duration(Duration::from_nanos((ms.max(0.0) * 1_000_000.0) as _));
+    }
+    if let Some(policy) = config.focus_stealing_policy {
+        set_focus_stealing_policy(policy);
+    }
+    if let Some(mode) = config.decoration_mode {
+        set_decoration_mode(mode);
+    }
+    if let Some(ms) = config.window_animation_duration_ms {
+        set_window_animation_duration(Duration::from_nanos((ms.max(0.0) * 1_000_000.0) as _));
+    }
+    if let Some(ms) = config.double_click_time_ms {
+        set_double_click_time(Duration::from_nanos((ms.max(0.0) * 1_000_000.0) as _));
+    }
+    if let Some(distance) = config.double_click_distance {
+        set_double_click_distance(distance);
+    }
+    if let Some(low_power) = config.low_power {
+        if let Some(max_fps) = low_power.max_fps {
+            set_low_power_max_fps(Some(max_fps));
+        }
+        if let Some(enabled) = low_power.enabled {
+            set_low_power_mode(enabled);
+        }
+    }
+}
+
+fn warn_about_overlapping_outputs() {
+    let outputs: Vec<_> = connectors()
+        .into_iter()
+        .filter(|c| c.connected())
+        .map(|c| {
+            let (x, y) = c.position();
+            (c, x, y, x + c.width(), y + c.height())
+        })
+        .collect();
+    for (idx, &(a, ax1, ay1, ax2, ay2)) in outputs.iter().enumerate() {
+        for &(b, bx1, by1, bx2, by2) in &outputs[idx + 1..] {
+            if ax1 < bx2 && bx1 < ax2 && ay1 < by2 && by1 < ay2 {
+                log::warn!(
+                    "Outputs {} ({}x{} at {},{}) and {} ({}x{} at {},{}) overlap. \
+                     This is not supported and can cause the pointer to behave unexpectedly.",
+                    a.name(),
+                    ax2 - ax1,
+                    ay2 - ay1,
+                    ax1,
+                    ay1,
+                    b.name(),
+                    bx2 - bx1,
+                    by2 - by1,
+                    bx1,
+                    by1,
+                );
+            }
+        }
+    }
+}
+
+/// Sets the cursor theme of every seat, resetting seats without a configured theme.
+fn apply_cursor_themes(
+    default_seat: Seat,
+    default_theme: Option<&str>,
+    seats: &[(String, SeatConfig)],
+) {
+    let mut themes = AHashMap::new();
+    if let Some(v) = default_theme {
+        themes.insert(default_seat, v);
+    }
+    for (name, seat) in seats {
+        if let Some(v) = &seat.cursor_theme {
+            themes.insert(get_seat(name), v.as_str());
+        }
+    }
+    for seat in get_seats() {
+        seat.set_cursor_theme(themes.get(&seat).copied());
+    }
 }
 
 fn create_command(exec: &Exec) -> Command {
@@ -1312,6 +1578,51 @@ fn create_command(exec: &Exec) -> Command {
     command
 }
 
+/// The distance the fingers have to move before a swipe gesture triggers an action.
+const SWIPE_GESTURE_DISTANCE: f64 = 100.0;
+
+/// The factor by which the `zoom-in` and `zoom-out` actions change the magnification.
+const ZOOM_STEP: f64 = 1.25;
+
+fn handle_swipe_gestures(state: &Rc<State>, gestures: impl Iterator<Item = SwipeGesture>) {
+    let gestures: Vec<_> = gestures
+        .map(|g| (g.finger_count, g.direction, g.action.into_fn(state)))
+        .collect();
+    let mut finger_count = 0;
+    let mut dx = 0.0;
+    let mut dy = 0.0;
+    state
+        .persistent
+        .seat
+        .on_swipe_event(move |event| match event {
+            SwipeEvent::Begin { finger_count: n } => {
+                finger_count = n;
+                dx = 0.0;
+                dy = 0.0;
+            }
+            SwipeEvent::Update { dx: x, dy: y } => {
+                dx += x;
+                dy += y;
+            }
+            SwipeEvent::End { cancelled } => {
+                if cancelled || f64::max(dx.abs(), dy.abs()) < SWIPE_GESTURE_DISTANCE {
+                    return;
+                }
+                let direction = match (dx.abs() >= dy.abs(), dx < 0.0, dy < 0.0) {
+                    (true, true, _) => Direction::Left,
+                    (true, false, _) => Direction::Right,
+                    (false, _, true) => Direction::Up,
+                    (false, _, false) => Direction::Down,
+                };
+                for (n, d, action) in &gestures {
+                    if *n == finger_count && *d == direction {
+                        action();
+                    }
+                }
+            }
+        });
+}
+
 const DEFAULT: &[u8] = include_bytes!("default-config.toml");
 
 pub fn configure() {
@@ -297,44 +297,37 @@ impl Rule for WindowRule {
 
     fn bind(&self, state: &Rc<State>, matcher: Self::Matcher) {
         let state = state.clone();
-        macro_rules! latch {
-            ($g:ident, $client:ident, $win:ident) => {
-                let g = $g.clone();
-                let state = state.clone();
-                $win.latch(move || {
-                    state.with_client($client, true, || {
-                        state.with_window(*$win, true, || g());
-                    });
-                });
-            };
-        }
-        if let Some(action) = &self.action {
-            let f = action.clone().into_fn(&state);
-            if let Some(action) = &self.latch {
-                let g = action.clone().into_rc_fn(&state);
-                matcher.bind(move |win| {
-                    let client = win.client();
-                    state.with_client(client, false, || {
-                        state.with_window(*win, false, &f);
-                    });
-                    latch!(g, client, win);
-                });
-            } else {
-                matcher.bind(move |win| {
-                    let client = win.client();
+        let action = self.action.clone().map(|a| a.into_fn(&state));
+        let latch = self.latch.clone().map(|a| a.into_rc_fn(&state));
+        let opacity = self.opacity.map(|o| o as f32);
+        if action.is_some() || latch.is_some() || opacity.is_some() {
+            matcher.bind(move |win| {
+                let client = win.client();
+                if let Some(opacity) = opacity {
+                    win.set_opacity(opacity);
+                }
+                if let Some(f) = &action {
                     state.with_client(client, false, || {
-                        state.with_window(*win, false, &f);
+                        state.with_window(*win, false, f);
                     });
+                }
+                if latch.is_none() && opacity.is_none() {
+                    return;
+                }
+                let g = latch.clone();
+                let state = state.clone();
+                let window = *win;
+                win.latch(move || {
+                    if opacity.is_some() {
+                        window.set_opacity(1.0);
+                    }
+                    if let Some(g) = g {
+                        state.with_client(client, true, || {
+                            state.with_window(window, true, || g());
+                        });
+                    }
                 });
-            }
-        } else {
-            if let Some(action) = &self.latch {
-                let g = action.clone().into_rc_fn(&state);
-                matcher.bind(move |win| {
-                    let client = win.client();
-                    latch!(g, client, win);
-                });
-            }
+            });
         }
         if let Some(auto_focus) = self.auto_focus {
             matcher.set_auto_focus(auto_focus);
@@ -342,6 +335,12 @@ impl Rule for WindowRule {
         if let Some(tile_state) = self.initial_tile_state {
             matcher.set_initial_tile_state(tile_state);
         }
+        if let Some(may_inhibit) = self.may_inhibit_shortcuts {
+            matcher.set_may_inhibit_shortcuts(may_inhibit);
+        }
+        if let Some((width, height)) = self.initial_float_size {
+            matcher.set_initial_float_size(width, height);
+        }
     }
 
     fn gen_matcher(m: Self::Matcher) -> Self::Criterion<'static> {